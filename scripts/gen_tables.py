@@ -2,7 +2,8 @@
 
 """
 Script for generating the pre-computed tables used by microfft:
-  - radix-2 FFT twiddle tables
+  - radix-2 FFT twiddle tables (f32, f64, Q15 and Q31)
+  - mixed-radix FFT twiddle tables (f32 and f64)
   - bit reversal tables
 
 Used to create the file `src/tables.rs`.
@@ -15,6 +16,12 @@ import math
 def parse_args():
     parser = argparse.ArgumentParser()
     parser.add_argument("N", type=int, help="Max FFT size")
+    parser.add_argument(
+        "--mixed-k",
+        type=int,
+        default=8,
+        help="Max power-of-two factor of mixed-radix FFT sizes",
+    )
     return parser.parse_args()
 
 
@@ -26,14 +33,14 @@ def emit_header():
     print()
 
 
-def emit_sine(max_n):
+def emit_sine(max_n, name, ty, scale=None):
     print("cfg_if::cfg_if! {")
 
     n = max_n
     while n > 2:
         kw = "if" if n == max_n else "else if"
         print(f'{kw} #[cfg(feature = "size-{n}")] {{')
-        emit_sine_table(n)
+        emit_sine_table(n, name, ty, scale)
         print("}", end=" ")
         n //= 2
 
@@ -42,15 +49,35 @@ def emit_sine(max_n):
     print()
 
 
-def emit_sine_table(n):
-    print("pub(crate) const SINE: &[f32] = &[")
+def emit_sine_table(n, name, ty, scale):
+    print(f"pub(crate) const {name}: &[{ty}] = &[")
     for k in range(1, n // 4):
         sine = math.sin(-2 * math.pi * k / n)
+        if scale is not None:
+            # clamp to keep the negated value representable
+            sine = max(round(sine * scale), -(scale - 1))
         print(f"    {sine},")
     print("];")
     print()
 
 
+def emit_sine_mixed(max_k, name, ty):
+    print("cfg_if::cfg_if! {")
+
+    for k in range(max_k, 1, -1):
+        kw = "if" if k == max_k else "else if"
+        features = ", ".join(f'feature = "size-{r << k}"' for r in (3, 5, 15))
+        print(f"{kw} #[cfg(any({features}))] {{")
+        emit_sine_table(15 << k, name, ty, None)
+        print("}", end=" ")
+
+    print("else {")
+    print(f"pub(crate) const {name}: &[{ty}] = &[];")
+    print("}")
+    print("}")
+    print()
+
+
 def emit_bitrev(max_n):
     print('#[cfg(feature = "bitrev-tables")]')
     print("pub(crate) const BITREV: &[&[u16]] = &[")
@@ -86,7 +113,12 @@ def main():
     args = parse_args()
 
     emit_header()
-    emit_sine(args.N)
+    emit_sine(args.N, "SINE", "f32")
+    emit_sine(args.N, "SINE_F64", "f64")
+    emit_sine(args.N, "SINE_Q15", "i16", 1 << 15)
+    emit_sine(args.N, "SINE_Q31", "i32", 1 << 31)
+    emit_sine_mixed(args.mixed_k, "SINE_MIXED", "f32")
+    emit_sine_mixed(args.mixed_k, "SINE_MIXED_F64", "f64")
     emit_bitrev(args.N)
 
 
@@ -0,0 +1,369 @@
+//! Batched FFTs over several channels
+//!
+//! The functions in this module perform the same-size CFFT or RFFT on each
+//! of `C` channels in one call. Two memory layouts are supported:
+//!
+//! - *planar*, with one array of `N` values per channel, i.e.
+//!   `[[f32; N]; C]`, and
+//! - *interleaved*, with one frame of `C` values per sample, i.e.
+//!   `[[f32; C]; N]`. This has the same memory layout as a flat buffer of
+//!   `N * C` interleaved samples. The nested form lets the compiler check
+//!   the number of values, so flat buffers are only accepted by the `_flat`
+//!   variants, which check their length at runtime instead.
+//!
+//! For power-of-two sizes, the butterflies of all channels are computed
+//! together, so each twiddle factor is only looked up once per butterfly
+//! stage, instead of once per channel. Mixed-radix sizes are supported as
+//! well, but transform one channel after the other. In the interleaved
+//! layout, each channel is gathered into a buffer on the stack for this,
+//! which holds the `N` values of the CFFT of one channel.
+//!
+//! The results are identical to transforming each channel individually with
+//! the corresponding function of the [`complex`](crate::complex) or
+//! [`real`](crate::real) module, up to rounding.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::batch;
+//!
+//! // three accelerometer axes, sampled together
+//! let mut frames = [[0.; 3]; 64];
+//! for (i, frame) in frames.iter_mut().enumerate() {
+//!     frame[0] = (i as f32 * 0.1).sin();
+//! }
+//!
+//! let spectra = batch::rfft_interleaved(&mut frames);
+//! // spectra[k][c] is bin `k` of channel `c`
+//! assert_eq!(spectra.len(), 32);
+//! ```
+
+use crate::impls::batch::{flatten, unflatten};
+use crate::size::{CFftImpl, CFftSize, RFftImpl, RFftSize, Size};
+use crate::{Complex32, Complex64};
+
+/// Perform in-place `N`-point CFFTs on `C` planar channels.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, batch::cfft_planar};
+///
+/// let mut input = [[Complex32::default(); 16]; 4];
+/// let result = cfft_planar(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn cfft_planar<const N: usize, const C: usize>(
+    input: &mut [[Complex32; N]; C],
+) -> &mut [[Complex32; N]; C]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::cfft_planar(flatten(input));
+    input
+}
+
+/// Perform in-place `N`-point CFFTs on `C` planar channels of
+/// double-precision values.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, batch::cfft_planar_f64};
+///
+/// let mut input = [[Complex64::default(); 16]; 4];
+/// let result = cfft_planar_f64(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn cfft_planar_f64<const N: usize, const C: usize>(
+    input: &mut [[Complex64; N]; C],
+) -> &mut [[Complex64; N]; C]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::cfft_planar(flatten(input));
+    input
+}
+
+/// Perform in-place `N`-point CFFTs on `N` interleaved frames of `C`
+/// channels.
+///
+/// For mixed-radix sizes, each channel is transformed through a buffer of
+/// `N` values on the stack, which takes up `8 * N` bytes.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, batch::cfft_interleaved};
+///
+/// let mut input = [[Complex32::default(); 4]; 16];
+/// let result = cfft_interleaved(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn cfft_interleaved<const N: usize, const C: usize>(
+    input: &mut [[Complex32; C]; N],
+) -> &mut [[Complex32; C]; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::cfft_interleaved(flatten(input));
+    input
+}
+
+/// Perform in-place `N`-point CFFTs on a flat buffer of `N` interleaved
+/// frames of `C` channels.
+///
+/// Like [`cfft_interleaved`], but for a buffer of `N * C` values, whose
+/// length is checked at runtime. The result is returned as `N` frames.
+///
+/// # Panics
+///
+/// Panics if the length of `input` is not `N * C`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, batch::cfft_interleaved_flat};
+///
+/// let mut input = [Complex32::default(); 16 * 4];
+/// let result = cfft_interleaved_flat::<16, 4>(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn cfft_interleaved_flat<const N: usize, const C: usize>(
+    input: &mut [Complex32],
+) -> &mut [[Complex32; C]; N]
+where
+    Size<N>: CFftSize,
+{
+    assert_eq!(input.len(), N * C, "input length must be N * C");
+    cfft_interleaved(unflatten(input))
+}
+
+/// Perform in-place `N`-point CFFTs on `N` interleaved frames of `C`
+/// channels of double-precision values.
+///
+/// For mixed-radix sizes, each channel is transformed through a buffer of
+/// `N` values on the stack, which takes up `16 * N` bytes.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, batch::cfft_interleaved_f64};
+///
+/// let mut input = [[Complex64::default(); 4]; 16];
+/// let result = cfft_interleaved_f64(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn cfft_interleaved_f64<const N: usize, const C: usize>(
+    input: &mut [[Complex64; C]; N],
+) -> &mut [[Complex64; C]; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::cfft_interleaved(flatten(input));
+    input
+}
+
+/// Perform in-place `N`-point CFFTs on a flat buffer of `N` interleaved
+/// frames of `C` channels of double-precision values.
+///
+/// Like [`cfft_interleaved_f64`], but for a buffer of `N * C` values, whose
+/// length is checked at runtime. The result is returned as `N` frames.
+///
+/// # Panics
+///
+/// Panics if the length of `input` is not `N * C`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, batch::cfft_interleaved_flat_f64};
+///
+/// let mut input = [Complex64::default(); 16 * 4];
+/// let result = cfft_interleaved_flat_f64::<16, 4>(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn cfft_interleaved_flat_f64<const N: usize, const C: usize>(
+    input: &mut [Complex64],
+) -> &mut [[Complex64; C]; N]
+where
+    Size<N>: CFftSize,
+{
+    assert_eq!(input.len(), N * C, "input length must be N * C");
+    cfft_interleaved_f64(unflatten(input))
+}
+
+/// Perform in-place `N`-point RFFTs on `C` planar channels.
+///
+/// Each channel is replaced by its `N/2` packed RFFT bins, with the
+/// real-valued Nyquist coefficient in the imaginary part of the DC bin, like
+/// [`real::rfft`](crate::real::rfft).
+///
+/// # Example
+///
+/// ```
+/// use microfft::batch::rfft_planar;
+///
+/// let mut input = [[0.; 16]; 4];
+/// let result = rfft_planar(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfft_planar<const N: usize, const C: usize>(
+    input: &mut [[f32; N]; C],
+) -> &mut [<Size<N> as RFftSize>::Half<Complex32>; C]
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft_planar(flatten(input))
+}
+
+/// Perform in-place `N`-point RFFTs on `C` planar channels of
+/// double-precision values.
+///
+/// Each channel is replaced by its `N/2` packed RFFT bins, with the
+/// real-valued Nyquist coefficient in the imaginary part of the DC bin, like
+/// [`real::rfft_f64`](crate::real::rfft_f64).
+///
+/// # Example
+///
+/// ```
+/// use microfft::batch::rfft_planar_f64;
+///
+/// let mut input = [[0.; 16]; 4];
+/// let result = rfft_planar_f64(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfft_planar_f64<const N: usize, const C: usize>(
+    input: &mut [[f64; N]; C],
+) -> &mut [<Size<N> as RFftSize>::Half<Complex64>; C]
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft_planar(flatten(input))
+}
+
+/// Perform in-place `N`-point RFFTs on `N` interleaved frames of `C`
+/// channels.
+///
+/// The result holds `N/2` interleaved frames of the packed RFFT bins of each
+/// channel, with the real-valued Nyquist coefficient in the imaginary part
+/// of the DC bin, like [`real::rfft`](crate::real::rfft).
+///
+/// For mixed-radix sizes, each channel is transformed through a buffer of
+/// `N/2` complex values on the stack, which takes up `4 * N` bytes.
+///
+/// # Example
+///
+/// ```
+/// use microfft::batch::rfft_interleaved;
+///
+/// let mut input = [[0.; 4]; 16];
+/// let result = rfft_interleaved(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfft_interleaved<const N: usize, const C: usize>(
+    input: &mut [[f32; C]; N],
+) -> &mut <Size<N> as RFftSize>::Half<[Complex32; C]>
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft_interleaved(flatten(input))
+}
+
+/// Perform in-place `N`-point RFFTs on a flat buffer of `N` interleaved
+/// frames of `C` channels.
+///
+/// Like [`rfft_interleaved`], but for a buffer of `N * C` values, whose
+/// length is checked at runtime.
+///
+/// # Panics
+///
+/// Panics if the length of `input` is not `N * C`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::batch::rfft_interleaved_flat;
+///
+/// let mut input = [0.; 16 * 4];
+/// let result = rfft_interleaved_flat::<16, 4>(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfft_interleaved_flat<const N: usize, const C: usize>(
+    input: &mut [f32],
+) -> &mut <Size<N> as RFftSize>::Half<[Complex32; C]>
+where
+    Size<N>: RFftSize,
+{
+    assert_eq!(input.len(), N * C, "input length must be N * C");
+    Size::<N>::rfft_interleaved(input)
+}
+
+/// Perform in-place `N`-point RFFTs on `N` interleaved frames of `C`
+/// channels of double-precision values.
+///
+/// The result holds `N/2` interleaved frames of the packed RFFT bins of each
+/// channel, with the real-valued Nyquist coefficient in the imaginary part
+/// of the DC bin, like [`real::rfft_f64`](crate::real::rfft_f64).
+///
+/// For mixed-radix sizes, each channel is transformed through a buffer of
+/// `N/2` complex values on the stack, which takes up `8 * N` bytes.
+///
+/// # Example
+///
+/// ```
+/// use microfft::batch::rfft_interleaved_f64;
+///
+/// let mut input = [[0.; 4]; 16];
+/// let result = rfft_interleaved_f64(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfft_interleaved_f64<const N: usize, const C: usize>(
+    input: &mut [[f64; C]; N],
+) -> &mut <Size<N> as RFftSize>::Half<[Complex64; C]>
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft_interleaved(flatten(input))
+}
+
+/// Perform in-place `N`-point RFFTs on a flat buffer of `N` interleaved
+/// frames of `C` channels of double-precision values.
+///
+/// Like [`rfft_interleaved_f64`], but for a buffer of `N * C` values, whose
+/// length is checked at runtime.
+///
+/// # Panics
+///
+/// Panics if the length of `input` is not `N * C`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::batch::rfft_interleaved_flat_f64;
+///
+/// let mut input = [0.; 16 * 4];
+/// let result = rfft_interleaved_flat_f64::<16, 4>(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfft_interleaved_flat_f64<const N: usize, const C: usize>(
+    input: &mut [f64],
+) -> &mut <Size<N> as RFftSize>::Half<[Complex64; C]>
+where
+    Size<N>: RFftSize,
+{
+    assert_eq!(input.len(), N * C, "input length must be N * C");
+    Size::<N>::rfft_interleaved(input)
+}
@@ -0,0 +1,115 @@
+//! DFT of arbitrary length (Bluestein's algorithm)
+//!
+//! The functions in this module compute the exact `N`-point DFT for any `N`,
+//! not only for the sizes supported by the FFTs in the other modules. This is
+//! useful for transforming inputs with, e.g., prime lengths without having to
+//! zero-pad them, which would change the frequency bins of the result.
+//!
+//! Internally, the DFT is expressed as a convolution, which is computed using
+//! power-of-two CFFTs of size `M`, the smallest power of two of at least
+//! `2N - 1`. That `M`-point CFFT must be enabled through the corresponding
+//! `size-*` feature. For example, a 251-point DFT requires `size-512`.
+//! Otherwise, the functions return an [`Error`] and leave the input
+//! unmodified.
+//!
+//! To stay allocation-free, the functions require a caller-provided scratch
+//! buffer of at least [`scratch_len(N)`](scratch_len) values.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{bluestein, Complex32};
+//!
+//! const N: usize = 127;
+//!
+//! let mut input = [Complex32::new(1., 0.); N];
+//! let mut scratch = [Complex32::default(); bluestein::scratch_len(N)];
+//! let result = bluestein::dft(&mut input, &mut scratch).unwrap();
+//! assert!((result[0].re - N as f32).abs() < 0.001);
+//! ```
+
+use crate::impls::bluestein;
+use crate::{Complex32, Complex64, Error};
+
+/// Return the minimum length of the scratch buffer required for an `n`-point
+/// DFT.
+#[must_use]
+pub const fn scratch_len(n: usize) -> usize {
+    2 * bluestein::fft_len(n)
+}
+
+/// Perform an in-place `N`-point DFT.
+///
+/// # Errors
+///
+/// Returns an error if the power-of-two CFFT required internally is not
+/// available.
+///
+/// # Panics
+///
+/// Panics if `scratch` is shorter than [`scratch_len(N)`](scratch_len).
+#[inline]
+pub fn dft<'a, const N: usize>(
+    input: &'a mut [Complex32; N],
+    scratch: &mut [Complex32],
+) -> Result<&'a mut [Complex32; N], Error> {
+    bluestein::dft(input, scratch)?;
+    Ok(input)
+}
+
+/// Perform an in-place `N`-point DFT on double-precision values.
+///
+/// # Errors
+///
+/// Returns an error if the power-of-two CFFT required internally is not
+/// available.
+///
+/// # Panics
+///
+/// Panics if `scratch` is shorter than [`scratch_len(N)`](scratch_len).
+#[inline]
+pub fn dft_f64<'a, const N: usize>(
+    input: &'a mut [Complex64; N],
+    scratch: &mut [Complex64],
+) -> Result<&'a mut [Complex64; N], Error> {
+    bluestein::dft(input, scratch)?;
+    Ok(input)
+}
+
+/// Perform an in-place `N`-point inverse DFT.
+///
+/// # Errors
+///
+/// Returns an error if the power-of-two CFFT required internally is not
+/// available.
+///
+/// # Panics
+///
+/// Panics if `scratch` is shorter than [`scratch_len(N)`](scratch_len).
+#[inline]
+pub fn idft<'a, const N: usize>(
+    input: &'a mut [Complex32; N],
+    scratch: &mut [Complex32],
+) -> Result<&'a mut [Complex32; N], Error> {
+    bluestein::idft(input, scratch)?;
+    Ok(input)
+}
+
+/// Perform an in-place `N`-point inverse DFT on double-precision values.
+///
+/// # Errors
+///
+/// Returns an error if the power-of-two CFFT required internally is not
+/// available.
+///
+/// # Panics
+///
+/// Panics if `scratch` is shorter than [`scratch_len(N)`](scratch_len).
+#[inline]
+pub fn idft_f64<'a, const N: usize>(
+    input: &'a mut [Complex64; N],
+    scratch: &mut [Complex64],
+) -> Result<&'a mut [Complex64; N], Error> {
+    bluestein::idft(input, scratch)?;
+    Ok(input)
+}
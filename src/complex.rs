@@ -1,10 +1,22 @@
 //! FFT on complex inputs (CFFT)
 
 use crate::impls::cfft::*;
-use crate::Complex32;
+use crate::impls::fft2d::COLUMNS;
+use crate::impls::qfft::*;
+use crate::normalization;
+use crate::size::{CFftImpl, CFftSize, Size};
+use crate::{Complex32, Complex64, ComplexQ15, ComplexQ31, Normalization};
 
 macro_rules! cfft_impls {
-    ( $( $N:expr => ($cfft_N:ident $(, $feature:expr)?), )* ) => {
+    (
+        $(
+            $N:expr => (
+                $cfft_N:ident,
+                $cfft_N_f64:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
         $(
             #[doc = concat!("Perform an in-place ", stringify!($N), "-point CFFT.")]
             #[doc = ""]
@@ -23,24 +35,368 @@ macro_rules! cfft_impls {
                 CFftN::<$N>::transform(input);
                 input
             }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point CFFT on double-precision values.")]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{Complex64, complex::", stringify!($cfft_N_f64), "};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [Complex64::default(); ", stringify!($N), "];")]
+            #[doc = concat!("let result = ", stringify!($cfft_N_f64), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $cfft_N_f64(input: &mut [Complex64; $N]) -> &mut [Complex64; $N] {
+                CFftN::<$N>::transform(input);
+                input
+            }
+        )*
+    };
+}
+
+macro_rules! fixed_cfft_impls {
+    (
+        $(
+            $N:expr => (
+                $cfft_N_q15:ident,
+                $cfft_N_q31:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
+        $(
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point CFFT on Q15 fixed-point values.")]
+            #[doc = ""]
+            #[doc = "Values are scaled down as needed during the computation to avoid overflows."]
+            #[doc = "Returns the result together with its block exponent `e`, meaning the actual"]
+            #[doc = "transform values are the returned values multiplied by `2^e`."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{ComplexQ15, complex::", stringify!($cfft_N_q15), "};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [ComplexQ15::default(); ", stringify!($N), "];")]
+            #[doc = concat!("let (result, exponent) = ", stringify!($cfft_N_q15), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $cfft_N_q15(input: &mut [ComplexQ15; $N]) -> (&mut [ComplexQ15; $N], i32) {
+                let exponent = QFftN::<$N>::cfft(input);
+                (input, exponent)
+            }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point CFFT on Q31 fixed-point values.")]
+            #[doc = ""]
+            #[doc = "Values are scaled down as needed during the computation to avoid overflows."]
+            #[doc = "Returns the result together with its block exponent `e`, meaning the actual"]
+            #[doc = "transform values are the returned values multiplied by `2^e`."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{ComplexQ31, complex::", stringify!($cfft_N_q31), "};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [ComplexQ31::default(); ", stringify!($N), "];")]
+            #[doc = concat!("let (result, exponent) = ", stringify!($cfft_N_q31), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $cfft_N_q31(input: &mut [ComplexQ31; $N]) -> (&mut [ComplexQ31; $N], i32) {
+                let exponent = QFftN::<$N>::cfft(input);
+                (input, exponent)
+            }
         )*
     };
 }
 
 cfft_impls! {
-    2 => (cfft_2),
-    4 => (cfft_4, "size-4"),
-    8 => (cfft_8, "size-8"),
-    16 => (cfft_16, "size-16"),
-    32 => (cfft_32, "size-32"),
-    64 => (cfft_64, "size-64"),
-    128 => (cfft_128, "size-128"),
-    256 => (cfft_256, "size-256"),
-    512 => (cfft_512, "size-512"),
-    1024 => (cfft_1024, "size-1024"),
-    2048 => (cfft_2048, "size-2048"),
-    4096 => (cfft_4096, "size-4096"),
-    8192 => (cfft_8192, "size-8192"),
-    16384 => (cfft_16384, "size-16384"),
-    32768 => (cfft_32768, "size-32768"),
+    2 => (cfft_2, cfft_2_f64),
+    4 => (cfft_4, cfft_4_f64, "size-4"),
+    8 => (cfft_8, cfft_8_f64, "size-8"),
+    16 => (cfft_16, cfft_16_f64, "size-16"),
+    32 => (cfft_32, cfft_32_f64, "size-32"),
+    64 => (cfft_64, cfft_64_f64, "size-64"),
+    128 => (cfft_128, cfft_128_f64, "size-128"),
+    256 => (cfft_256, cfft_256_f64, "size-256"),
+    512 => (cfft_512, cfft_512_f64, "size-512"),
+    1024 => (cfft_1024, cfft_1024_f64, "size-1024"),
+    2048 => (cfft_2048, cfft_2048_f64, "size-2048"),
+    4096 => (cfft_4096, cfft_4096_f64, "size-4096"),
+    8192 => (cfft_8192, cfft_8192_f64, "size-8192"),
+    16384 => (cfft_16384, cfft_16384_f64, "size-16384"),
+    32768 => (cfft_32768, cfft_32768_f64, "size-32768"),
+}
+
+cfft_impls! {
+    12 => (cfft_12, cfft_12_f64, "size-12"),
+    24 => (cfft_24, cfft_24_f64, "size-24"),
+    48 => (cfft_48, cfft_48_f64, "size-48"),
+    96 => (cfft_96, cfft_96_f64, "size-96"),
+    192 => (cfft_192, cfft_192_f64, "size-192"),
+    384 => (cfft_384, cfft_384_f64, "size-384"),
+    768 => (cfft_768, cfft_768_f64, "size-768"),
+    20 => (cfft_20, cfft_20_f64, "size-20"),
+    40 => (cfft_40, cfft_40_f64, "size-40"),
+    80 => (cfft_80, cfft_80_f64, "size-80"),
+    160 => (cfft_160, cfft_160_f64, "size-160"),
+    320 => (cfft_320, cfft_320_f64, "size-320"),
+    640 => (cfft_640, cfft_640_f64, "size-640"),
+    1280 => (cfft_1280, cfft_1280_f64, "size-1280"),
+    60 => (cfft_60, cfft_60_f64, "size-60"),
+    120 => (cfft_120, cfft_120_f64, "size-120"),
+    240 => (cfft_240, cfft_240_f64, "size-240"),
+    480 => (cfft_480, cfft_480_f64, "size-480"),
+    960 => (cfft_960, cfft_960_f64, "size-960"),
+    1920 => (cfft_1920, cfft_1920_f64, "size-1920"),
+    3840 => (cfft_3840, cfft_3840_f64, "size-3840"),
+}
+
+fixed_cfft_impls! {
+    2 => (cfft_2_q15, cfft_2_q31),
+    4 => (cfft_4_q15, cfft_4_q31, "size-4"),
+    8 => (cfft_8_q15, cfft_8_q31, "size-8"),
+    16 => (cfft_16_q15, cfft_16_q31, "size-16"),
+    32 => (cfft_32_q15, cfft_32_q31, "size-32"),
+    64 => (cfft_64_q15, cfft_64_q31, "size-64"),
+    128 => (cfft_128_q15, cfft_128_q31, "size-128"),
+    256 => (cfft_256_q15, cfft_256_q31, "size-256"),
+    512 => (cfft_512_q15, cfft_512_q31, "size-512"),
+    1024 => (cfft_1024_q15, cfft_1024_q31, "size-1024"),
+    2048 => (cfft_2048_q15, cfft_2048_q31, "size-2048"),
+    4096 => (cfft_4096_q15, cfft_4096_q31, "size-4096"),
+    8192 => (cfft_8192_q15, cfft_8192_q31, "size-8192"),
+    16384 => (cfft_16384_q15, cfft_16384_q31, "size-16384"),
+    32768 => (cfft_32768_q15, cfft_32768_q31, "size-32768"),
+}
+
+/// Perform an in-place `N`-point CFFT.
+///
+/// This is the const-generic equivalent of the `cfft_N` functions. It
+/// can be used with any `N` for which [`Size<N>`](Size) implements
+/// [`CFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, complex::cfft};
+///
+/// let mut input = [Complex32::default(); 16];
+/// let result = cfft(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn cfft<const N: usize>(input: &mut [Complex32; N]) -> &mut [Complex32; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::cfft(input);
+    input
+}
+
+/// Perform an in-place `N`-point CFFT on double-precision values.
+///
+/// This is the const-generic equivalent of the `cfft_N_f64` functions. It
+/// can be used with any `N` for which [`Size<N>`](Size) implements
+/// [`CFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, complex::cfft_f64};
+///
+/// let mut input = [Complex64::default(); 16];
+/// let result = cfft_f64(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn cfft_f64<const N: usize>(input: &mut [Complex64; N]) -> &mut [Complex64; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::cfft(input);
+    input
+}
+
+/// Perform an out-of-place `N`-point CFFT.
+///
+/// Like [`cfft`], but leaves `input` unmodified and writes the result into
+/// `output` instead.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, complex::cfft_into};
+///
+/// let input = [Complex32::default(); 16];
+/// let mut output = [Complex32::default(); 16];
+/// let result = cfft_into(&input, &mut output);
+/// ```
+#[inline]
+pub fn cfft_into<'a, const N: usize>(
+    input: &[Complex32; N],
+    output: &'a mut [Complex32; N],
+) -> &'a mut [Complex32; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::cfft_into(input, output);
+    output
+}
+
+/// Perform an out-of-place `N`-point CFFT on double-precision values.
+///
+/// Like [`cfft_f64`], but leaves `input` unmodified and writes the result into
+/// `output` instead.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, complex::cfft_into_f64};
+///
+/// let input = [Complex64::default(); 16];
+/// let mut output = [Complex64::default(); 16];
+/// let result = cfft_into_f64(&input, &mut output);
+/// ```
+#[inline]
+pub fn cfft_into_f64<'a, const N: usize>(
+    input: &[Complex64; N],
+    output: &'a mut [Complex64; N],
+) -> &'a mut [Complex64; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::cfft_into(input, output);
+    output
+}
+
+/// Perform an in-place `N`-point CFFT, using the given normalization.
+///
+/// Like [`cfft`], but scales the result according to the forward
+/// transform part of `normalization`.
+/// If no scaling is required, no additional pass over the data is performed.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, Normalization, complex::cfft_normalized};
+///
+/// let mut input = [Complex32::default(); 16];
+/// let result = cfft_normalized(&mut input, Normalization::Orthonormal);
+/// ```
+#[inline]
+#[must_use]
+pub fn cfft_normalized<const N: usize>(
+    input: &mut [Complex32; N],
+    normalization: Normalization,
+) -> &mut [Complex32; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::cfft(input);
+    normalization::scale(input, normalization.forward_scale(N));
+    input
+}
+
+/// Perform an in-place `N`-point CFFT on double-precision values, using the given normalization.
+///
+/// Like [`cfft_f64`], but scales the result according to the forward
+/// transform part of `normalization`.
+/// If no scaling is required, no additional pass over the data is performed.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, Normalization, complex::cfft_normalized_f64};
+///
+/// let mut input = [Complex64::default(); 16];
+/// let result = cfft_normalized_f64(&mut input, Normalization::Orthonormal);
+/// ```
+#[inline]
+#[must_use]
+pub fn cfft_normalized_f64<const N: usize>(
+    input: &mut [Complex64; N],
+    normalization: Normalization,
+) -> &mut [Complex64; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::cfft(input);
+    normalization::scale(input, normalization.forward_scale(N));
+    input
+}
+
+/// Perform an in-place 2D CFFT on `H` rows of `W` values each.
+///
+/// The rows are transformed first, followed by the columns. The columns are
+/// transformed in blocks of 4 adjacent columns through a buffer of `4 * H`
+/// values on the stack, rather than by transposing the whole input. This
+/// buffer takes up `32 * H` bytes.
+///
+/// It can be used with any `W` and `H` for which [`Size<W>`](Size) and
+/// [`Size<H>`](Size) implement [`CFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, complex::fft2d};
+///
+/// let mut input = [[Complex32::default(); 32]; 16];
+/// let result = fft2d(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn fft2d<const W: usize, const H: usize>(
+    input: &mut [[Complex32; W]; H],
+) -> &mut [[Complex32; W]; H]
+where
+    Size<W>: CFftSize,
+    Size<H>: CFftSize,
+{
+    for row in input.iter_mut() {
+        Size::<W>::cfft(row);
+    }
+    let mut columns = [[Complex32::default(); H]; COLUMNS];
+    Size::<H>::cfft_columns(input, &mut columns);
+    input
+}
+
+/// Perform an in-place 2D CFFT on `H` rows of `W` double-precision values each.
+///
+/// The rows are transformed first, followed by the columns. The columns are
+/// transformed in blocks of 4 adjacent columns through a buffer of `4 * H`
+/// values on the stack, rather than by transposing the whole input. This
+/// buffer takes up `64 * H` bytes.
+///
+/// It can be used with any `W` and `H` for which [`Size<W>`](Size) and
+/// [`Size<H>`](Size) implement [`CFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, complex::fft2d_f64};
+///
+/// let mut input = [[Complex64::default(); 32]; 16];
+/// let result = fft2d_f64(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn fft2d_f64<const W: usize, const H: usize>(
+    input: &mut [[Complex64; W]; H],
+) -> &mut [[Complex64; W]; H]
+where
+    Size<W>: CFftSize,
+    Size<H>: CFftSize,
+{
+    for row in input.iter_mut() {
+        Size::<W>::cfft(row);
+    }
+    let mut columns = [[Complex64::default(); H]; COLUMNS];
+    Size::<H>::cfft_columns(input, &mut columns);
+    input
 }
@@ -0,0 +1,182 @@
+//! Chirp-Z transform and zoom FFT
+//!
+//! The chirp-Z transform (CZT) evaluates the z-transform of `N` input values
+//! at `M` points `z_k` on a [`Contour`]:
+//!
+//! ```text
+//! X[k] = sum_n x[n] * z_k^-n
+//! ```
+//!
+//! The number of points `M` is independent of `N`. On an arc of the unit
+//! circle, this computes the DFT at `M` arbitrary, evenly spaced frequencies,
+//! which allows sampling a narrow frequency band much more densely than the
+//! bins of an `N`-point FFT. [`zoom_fft`] does this for a given frequency
+//! range. Note that the ability to separate close peaks still depends on the
+//! duration of the input.
+//!
+//! Internally, the CZT is expressed as a convolution, which is computed
+//! using power-of-two CFFTs of size `L`, the smallest power of two of at
+//! least `N + M - 1`. That `L`-point CFFT must be enabled through the
+//! corresponding `size-*` feature, otherwise the functions return an
+//! [`Error`]. To stay allocation-free, the functions require a
+//! caller-provided scratch buffer of at least
+//! [`scratch_len(N, M)`](scratch_len) values.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{czt, Complex32};
+//!
+//! // two tones at 49 Hz and 51 Hz, sampled at 1 kHz
+//! let signal: Vec<f32> = (0..1000)
+//!     .map(|i| {
+//!         let t = i as f32 / 1000.;
+//!         (2. * core::f32::consts::PI * 49. * t).sin()
+//!             + (2. * core::f32::consts::PI * 51. * t).sin()
+//!     })
+//!     .collect();
+//!
+//! // 41 points from 48 Hz to 52 Hz, i.e. a resolution of 0.1 Hz
+//! let mut output = [Complex32::default(); 41];
+//! let mut scratch = vec![Complex32::default(); czt::scratch_len(1000, 41)];
+//! let spectrum = czt::zoom_fft(&signal, &mut output, 48., 52., 1000., &mut scratch).unwrap();
+//!
+//! assert!(spectrum[10].norm() > 450.); // 49 Hz
+//! assert!(spectrum[20].norm() < 10.); // 50 Hz
+//! assert!(spectrum[30].norm() > 450.); // 51 Hz
+//! ```
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::czt::{self, Spiral};
+use crate::{Complex32, Complex64, Error};
+
+/// Return the minimum length of the scratch buffer required for a CZT of
+/// `n` input values at `m` points.
+#[must_use]
+pub const fn scratch_len(n: usize, m: usize) -> usize {
+    2 * czt::fft_len(n, m)
+}
+
+/// The points `z_k = A * W^-k` at which the chirp-Z transform is evaluated.
+///
+/// The contour starts at `A` and each point is obtained from the previous
+/// one by multiplying it with `W^-1`. In general, this is a spiral; if both
+/// have a magnitude of one, it is an arc of the unit circle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contour<T> {
+    start_radius: T,
+    start_angle: T,
+    step_radius: T,
+    step_angle: T,
+}
+
+impl<T: Float> Contour<T> {
+    /// The spiral contour `z_k = r_0 * r^k * exp(i*(phi_0 + k*phi))`, with
+    /// `r_0 = start_radius`, `phi_0 = start_angle`, `r = step_radius` and
+    /// `phi = step_angle`, with angles in radians.
+    ///
+    /// Radii other than one quickly lead to very large or very small
+    /// intermediate values, so they should only be close to one.
+    pub fn new(start_radius: T, start_angle: T, step_radius: T, step_angle: T) -> Self {
+        Self {
+            start_radius,
+            start_angle,
+            step_radius,
+            step_angle,
+        }
+    }
+
+    /// The arc of the unit circle `z_k = exp(i*(start_angle + k*step_angle))`,
+    /// with angles in radians.
+    pub fn arc(start_angle: T, step_angle: T) -> Self {
+        Self::new(T::one(), start_angle, T::one(), step_angle)
+    }
+
+    /// The arc of the unit circle corresponding to `points` evenly spaced
+    /// frequencies from `start` to `stop` (inclusive), for the given
+    /// `sample_rate`.
+    pub fn frequencies(start: T, stop: T, sample_rate: T, points: usize) -> Self {
+        let to_angle = |f: T| T::from_f64(2. * core::f64::consts::PI * f.to_f64()) / sample_rate;
+        let step = if points > 1 {
+            (stop - start) / T::from_usize(points - 1)
+        } else {
+            T::zero()
+        };
+        Self::arc(to_angle(start), to_angle(step))
+    }
+
+    #[inline]
+    fn spiral(&self) -> Spiral {
+        Spiral {
+            a_radius: self.start_radius.to_f64(),
+            a_angle: self.start_angle.to_f64(),
+            w_radius: self.step_radius.to_f64(),
+            w_angle: self.step_angle.to_f64(),
+        }
+    }
+}
+
+macro_rules! czt_impls {
+    ($T:ty, $C:ty, $czt:ident, $zoom_fft:ident, $suffix:expr) => {
+        #[doc = concat!("Perform a chirp-Z transform", $suffix, ".")]
+        #[doc = ""]
+        #[doc = "Evaluates the z-transform of `input` at the `output.len()` points of `contour`."]
+        #[doc = ""]
+        #[doc = "# Errors"]
+        #[doc = ""]
+        #[doc = "Returns an error if the power-of-two CFFT required internally is not available."]
+        #[doc = ""]
+        #[doc = "# Panics"]
+        #[doc = ""]
+        #[doc = "Panics if `scratch` is shorter than [`scratch_len(input.len(), output.len())`](scratch_len)."]
+        #[inline]
+        pub fn $czt<'a>(
+            input: &[$C],
+            output: &'a mut [$C],
+            contour: Contour<$T>,
+            scratch: &mut [$C],
+        ) -> Result<&'a mut [$C], Error> {
+            czt::czt(|n| input[n], input.len(), output, contour.spiral(), scratch)?;
+            Ok(output)
+        }
+
+        #[doc = concat!("Compute the DFT of the real `input` at evenly spaced frequencies", $suffix, ".")]
+        #[doc = ""]
+        #[doc = "Fills `output` with the DFT at `output.len()` frequencies from `start` to `stop`"]
+        #[doc = "(inclusive), for the given `sample_rate`. At a frequency `k * sample_rate / N`,"]
+        #[doc = "the result is equal to bin `k` of the `N`-point FFT of `input`."]
+        #[doc = ""]
+        #[doc = "# Errors"]
+        #[doc = ""]
+        #[doc = "Returns an error if the power-of-two CFFT required internally is not available."]
+        #[doc = ""]
+        #[doc = "# Panics"]
+        #[doc = ""]
+        #[doc = "Panics if `scratch` is shorter than [`scratch_len(input.len(), output.len())`](scratch_len)."]
+        #[inline]
+        pub fn $zoom_fft<'a>(
+            input: &[$T],
+            output: &'a mut [$C],
+            start: $T,
+            stop: $T,
+            sample_rate: $T,
+            scratch: &mut [$C],
+        ) -> Result<&'a mut [$C], Error> {
+            let contour = Contour::frequencies(start, stop, sample_rate, output.len());
+            let x = |n: usize| Complex::new(input[n], 0.);
+            czt::czt(x, input.len(), output, contour.spiral(), scratch)?;
+            Ok(output)
+        }
+    };
+}
+
+czt_impls!(f32, Complex32, czt, zoom_fft, "");
+czt_impls!(
+    f64,
+    Complex64,
+    czt_f64,
+    zoom_fft_f64,
+    " on double-precision values"
+);
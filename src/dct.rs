@@ -0,0 +1,102 @@
+//! Discrete cosine and sine transforms (DCT/DST)
+//!
+//! The transforms are computed in place using an `N`-point RFFT, plus
+//! `O(N)` pre- and post-processing. They are available for the same
+//! power-of-two sizes as the RFFT.
+//!
+//! The following definitions are used, for `k` in `0..N`:
+//!
+//! ```text
+//! DCT-II:  X[k] = sum_n x[n] * cos(PI * k * (2n + 1) / (2N))
+//! DST-II:  X[k] = sum_n x[n] * sin(PI * (k + 1) * (2n + 1) / (2N))
+//! ```
+//!
+//! Like the FFTs in this crate, the forward transforms are unscaled. The
+//! DCT-III and DST-III are scaled to be the exact inverses of the DCT-II and
+//! DST-II, respectively:
+//!
+//! ```text
+//! DCT-III: x[n] = (X[0] + 2 * sum_{k>0} X[k] * cos(PI * k * (2n + 1) / (2N))) / N
+//! DST-III: x[n] = ((-1)^n * X[N-1] + 2 * sum_{k<N-1} X[k] * sin(PI * (k + 1) * (2n + 1) / (2N))) / N
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::dct;
+//!
+//! let mut input = [1.; 16];
+//! let coefficients = dct::dct2_16(&mut input);
+//! assert_eq!(coefficients[0], 16.);
+//!
+//! let recovered = dct::dct3_16(coefficients);
+//! assert!(recovered.iter().all(|x| (x - 1.).abs() < 1e-6));
+//! ```
+
+use crate::impls::dct::*;
+
+macro_rules! dct_impls {
+    (
+        $(
+            $N:expr => (
+                $dct2_N:ident,
+                $dct3_N:ident,
+                $dst2_N:ident,
+                $dst3_N:ident,
+                $dct2_N_f64:ident,
+                $dct3_N_f64:ident,
+                $dst2_N_f64:ident,
+                $dst3_N_f64:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
+        $(
+            dct_impls!(@fn f32, $N, $dct2_N, dct2, "DCT-II", "" $(, $feature)?);
+            dct_impls!(@fn f32, $N, $dct3_N, dct3, "DCT-III", "" $(, $feature)?);
+            dct_impls!(@fn f32, $N, $dst2_N, dst2, "DST-II", "" $(, $feature)?);
+            dct_impls!(@fn f32, $N, $dst3_N, dst3, "DST-III", "" $(, $feature)?);
+            dct_impls!(@fn f64, $N, $dct2_N_f64, dct2, "DCT-II", " on double-precision values" $(, $feature)?);
+            dct_impls!(@fn f64, $N, $dct3_N_f64, dct3, "DCT-III", " on double-precision values" $(, $feature)?);
+            dct_impls!(@fn f64, $N, $dst2_N_f64, dst2, "DST-II", " on double-precision values" $(, $feature)?);
+            dct_impls!(@fn f64, $N, $dst3_N_f64, dst3, "DST-III", " on double-precision values" $(, $feature)?);
+        )*
+    };
+    (@fn $T:ty, $N:expr, $name:ident, $method:ident, $kind:expr, $suffix:expr $(, $feature:expr)?) => {
+        #[doc = concat!("Perform an in-place ", stringify!($N), "-point ", $kind, $suffix, ".")]
+        #[doc = ""]
+        #[doc = "# Example"]
+        #[doc = ""]
+        #[doc = "```"]
+        #[doc = concat!("use microfft::dct::", stringify!($name), ";")]
+        #[doc = ""]
+        #[doc = concat!("let mut input = [0.; ", stringify!($N), "];")]
+        #[doc = concat!("let result = ", stringify!($name), "(&mut input);")]
+        #[doc = "```"]
+        $( #[cfg(feature = $feature)] )?
+        #[inline]
+        #[must_use]
+        pub fn $name(input: &mut [$T; $N]) -> &mut [$T; $N] {
+            DctN::<$N>::$method(input);
+            input
+        }
+    };
+}
+
+dct_impls! {
+    2 => (dct2_2, dct3_2, dst2_2, dst3_2, dct2_2_f64, dct3_2_f64, dst2_2_f64, dst3_2_f64),
+    4 => (dct2_4, dct3_4, dst2_4, dst3_4, dct2_4_f64, dct3_4_f64, dst2_4_f64, dst3_4_f64),
+    8 => (dct2_8, dct3_8, dst2_8, dst3_8, dct2_8_f64, dct3_8_f64, dst2_8_f64, dst3_8_f64, "size-4"),
+    16 => (dct2_16, dct3_16, dst2_16, dst3_16, dct2_16_f64, dct3_16_f64, dst2_16_f64, dst3_16_f64, "size-8"),
+    32 => (dct2_32, dct3_32, dst2_32, dst3_32, dct2_32_f64, dct3_32_f64, dst2_32_f64, dst3_32_f64, "size-16"),
+    64 => (dct2_64, dct3_64, dst2_64, dst3_64, dct2_64_f64, dct3_64_f64, dst2_64_f64, dst3_64_f64, "size-32"),
+    128 => (dct2_128, dct3_128, dst2_128, dst3_128, dct2_128_f64, dct3_128_f64, dst2_128_f64, dst3_128_f64, "size-64"),
+    256 => (dct2_256, dct3_256, dst2_256, dst3_256, dct2_256_f64, dct3_256_f64, dst2_256_f64, dst3_256_f64, "size-128"),
+    512 => (dct2_512, dct3_512, dst2_512, dst3_512, dct2_512_f64, dct3_512_f64, dst2_512_f64, dst3_512_f64, "size-256"),
+    1024 => (dct2_1024, dct3_1024, dst2_1024, dst3_1024, dct2_1024_f64, dct3_1024_f64, dst2_1024_f64, dst3_1024_f64, "size-512"),
+    2048 => (dct2_2048, dct3_2048, dst2_2048, dst3_2048, dct2_2048_f64, dct3_2048_f64, dst2_2048_f64, dst3_2048_f64, "size-1024"),
+    4096 => (dct2_4096, dct3_4096, dst2_4096, dst3_4096, dct2_4096_f64, dct3_4096_f64, dst2_4096_f64, dst3_4096_f64, "size-2048"),
+    8192 => (dct2_8192, dct3_8192, dst2_8192, dst3_8192, dct2_8192_f64, dct3_8192_f64, dst2_8192_f64, dst3_8192_f64, "size-4096"),
+    16384 => (dct2_16384, dct3_16384, dst2_16384, dst3_16384, dct2_16384_f64, dct3_16384_f64, dst2_16384_f64, dst3_16384_f64, "size-8192"),
+    32768 => (dct2_32768, dct3_32768, dst2_32768, dst3_32768, dct2_32768_f64, dct3_32768_f64, dst2_32768_f64, dst3_32768_f64, "size-16384"),
+}
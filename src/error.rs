@@ -0,0 +1,34 @@
+use core::fmt;
+
+/// Errors returned by the transforms on runtime-sized inputs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The transform size is not a power of two, nor one of the supported
+    /// mixed-radix sizes `3 * 2^k`, `5 * 2^k` and `15 * 2^k` with `k` from 2
+    /// to 8. Real-valued transforms additionally require a size of at least
+    /// 2.
+    UnsupportedSize,
+    /// The transform size is supported, but the corresponding `size-*`
+    /// feature is not enabled.
+    SizeNotEnabled,
+    /// The transform size is larger than the largest size supported by the
+    /// sine tables microfft can be compiled with.
+    TooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::UnsupportedSize => "FFT size is not supported",
+            Self::SizeNotEnabled => "FFT size is not enabled",
+            Self::TooLarge => "FFT size is too large",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
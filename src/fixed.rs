@@ -0,0 +1,58 @@
+use num_traits::{PrimInt, Signed};
+
+use crate::tables;
+
+/// Fixed-point types the FFT implementations can operate on.
+///
+/// Values are interpreted as signed fractions in the range `[-1, 1)`, with
+/// `FRAC_BITS` fractional bits.
+pub(crate) trait Fixed: Copy + 'static {
+    /// A type wide enough to hold the product of two values without overflow.
+    type Wide: PrimInt + Signed;
+
+    const FRAC_BITS: usize;
+
+    /// The sine table used for looking up twiddle factors.
+    const SINE: &'static [Self];
+
+    fn widen(self) -> Self::Wide;
+
+    /// Narrow a value back, saturating if it is out of range.
+    fn narrow(x: Self::Wide) -> Self;
+}
+
+impl Fixed for i16 {
+    type Wide = i32;
+
+    const FRAC_BITS: usize = 15;
+
+    const SINE: &'static [Self] = tables::SINE_Q15;
+
+    #[inline]
+    fn widen(self) -> i32 {
+        self.into()
+    }
+
+    #[inline]
+    fn narrow(x: i32) -> Self {
+        x.clamp(i16::MIN.into(), i16::MAX.into()) as i16
+    }
+}
+
+impl Fixed for i32 {
+    type Wide = i64;
+
+    const FRAC_BITS: usize = 31;
+
+    const SINE: &'static [Self] = tables::SINE_Q31;
+
+    #[inline]
+    fn widen(self) -> i64 {
+        self.into()
+    }
+
+    #[inline]
+    fn narrow(x: i64) -> Self {
+        x.clamp(i32::MIN.into(), i32::MAX.into()) as i32
+    }
+}
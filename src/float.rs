@@ -0,0 +1,95 @@
+use core::ops::Neg;
+
+use num_traits::{Num, NumAssign};
+
+use crate::{math, tables};
+
+/// Floating-point types the FFT implementations can operate on.
+///
+/// This trait is only `pub` so it can be used in the bounds of the public
+/// [`size`](crate::size) traits. It is not reachable from outside the crate.
+pub trait Float: Copy + Num + NumAssign + Neg<Output = Self> + 'static {
+    /// The sine table used for looking up twiddle factors.
+    const SINE: &'static [Self];
+
+    /// The sine table used for looking up mixed-radix twiddle factors.
+    const SINE_MIXED: &'static [Self];
+
+    const TWO: Self;
+
+    fn from_usize(n: usize) -> Self;
+
+    fn from_f64(x: f64) -> Self;
+
+    fn to_f64(self) -> f64;
+
+    /// Compute the square root, which must not be negative.
+    fn sqrt(self) -> Self;
+
+    /// Compute the four-quadrant arctangent of `self / x`, in `[-PI, PI]`.
+    fn atan2(self, x: Self) -> Self;
+}
+
+impl Float for f32 {
+    const SINE: &'static [Self] = tables::SINE;
+    const SINE_MIXED: &'static [Self] = tables::SINE_MIXED;
+
+    const TWO: Self = 2.;
+
+    #[inline]
+    fn from_usize(n: usize) -> Self {
+        n as f32
+    }
+
+    #[inline]
+    fn from_f64(x: f64) -> Self {
+        x as f32
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.into()
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        math::sqrt_f32(self)
+    }
+
+    #[inline]
+    fn atan2(self, x: Self) -> Self {
+        math::atan2_f32(self, x)
+    }
+}
+
+impl Float for f64 {
+    const SINE: &'static [Self] = tables::SINE_F64;
+    const SINE_MIXED: &'static [Self] = tables::SINE_MIXED_F64;
+
+    const TWO: Self = 2.;
+
+    #[inline]
+    fn from_usize(n: usize) -> Self {
+        n as f64
+    }
+
+    #[inline]
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        math::sqrt(self)
+    }
+
+    #[inline]
+    fn atan2(self, x: Self) -> Self {
+        math::atan2(self, x)
+    }
+}
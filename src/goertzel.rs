@@ -0,0 +1,296 @@
+//! Goertzel filters for single DFT bins
+//!
+//! If only a few frequencies of a signal are of interest, e.g. for tone
+//! detection, computing them with the Goertzel algorithm is cheaper than
+//! computing a full RFFT. Each sample costs a single real multiplication per
+//! bin, and the filters process their input sample by sample, without
+//! buffering it.
+//!
+//! A [`Goertzel`] filter computes the DFT of a block of `len` samples at a
+//! single bin, which does not have to be an integer:
+//!
+//! ```text
+//! X = sum_n x[n] * exp(-2*PI*i * bin * n / len)
+//! ```
+//!
+//! For an integer `bin < len/2`, this is exactly the value of bin `bin` of
+//! the RFFT of the block, as returned by `real::rfft_N`, so results can be
+//! cross-checked against those. Note that the RFFT packs the Nyquist
+//! coefficient into the DC bin; [`RealSpectrum::bin`](crate::RealSpectrum::bin)
+//! undoes this packing.
+//!
+//! A [`GoertzelBank`] runs several filters on the same samples.
+//!
+//! The filter state is accumulated with the precision of the samples. For
+//! long blocks, bins close to DC or the Nyquist frequency lose accuracy in
+//! single precision, so double-precision samples should be preferred there.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::goertzel::Goertzel;
+//!
+//! // detect a 1 kHz tone in blocks of 200 samples at 8 kHz
+//! let mut filter = Goertzel::from_frequency(1000., 8000., 200);
+//!
+//! for i in 0..200 {
+//!     let t = i as f32 / 8000.;
+//!     let sample = (2. * core::f32::consts::PI * 1000. * t).sin();
+//!     if let Some(bin) = filter.push(sample) {
+//!         // a sine of amplitude 1 results in a magnitude of `len / 2`
+//!         assert!((bin.norm() - 100.).abs() < 0.1);
+//!     }
+//! }
+//! ```
+
+use core::f64::consts::PI;
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::math;
+
+/// A streaming Goertzel filter for a single DFT bin.
+///
+/// The filter computes the DFT at `bin` of consecutive blocks of `len`
+/// samples. Samples are fed with [`push`](Self::push), which returns the
+/// result after each complete block and then starts the next block.
+#[derive(Clone, Debug)]
+pub struct Goertzel<T> {
+    len: usize,
+    bin: f64,
+    coeff: T,
+    cos: T,
+    sin: T,
+    s1: T,
+    s2: T,
+    count: usize,
+}
+
+impl<T: Float> Goertzel<T> {
+    /// Create a filter for the (possibly non-integer) `bin` of blocks of
+    /// `len` samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is zero.
+    pub fn new(bin: T, len: usize) -> Self {
+        assert!(len > 0, "block length must not be zero");
+
+        let bin = bin.to_f64();
+        let (sin, cos) = math::sin_cos(2. * PI * bin / len as f64);
+        Self {
+            len,
+            bin,
+            coeff: T::from_f64(2. * cos),
+            cos: T::from_f64(cos),
+            sin: T::from_f64(sin),
+            s1: T::zero(),
+            s2: T::zero(),
+            count: 0,
+        }
+    }
+
+    /// Create a filter for `frequency`, for blocks of `len` samples at
+    /// `sample_rate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is zero.
+    pub fn from_frequency(frequency: T, sample_rate: T, len: usize) -> Self {
+        Self::new(frequency * T::from_usize(len) / sample_rate, len)
+    }
+
+    /// The block length.
+    #[inline]
+    pub fn block_len(&self) -> usize {
+        self.len
+    }
+
+    /// The number of samples of the current block that have been pushed.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Feed a sample into the filter.
+    ///
+    /// Returns the DFT bin once `len` samples have been pushed, and resets
+    /// the filter for the next block.
+    #[inline]
+    pub fn push(&mut self, sample: T) -> Option<Complex<T>> {
+        self.update(sample);
+        if self.count == self.len {
+            let result = self.result();
+            self.reset();
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// The DFT bin of the samples pushed so far.
+    ///
+    /// Samples that have not been pushed yet are treated as zero.
+    pub fn result(&self) -> Complex<T> {
+        if self.count == 0 {
+            return Complex::new(T::zero(), T::zero());
+        }
+
+        // The filter output is the DFT of the samples, rotated by the phase
+        // of the last sample. Remove that rotation, reducing the phase to
+        // whole turns first, so it stays accurate for long blocks.
+        let y = Complex::new(self.s1 - self.cos * self.s2, self.sin * self.s2);
+        let turns = self.bin * (self.count - 1) as f64 / self.len as f64;
+        let turns = turns - turns as i64 as f64;
+        let (sin, cos) = math::sin_cos(-2. * PI * turns);
+        y * Complex::new(T::from_f64(cos), T::from_f64(sin))
+    }
+
+    /// The squared magnitude of the DFT bin of the samples pushed so far.
+    ///
+    /// This is cheaper than computing it from [`result`](Self::result).
+    #[inline]
+    pub fn power(&self) -> T {
+        self.s1 * self.s1 + self.s2 * self.s2 - self.coeff * self.s1 * self.s2
+    }
+
+    /// Discard the samples of the current block.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.s1 = T::zero();
+        self.s2 = T::zero();
+        self.count = 0;
+    }
+
+    #[inline]
+    fn update(&mut self, sample: T) {
+        let s0 = sample + self.coeff * self.s1 - self.s2;
+        self.s2 = self.s1;
+        self.s1 = s0;
+        self.count += 1;
+    }
+}
+
+/// A bank of `K` streaming Goertzel filters on the same samples.
+///
+/// All filters share the block length, so they complete their blocks
+/// together.
+///
+/// # Example
+///
+/// ```
+/// use microfft::goertzel::GoertzelBank;
+///
+/// // DTMF row frequencies
+/// let mut bank = GoertzelBank::from_frequencies([697., 770., 852., 941.], 8000., 205);
+///
+/// for i in 0..205 {
+///     let t = i as f32 / 8000.;
+///     let sample = (2. * core::f32::consts::PI * 770. * t).sin();
+///     if let Some(bins) = bank.push(sample) {
+///         let loudest = (0..4)
+///             .max_by(|&a, &b| bins[a].norm().total_cmp(&bins[b].norm()))
+///             .unwrap();
+///         assert_eq!(loudest, 1);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GoertzelBank<T, const K: usize> {
+    filters: [Goertzel<T>; K],
+}
+
+impl<T: Float, const K: usize> GoertzelBank<T, K> {
+    /// Create filters for the (possibly non-integer) `bins` of blocks of
+    /// `len` samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is zero.
+    pub fn new(bins: [T; K], len: usize) -> Self {
+        Self {
+            filters: bins.map(|bin| Goertzel::new(bin, len)),
+        }
+    }
+
+    /// Create filters for `frequencies`, for blocks of `len` samples at
+    /// `sample_rate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is zero.
+    pub fn from_frequencies(frequencies: [T; K], sample_rate: T, len: usize) -> Self {
+        Self {
+            filters: frequencies.map(|f| Goertzel::from_frequency(f, sample_rate, len)),
+        }
+    }
+
+    /// The filters of the bank.
+    #[inline]
+    pub fn filters(&self) -> &[Goertzel<T>; K] {
+        &self.filters
+    }
+
+    /// Feed a sample into all filters.
+    ///
+    /// Returns the DFT bins once a complete block has been pushed, and
+    /// resets the filters for the next block.
+    #[inline]
+    pub fn push(&mut self, sample: T) -> Option<[Complex<T>; K]> {
+        for filter in self.filters.iter_mut() {
+            filter.update(sample);
+        }
+
+        match self.filters.first() {
+            Some(filter) if filter.count == filter.len => {
+                let results = self.results();
+                self.reset();
+                Some(results)
+            }
+            _ => None,
+        }
+    }
+
+    /// The DFT bins of the samples pushed so far.
+    pub fn results(&self) -> [Complex<T>; K] {
+        core::array::from_fn(|k| self.filters[k].result())
+    }
+
+    /// The squared magnitudes of the DFT bins of the samples pushed so far.
+    pub fn powers(&self) -> [T; K] {
+        core::array::from_fn(|k| self.filters[k].power())
+    }
+
+    /// Discard the samples of the current block.
+    #[inline]
+    pub fn reset(&mut self) {
+        for filter in self.filters.iter_mut() {
+            filter.reset();
+        }
+    }
+}
+
+/// Compute the DFT of `samples` at the (possibly non-integer) `bin`, using
+/// the Goertzel algorithm.
+///
+/// # Example
+///
+/// ```
+/// use microfft::goertzel::dft_bin;
+///
+/// let samples: [f32; 4] = [1., 0., -1., 0.];
+/// let bin = dft_bin(&samples, 1.);
+/// assert!((bin.re - 2.).abs() < 1e-6 && bin.im.abs() < 1e-6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `samples` is empty.
+pub fn dft_bin<T: Float>(samples: &[T], bin: T) -> Complex<T> {
+    let mut filter = Goertzel::new(bin, samples.len());
+    for sample in samples {
+        filter.update(*sample);
+    }
+    filter.result()
+}
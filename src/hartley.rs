@@ -0,0 +1,123 @@
+//! Discrete Hartley transform (DHT)
+//!
+//! The DHT maps real values to real values:
+//!
+//! ```text
+//! H[k] = sum_n x[n] * (cos(2*PI*k*n/N) + sin(2*PI*k*n/N))
+//! ```
+//!
+//! It is computed in place using an `N`-point RFFT, plus `O(N)`
+//! post-processing, and is available for the same sizes as the RFFT.
+//!
+//! The DHT is its own inverse, up to a factor of `N`. Applying it twice
+//! yields the original values multiplied by `N`, so the same functions can be
+//! used for both directions.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::hartley;
+//!
+//! let mut input = [1., 2., 3., 4., 5., 6., 7., 8.];
+//! let original = input;
+//!
+//! let spectrum = hartley::dht_8(&mut input);
+//! let recovered = hartley::dht_8(spectrum);
+//! for (x, y) in original.iter().zip(recovered.iter()) {
+//!     assert!((x - y / 8.).abs() < 1e-5);
+//! }
+//! ```
+
+use crate::impls::hartley::*;
+
+macro_rules! dht_impls {
+    (
+        $(
+            $N:expr => (
+                $dht_N:ident,
+                $dht_N_f64:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
+        $(
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point DHT.")]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::hartley::", stringify!($dht_N), ";")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [0.; ", stringify!($N), "];")]
+            #[doc = concat!("let result = ", stringify!($dht_N), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $dht_N(input: &mut [f32; $N]) -> &mut [f32; $N] {
+                DhtN::<$N>::transform(input);
+                input
+            }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point DHT on double-precision values.")]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::hartley::", stringify!($dht_N_f64), ";")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [0.; ", stringify!($N), "];")]
+            #[doc = concat!("let result = ", stringify!($dht_N_f64), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $dht_N_f64(input: &mut [f64; $N]) -> &mut [f64; $N] {
+                DhtN::<$N>::transform(input);
+                input
+            }
+        )*
+    };
+}
+
+dht_impls! {
+    2 => (dht_2, dht_2_f64),
+    4 => (dht_4, dht_4_f64),
+    8 => (dht_8, dht_8_f64, "size-4"),
+    16 => (dht_16, dht_16_f64, "size-8"),
+    32 => (dht_32, dht_32_f64, "size-16"),
+    64 => (dht_64, dht_64_f64, "size-32"),
+    128 => (dht_128, dht_128_f64, "size-64"),
+    256 => (dht_256, dht_256_f64, "size-128"),
+    512 => (dht_512, dht_512_f64, "size-256"),
+    1024 => (dht_1024, dht_1024_f64, "size-512"),
+    2048 => (dht_2048, dht_2048_f64, "size-1024"),
+    4096 => (dht_4096, dht_4096_f64, "size-2048"),
+    8192 => (dht_8192, dht_8192_f64, "size-4096"),
+    16384 => (dht_16384, dht_16384_f64, "size-8192"),
+    32768 => (dht_32768, dht_32768_f64, "size-16384"),
+}
+
+dht_impls! {
+    12 => (dht_12, dht_12_f64, "size-12"),
+    24 => (dht_24, dht_24_f64, "size-24"),
+    48 => (dht_48, dht_48_f64, "size-48"),
+    96 => (dht_96, dht_96_f64, "size-96"),
+    192 => (dht_192, dht_192_f64, "size-192"),
+    384 => (dht_384, dht_384_f64, "size-384"),
+    768 => (dht_768, dht_768_f64, "size-768"),
+    20 => (dht_20, dht_20_f64, "size-20"),
+    40 => (dht_40, dht_40_f64, "size-40"),
+    80 => (dht_80, dht_80_f64, "size-80"),
+    160 => (dht_160, dht_160_f64, "size-160"),
+    320 => (dht_320, dht_320_f64, "size-320"),
+    640 => (dht_640, dht_640_f64, "size-640"),
+    1280 => (dht_1280, dht_1280_f64, "size-1280"),
+    60 => (dht_60, dht_60_f64, "size-60"),
+    120 => (dht_120, dht_120_f64, "size-120"),
+    240 => (dht_240, dht_240_f64, "size-240"),
+    480 => (dht_480, dht_480_f64, "size-480"),
+    960 => (dht_960, dht_960_f64, "size-960"),
+    1920 => (dht_1920, dht_1920_f64, "size-1920"),
+    3840 => (dht_3840, dht_3840_f64, "size-3840"),
+}
@@ -0,0 +1,299 @@
+//! CFFTs and RFFTs of several channels at once.
+//!
+//! The channels are stored either planar (one channel after the other) or
+//! interleaved (one frame of all channels after the other). Both layouts are
+//! addressed through a [`Layout`] that maps a channel and an element index
+//! to an index into the flat data.
+//!
+//! For power-of-two sizes, the butterflies of all channels are computed in
+//! the same loop, so each twiddle factor is only looked up once and then
+//! applied to all channels. Mixed-radix sizes transform one channel after
+//! the other.
+
+use core::slice;
+
+use num_complex::Complex;
+use static_assertions::{assert_eq_align, assert_eq_size};
+
+use crate::float::Float;
+use crate::impls::cfft::*;
+use crate::impls::permute::permute;
+use crate::impls::rfft::*;
+use crate::impls::twiddle;
+use crate::{Complex32, Complex64};
+
+/// Position of the elements of several channels in flat data.
+#[derive(Clone, Copy)]
+pub(crate) struct Layout {
+    channels: usize,
+    channel_stride: usize,
+    element_stride: usize,
+}
+
+impl Layout {
+    /// `channels` channels of `n` elements each, one after the other.
+    #[inline]
+    fn planar(n: usize, channels: usize) -> Self {
+        Self {
+            channels,
+            channel_stride: n,
+            element_stride: 1,
+        }
+    }
+
+    /// Frames of one element of each of the `channels` channels.
+    #[inline]
+    fn interleaved(channels: usize) -> Self {
+        Self {
+            channels,
+            channel_stride: 1,
+            element_stride: channels,
+        }
+    }
+
+    #[inline]
+    fn index(&self, channel: usize, i: usize) -> usize {
+        channel * self.channel_stride + i * self.element_stride
+    }
+}
+
+pub(crate) trait CFftBatch<T: Float>: CFft<T> {
+    /// Transform the planar channels of `N` values each in `x`.
+    fn planar(x: &mut [Complex<T>]);
+
+    /// Transform the channels in `x`, which holds `N` interleaved frames.
+    fn interleaved(x: &mut [Complex<T>]);
+}
+
+/// Radix-2 butterflies of all channels of `x`, which have already been
+/// reordered.
+#[inline]
+fn compute_butterflies<T: Float, F: CFft<T>>(x: &mut [Complex<T>], layout: Layout) {
+    let n = F::N;
+    if n == 1 || layout.channels == 0 {
+        return;
+    }
+
+    let m = n / 2;
+    compute_butterflies::<T, F::Half>(x, layout);
+    compute_butterflies::<T, F::Half>(&mut x[m * layout.element_stride..], layout);
+
+    for k in 0..m {
+        let twiddle = twiddle::pow2::<T>(k, n);
+        for c in 0..layout.channels {
+            let (i, j) = (layout.index(c, k), layout.index(c, k + m));
+            let (x_k, x_km) = (x[i], x[j]);
+            let y = twiddle * x_km;
+            x[i] = x_k + y;
+            x[j] = x_k - y;
+        }
+    }
+}
+
+impl<T: Float> CFftBatch<T> for CFftN<1> {
+    #[inline]
+    fn planar(_x: &mut [Complex<T>]) {}
+
+    #[inline]
+    fn interleaved(_x: &mut [Complex<T>]) {}
+}
+
+macro_rules! pow2_batch_impls {
+    ( $( $N:expr ),* ) => {
+        $(
+            impl<T: Float> CFftBatch<T> for CFftN<$N> {
+                #[inline]
+                fn planar(x: &mut [Complex<T>]) {
+                    debug_assert_eq!(x.len() % $N, 0);
+
+                    for channel in x.chunks_exact_mut($N) {
+                        <Self as CFft<T>>::bit_reverse_reorder(channel);
+                    }
+                    let layout = Layout::planar($N, x.len() / $N);
+                    compute_butterflies::<T, Self>(x, layout);
+                }
+
+                #[inline]
+                fn interleaved(x: &mut [Complex<T>]) {
+                    debug_assert_eq!(x.len() % $N, 0);
+
+                    // Bit reversal is an involution, so swapping whole frames
+                    // reorders all channels.
+                    let channels = x.len() / $N;
+                    for i in 0..$N {
+                        let j = <Self as CFft<T>>::reorder_index(i);
+                        if j > i {
+                            for c in 0..channels {
+                                x.swap(i * channels + c, j * channels + c);
+                            }
+                        }
+                    }
+                    compute_butterflies::<T, Self>(x, Layout::interleaved(channels));
+                }
+            }
+        )*
+    };
+}
+
+pow2_batch_impls! { 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768 }
+
+macro_rules! mixed_batch_impls {
+    ( $( $N:expr ),* ) => {
+        $(
+            impl<T: Float> CFftBatch<T> for CFftN<$N> {
+                #[inline]
+                fn planar(x: &mut [Complex<T>]) {
+                    debug_assert_eq!(x.len() % $N, 0);
+
+                    for channel in x.chunks_exact_mut($N) {
+                        <Self as CFft<T>>::transform(channel);
+                    }
+                }
+
+                #[inline]
+                fn interleaved(x: &mut [Complex<T>]) {
+                    debug_assert_eq!(x.len() % $N, 0);
+
+                    let layout = Layout::interleaved(x.len() / $N);
+                    let mut channel = [Complex::new(T::zero(), T::zero()); $N];
+                    for c in 0..layout.channels {
+                        for i in 0..$N {
+                            channel[<Self as CFft<T>>::reorder_index(i)] = x[layout.index(c, i)];
+                        }
+                        <Self as CFft<T>>::compute_butterflies(&mut channel);
+                        for (i, value) in channel.iter().enumerate() {
+                            x[layout.index(c, i)] = *value;
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+mixed_batch_impls! { 6, 12, 24, 48, 96, 192, 384 }
+mixed_batch_impls! { 10, 20, 40, 80, 160, 320, 640 }
+mixed_batch_impls! { 30, 60, 120, 240, 480, 960, 1920 }
+mixed_batch_impls! { 768, 1280, 3840 }
+
+/// Perform the RFFTs of the planar channels of `N` values each in `x`.
+#[inline]
+pub(crate) fn rfft_planar<T: Float, R: RFft<T>>(x: &mut [T]) -> &mut [Complex<T>]
+where
+    R::CFft: CFftBatch<T>,
+{
+    debug_assert_eq!(x.len() % R::N, 0);
+
+    let channels = x.len() / R::N;
+    let x = pack_complex(x);
+    R::CFft::planar(x);
+    recombine::<T, R>(x, Layout::planar(R::N / 2, channels));
+    x
+}
+
+/// Perform the RFFTs of the channels in `x`, which holds `N` interleaved
+/// frames.
+///
+/// The result holds `N/2` interleaved frames of complex values.
+#[inline]
+pub(crate) fn rfft_interleaved<T: Float, R: RFft<T>>(x: &mut [T]) -> &mut [Complex<T>]
+where
+    R::CFft: CFftBatch<T>,
+{
+    debug_assert_eq!(x.len() % R::N, 0);
+
+    // Within each pair of frames `(a, b)`, move `a[c]` and `b[c]` next to
+    // each other, so they form the packed complex value of channel `c`.
+    let channels = x.len() / R::N;
+    if channels > 0 {
+        for pair in x.chunks_exact_mut(2 * channels) {
+            permute(pair, |p| {
+                if p < channels {
+                    2 * p
+                } else {
+                    2 * (p - channels) + 1
+                }
+            });
+        }
+    }
+
+    let x = pack_complex(x);
+    R::CFft::interleaved(x);
+    recombine::<T, R>(x, Layout::interleaved(channels));
+    x
+}
+
+/// Recombine the CFFT results of all channels into RFFT results, like
+/// `RFft::recombine`.
+#[inline]
+fn recombine<T: Float, R: RFft<T>>(x: &mut [Complex<T>], layout: Layout) {
+    let m = R::CFft::N;
+
+    for c in 0..layout.channels {
+        let i = layout.index(c, 0);
+        let x0 = x[i];
+        x[i] = Complex::new(x0.re + x0.im, x0.re - x0.im);
+    }
+    if m == 1 {
+        return;
+    }
+
+    let u = m / 2;
+    for k in 1..u {
+        let twiddle = R::twiddle(k);
+        let (twiddle_re, twiddle_im) = (twiddle.re, twiddle.im);
+
+        for c in 0..layout.channels {
+            let (i, j) = (layout.index(c, k), layout.index(c, m - k));
+            let (x_k, x_nk) = (x[i], x[j]);
+            let sum = (x_k + x_nk) / T::TWO;
+            let diff = (x_k - x_nk) / T::TWO;
+
+            x[i] = Complex::new(
+                sum.re + twiddle_re * sum.im + twiddle_im * diff.re,
+                diff.im + twiddle_im * sum.im - twiddle_re * diff.re,
+            );
+            x[j] = Complex::new(
+                sum.re - twiddle_re * sum.im - twiddle_im * diff.re,
+                -diff.im + twiddle_im * sum.im - twiddle_re * diff.re,
+            );
+        }
+    }
+
+    for c in 0..layout.channels {
+        let i = layout.index(c, u);
+        x[i] = x[i].conj();
+    }
+}
+
+#[inline]
+fn pack_complex<T: Float>(x: &mut [T]) -> &mut [Complex<T>] {
+    assert_eq_size!(Complex32, [f32; 2]);
+    assert_eq_align!(Complex32, f32);
+    assert_eq_size!(Complex64, [f64; 2]);
+    assert_eq_align!(Complex64, f64);
+    assert_eq!(x.len() % 2, 0);
+
+    let len = x.len() / 2;
+    let data = x.as_mut_ptr().cast::<Complex<T>>();
+    unsafe { slice::from_raw_parts_mut(data, len) }
+}
+
+/// View arrays of `K` elements as a flat slice of their elements.
+#[inline]
+pub(crate) fn flatten<E, const K: usize>(x: &mut [[E; K]]) -> &mut [E] {
+    let len = x.len() * K;
+    let data = x.as_mut_ptr().cast::<E>();
+    unsafe { slice::from_raw_parts_mut(data, len) }
+}
+
+/// View a flat slice as `L` arrays of `K` elements.
+#[inline]
+pub(crate) fn unflatten<E, const K: usize, const L: usize>(x: &mut [E]) -> &mut [[E; K]; L] {
+    // Checking the total length, rather than dividing by `K`, also works for
+    // zero channels.
+    assert_eq!(x.len(), K * L);
+
+    let data = x.as_mut_ptr().cast::<[[E; K]; L]>();
+    unsafe { &mut *data }
+}
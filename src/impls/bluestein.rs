@@ -0,0 +1,209 @@
+//! Bluestein's algorithm for DFTs of arbitrary length.
+//!
+//! An `N`-point DFT can be written as a convolution by substituting
+//! `n*k = (n^2 + k^2 - (k-n)^2) / 2`:
+//!
+//! ```text
+//! X[k] = w[k] * sum_n (x[n] * w[n]) * conj(w[k-n]),  w[n] = exp(-PI*i*n^2/N)
+//! ```
+//!
+//! The convolution is computed as a cyclic convolution of power-of-two
+//! length `M >= 2N - 1`, using the radix-2 CFFT. The chirp-Z transform uses
+//! the same convolution.
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::dispatch;
+use crate::{math, Error};
+
+/// The number of values after which [`Chirp`] recomputes its factors exactly.
+const RESEED: u64 = 64;
+
+/// The size of the power-of-two CFFT used for an `n`-point DFT.
+#[inline]
+pub(crate) const fn fft_len(n: usize) -> usize {
+    if n <= 1 {
+        n
+    } else {
+        (2 * n - 1).next_power_of_two()
+    }
+}
+
+/// The sequence `q[j] = u^j * v^(j^2)` for `j = 0, 1, 2, ...`, where
+/// `exact(j)` computes `q[j]`.
+///
+/// Instead of evaluating `exact` for every value, the values are computed by
+/// a recurrence: consecutive values differ by the factor
+/// `d[j] = u * v^(2j + 1)`, and consecutive factors by `v^2`. To bound the
+/// rounding errors, `q[j]` and `d[j]` are recomputed exactly every
+/// [`RESEED`] values.
+pub(crate) struct Chirp<F> {
+    exact: F,
+    j: u64,
+    q: Complex<f64>,
+    d: Complex<f64>,
+    step: Complex<f64>,
+}
+
+impl<F: Fn(u64) -> Complex<f64>> Chirp<F> {
+    #[inline]
+    pub(crate) fn new(exact: F) -> Self {
+        let (q0, q1, q2) = (exact(0), exact(1), exact(2));
+        let d = q1 / q0;
+        Self {
+            step: q2 / q1 / d,
+            exact,
+            j: 0,
+            q: q0,
+            d,
+        }
+    }
+}
+
+impl<F: Fn(u64) -> Complex<f64>> Iterator for Chirp<F> {
+    type Item = Complex<f64>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let q = self.q;
+        self.j += 1;
+        if self.j % RESEED == 0 {
+            self.q = (self.exact)(self.j);
+            self.d = (self.exact)(self.j + 1) / self.q;
+        } else {
+            self.q = q * self.d;
+            self.d *= self.step;
+        }
+        Some(q)
+    }
+}
+
+/// Convert a double-precision value to the precision of `T`.
+#[inline]
+pub(crate) fn convert<T: Float>(x: Complex<f64>) -> Complex<T> {
+    Complex::new(T::from_f64(x.re), T::from_f64(x.im))
+}
+
+/// The chirp factors `exp(-PI*i*j^2/n)` for `j = 0, 1, 2, ...`.
+#[inline]
+fn chirp(n: usize) -> Chirp<impl Fn(u64) -> Complex<f64>> {
+    Chirp::new(move |j| {
+        // `j^2` grows quickly, so reduce it modulo the period of `2n` first.
+        let period = 2 * n as u64;
+        let j = j % period;
+        let r = j * j % period;
+
+        let angle = -core::f64::consts::PI * r as f64 / n as f64;
+        let (sin, cos) = math::sin_cos(angle);
+        Complex::new(cos, sin)
+    })
+}
+
+/// Perform an in-place DFT on `x`, using `scratch` as working memory.
+///
+/// `scratch` must hold at least `2 * fft_len(x.len())` values. Returns an
+/// error, leaving `x` unmodified, if the power-of-two CFFT of that size is
+/// not available.
+pub(crate) fn dft<T: Float>(x: &mut [Complex<T>], scratch: &mut [Complex<T>]) -> Result<(), Error> {
+    let n = x.len();
+    if n <= 1 {
+        return Ok(());
+    }
+
+    let input = x.iter().zip(chirp(n)).map(|(x, c)| *x * convert(c));
+    let kernel = chirp(n).map(|c| convert::<T>(c).conj());
+    let y = convolve(input, n, kernel, n, scratch)?;
+    for ((x, y), c) in x.iter_mut().zip(y.iter()).zip(chirp(n)) {
+        *x = *y * convert(c);
+    }
+    Ok(())
+}
+
+/// Compute the first `m` values of the linear convolution of the `n` values
+/// of `input` with the symmetric kernel, for `j` in `(-n, m)`, whose values
+/// at `|j| = 0, 1, 2, ...` are yielded by `kernel`.
+///
+/// The convolution is computed as a cyclic convolution of power-of-two
+/// length `L >= n + m - 1` in `scratch`, which must hold at least `2 * L`
+/// values. Returns an error if the `L`-point CFFT is not available. Both `n`
+/// and `m` must be non-zero.
+pub(crate) fn convolve<T: Float>(
+    input: impl Iterator<Item = Complex<T>>,
+    n: usize,
+    kernel: impl Iterator<Item = Complex<T>>,
+    m: usize,
+    scratch: &mut [Complex<T>],
+) -> Result<&mut [Complex<T>], Error> {
+    debug_assert!(n > 0 && m > 0);
+
+    let l = (n + m - 1).next_power_of_two();
+    assert!(
+        scratch.len() >= 2 * l,
+        "scratch buffer too small: {} < {}",
+        scratch.len(),
+        2 * l
+    );
+    let (a, b) = scratch[..2 * l].split_at_mut(l);
+
+    let zero = Complex::new(T::zero(), T::zero());
+
+    // the kernel for `j` in `(-n, m)`, wrapped around to make the
+    // convolution cyclic
+    b.fill(zero);
+    for (j, k) in kernel.take(n.max(m)).enumerate() {
+        if j < m {
+            b[j] = k;
+        }
+        if j > 0 && j < n {
+            b[l - j] = k;
+        }
+    }
+
+    a.fill(zero);
+    for (a, x) in a.iter_mut().zip(input.take(n)) {
+        *a = x;
+    }
+
+    dispatch::cfft(a)?;
+    dispatch::cfft(b)?;
+
+    // Multiply in the frequency domain and transform back. The inverse
+    // transform is computed as `conj(CFFT(conj(x))) / L`.
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        *a = (*a * *b).conj();
+    }
+    dispatch::cfft(a)?;
+
+    let scale = T::from_usize(l);
+    let y = &mut a[..m];
+    for y in y.iter_mut() {
+        *y = y.conj() / scale;
+    }
+    Ok(y)
+}
+
+/// Perform an in-place inverse DFT on `x`, using `scratch` as working memory.
+///
+/// The same requirements as for [`dft`] apply.
+pub(crate) fn idft<T: Float>(
+    x: &mut [Complex<T>],
+    scratch: &mut [Complex<T>],
+) -> Result<(), Error> {
+    // IDFT(x) = conj(DFT(conj(x))) / N
+    for c in x.iter_mut() {
+        *c = c.conj();
+    }
+
+    let result = dft(x, scratch);
+
+    // on error, only undo the conjugation
+    let scale = match result {
+        Ok(()) => T::from_usize(x.len()),
+        Err(_) => T::one(),
+    };
+    for c in x.iter_mut() {
+        *c = c.conj() / scale;
+    }
+    result
+}
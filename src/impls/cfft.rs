@@ -1,16 +1,17 @@
-use crate::{tables, Complex32};
+use num_complex::Complex;
 
-pub(crate) trait CFft {
-    type Half: CFft;
+use crate::float::Float;
+use crate::impls::twiddle;
+#[cfg(feature = "bitrev-tables")]
+use crate::tables;
 
-    const N: usize;
-    const LOG2_N: usize = Self::N.ilog2() as usize;
+pub(crate) trait CFft<T: Float> {
+    type Half: CFft<T>;
 
-    #[cfg(feature = "bitrev-tables")]
-    const BITREV_TABLE: &'static [u16] = tables::BITREV[Self::LOG2_N];
+    const N: usize;
 
     #[inline]
-    fn transform(x: &mut [Complex32]) -> &mut [Complex32] {
+    fn transform(x: &mut [Complex<T>]) -> &mut [Complex<T>] {
         debug_assert_eq!(x.len(), Self::N);
 
         Self::bit_reverse_reorder(x);
@@ -18,40 +19,57 @@ pub(crate) trait CFft {
         x
     }
 
-    #[cfg(feature = "bitrev-tables")]
+    /// Perform the transform of `x` into `y`, leaving `x` unmodified.
+    ///
+    /// The reordering of the input values is performed while copying them,
+    /// rather than as a separate pass.
     #[inline]
-    fn bit_reverse_reorder(x: &mut [Complex32]) {
+    fn transform_into(x: &[Complex<T>], y: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), Self::N);
+        debug_assert_eq!(y.len(), Self::N);
 
-        for i in 0..Self::N {
-            let j = Self::BITREV_TABLE[i] as usize;
-            x.swap(i, j);
+        for (i, x_i) in x.iter().enumerate() {
+            y[Self::reorder_index(i)] = *x_i;
         }
+        Self::compute_butterflies(y);
     }
 
-    #[cfg(not(feature = "bitrev-tables"))]
     #[inline]
-    fn bit_reverse_reorder(x: &mut [Complex32]) {
+    fn bit_reverse_reorder(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), Self::N);
 
-        let shift = core::mem::size_of::<usize>() * 8 - Self::LOG2_N;
-        for i in 0..Self::N {
-            let rev = i.reverse_bits();
-            let j = rev >> shift;
-            if j > i {
-                x.swap(i, j);
-            }
+        bit_reverse_reorder(x);
+    }
+
+    /// Look up the twiddle factor `exp(-2*PI*i*k/N)` for any `k`.
+    #[inline]
+    fn twiddle(k: usize) -> Complex<T> {
+        twiddle::pow2(k, Self::N)
+    }
+
+    /// The index the input value at index `i` is moved to by
+    /// `bit_reverse_reorder`.
+    #[inline]
+    fn reorder_index(i: usize) -> usize {
+        debug_assert!(i < Self::N);
+
+        if Self::N == 1 {
+            return 0;
         }
+
+        let shift = usize::BITS - Self::N.ilog2();
+        i.reverse_bits() >> shift
     }
 
+    #[cfg(not(feature = "radix-4"))]
     #[inline]
-    fn compute_butterflies(x: &mut [Complex32]) {
+    fn compute_butterflies(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), Self::N);
 
         let m = Self::N / 2;
         let u = m / 2;
 
-        let table_len = tables::SINE.len();
+        let table_len = T::SINE.len();
         let table_stride = (table_len + 1) * 4 / Self::N;
 
         Self::Half::compute_butterflies(&mut x[..m]);
@@ -67,9 +85,9 @@ pub(crate) trait CFft {
         //   - im from SINE table directly
         for k in 1..u {
             let s = k * table_stride;
-            let re = tables::SINE[table_len - s] * -1.;
-            let im = tables::SINE[s - 1];
-            let twiddle = Complex32::new(re, im);
+            let re = -T::SINE[table_len - s];
+            let im = T::SINE[s - 1];
+            let twiddle = Complex::new(re, im);
 
             let (x_k, x_km) = (x[k], x[k + m]);
             let y = twiddle * x_km;
@@ -79,7 +97,7 @@ pub(crate) trait CFft {
 
         // [k = m/2] twiddle factor: `0 - 1i`
         let (x_u, x_um) = (x[u], x[u + m]);
-        let y = x_um * Complex32::new(0., -1.);
+        let y = Complex::new(x_um.im, -x_um.re);
         x[u] = x_u + y;
         x[u + m] = x_u - y;
 
@@ -88,9 +106,9 @@ pub(crate) trait CFft {
         //   - im from SINE table backwards
         for k in (u + 1)..m {
             let s = (k - u) * table_stride;
-            let re = tables::SINE[s - 1];
-            let im = tables::SINE[table_len - s];
-            let twiddle = Complex32::new(re, im);
+            let re = T::SINE[s - 1];
+            let im = T::SINE[table_len - s];
+            let twiddle = Complex::new(re, im);
 
             let (x_k, x_km) = (x[k], x[k + m]);
             let y = twiddle * x_km;
@@ -98,33 +116,174 @@ pub(crate) trait CFft {
             x[k + m] = x_k - y;
         }
     }
+
+    /// Radix-4 variant of the butterfly computation.
+    ///
+    /// The bit-reversed input contains the CFFTs of the inputs at indices
+    /// `4n`, `4n + 2`, `4n + 1` and `4n + 3`, in that order, as its four
+    /// quarters. These are combined in a single pass, which saves about a
+    /// quarter of the complex multiplications compared to two radix-2 stages.
+    /// For sizes that are an odd power of two, the recursion ends with a
+    /// radix-2 butterfly.
+    #[cfg(feature = "radix-4")]
+    #[inline]
+    fn compute_butterflies(x: &mut [Complex<T>]) {
+        debug_assert_eq!(x.len(), Self::N);
+
+        let l = Self::N / 4;
+
+        for quarter in x.chunks_exact_mut(l) {
+            <Self::Half as CFft<T>>::Half::compute_butterflies(quarter);
+        }
+
+        // [k = 0] twiddle factors: `1 + 0i`
+        let one = Complex::new(T::one(), T::zero());
+        radix_4_butterfly(x, 0, l, one, one, one);
+
+        // The twiddle factors of the second, third and fourth quarter are
+        // `w^2k`, `w^k` and `w^3k`, with `w = exp(-2*PI*i/N)`. The loop is
+        // split at the `k` where `w^2k` or `w^3k` enter another quadrant, so
+        // all of them can be looked up from the SINE table directly, and only
+        // need to be rotated by a fixed multiple of `-PI/2`:
+        //   - re from SINE table backwards and negative
+        //   - im from SINE table directly
+        let table_len = T::SINE.len();
+        let quadrant = table_len + 1;
+        let table_stride = quadrant * 4 / Self::N;
+        let lookup = |s: usize| Complex::new(-T::SINE[table_len - s], T::SINE[s - 1]);
+        let rotate = |w: Complex<T>| Complex::new(w.im, -w.re);
+
+        // `l` is a power of two, so `3k` is never a multiple of it
+        let (a, h, c) = (l / 3 + 1, l / 2, 2 * l / 3 + 1);
+
+        // [k in [1, l/3)] `w^2k` and `w^3k` in the first quadrant
+        for k in 1..a {
+            let s = k * table_stride;
+            radix_4_butterfly(x, k, l, lookup(2 * s), lookup(s), lookup(3 * s));
+        }
+
+        // [k in (l/3, l/2)] `w^3k` in the second quadrant
+        for k in a..h {
+            let s = k * table_stride;
+            let d = rotate(lookup(3 * s - quadrant));
+            radix_4_butterfly(x, k, l, lookup(2 * s), lookup(s), d);
+        }
+
+        if h == 0 {
+            return;
+        }
+
+        // [k = l/2] `w^2k = 0 - 1i`, `w^3k` in the second quadrant
+        let s = h * table_stride;
+        let b = Complex::new(T::zero(), -T::one());
+        let d = rotate(lookup(3 * s - quadrant));
+        radix_4_butterfly(x, h, l, b, lookup(s), d);
+
+        // [k in (l/2, 2l/3)] `w^2k` and `w^3k` in the second quadrant
+        for k in (h + 1)..c {
+            let s = k * table_stride;
+            let b = rotate(lookup(2 * s - quadrant));
+            let d = rotate(lookup(3 * s - quadrant));
+            radix_4_butterfly(x, k, l, b, lookup(s), d);
+        }
+
+        // [k in (2l/3, l)] `w^2k` in the second, `w^3k` in the third quadrant
+        for k in c..l {
+            let s = k * table_stride;
+            let b = rotate(lookup(2 * s - quadrant));
+            let d = -lookup(3 * s - 2 * quadrant);
+            radix_4_butterfly(x, k, l, b, lookup(s), d);
+        }
+    }
+}
+
+/// Combine the `k`-th values of the four quarters of length `l` of `x` with a
+/// radix-4 butterfly, after multiplying the last three by the twiddle factors
+/// `b`, `c` and `d`.
+#[cfg(feature = "radix-4")]
+#[inline(always)]
+fn radix_4_butterfly<T: Float>(
+    x: &mut [Complex<T>],
+    k: usize,
+    l: usize,
+    b: Complex<T>,
+    c: Complex<T>,
+    d: Complex<T>,
+) {
+    let a = x[k];
+    let b = x[k + l] * b;
+    let c = x[k + 2 * l] * c;
+    let d = x[k + 3 * l] * d;
+
+    let (sum_ab, diff_ab) = (a + b, a - b);
+    let (sum_cd, diff_cd) = (c + d, c - d);
+    // multiplication by `-i`
+    let rot_cd = Complex::new(diff_cd.im, -diff_cd.re);
+
+    x[k] = sum_ab + sum_cd;
+    x[k + l] = diff_ab + rot_cd;
+    x[k + 2 * l] = sum_ab - sum_cd;
+    x[k + 3 * l] = diff_ab - rot_cd;
+}
+
+/// Reorder the elements of `x` into bit-reversed index order.
+///
+/// The length of `x` must be a power of two.
+#[cfg(feature = "bitrev-tables")]
+#[inline]
+pub(crate) fn bit_reverse_reorder<E>(x: &mut [E]) {
+    let table = tables::BITREV[x.len().ilog2() as usize];
+    for (i, &j) in table.iter().enumerate() {
+        x.swap(i, j as usize);
+    }
+}
+
+/// Reorder the elements of `x` into bit-reversed index order.
+///
+/// The length of `x` must be a power of two.
+#[cfg(not(feature = "bitrev-tables"))]
+#[inline]
+pub(crate) fn bit_reverse_reorder<E>(x: &mut [E]) {
+    let n = x.len();
+    if n <= 1 {
+        return;
+    }
+
+    let shift = core::mem::size_of::<usize>() * 8 - n.ilog2() as usize;
+    for i in 0..n {
+        let rev = i.reverse_bits();
+        let j = rev >> shift;
+        if j > i {
+            x.swap(i, j);
+        }
+    }
 }
 
 pub(crate) struct CFftN<const N: usize>;
 
-impl CFft for CFftN<1> {
+impl<T: Float> CFft<T> for CFftN<1> {
     type Half = Self;
 
     const N: usize = 1;
 
     #[inline]
-    fn bit_reverse_reorder(x: &mut [Complex32]) {
+    fn bit_reverse_reorder(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), 1);
     }
 
     #[inline]
-    fn compute_butterflies(x: &mut [Complex32]) {
+    fn compute_butterflies(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), 1);
     }
 }
 
-impl CFft for CFftN<2> {
+impl<T: Float> CFft<T> for CFftN<2> {
     type Half = CFftN<1>;
 
     const N: usize = 2;
 
     #[inline]
-    fn compute_butterflies(x: &mut [Complex32]) {
+    fn compute_butterflies(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), 2);
 
         let (x_0, x_1) = (x[0], x[1]);
@@ -136,7 +295,7 @@ impl CFft for CFftN<2> {
 macro_rules! cfft_impls {
     ( $( $N:expr ),* ) => {
         $(
-            impl CFft for CFftN<$N> {
+            impl<T: Float> CFft<T> for CFftN<$N> {
                 type Half = CFftN<{$N / 2}>;
 
                 const N: usize = $N;
@@ -0,0 +1,135 @@
+//! Chirp-Z transform.
+//!
+//! The chirp-Z transform evaluates the z-transform of `N` input values at
+//! `M` points `z_k = A * W^-k` on a spiral contour. Like in Bluestein's
+//! algorithm, substituting `n*k = (n^2 + k^2 - (k-n)^2) / 2` turns it into a
+//! convolution:
+//!
+//! ```text
+//! X[k] = sum_n x[n] * z_k^-n
+//!      = c[k] * sum_n (x[n] * A^-n * c[n]) / c[k-n],  c[j] = W^(j^2/2)
+//! ```
+//!
+//! The convolution is computed like in Bluestein's algorithm, as a cyclic
+//! convolution of power-of-two length `L >= N + M - 1`.
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::bluestein::{self, convert, Chirp};
+use crate::{math, Error};
+
+/// The size of the power-of-two CFFT used for `n` input and `m` output
+/// values.
+#[inline]
+pub(crate) const fn fft_len(n: usize, m: usize) -> usize {
+    if n == 0 || m == 0 {
+        0
+    } else {
+        (n + m - 1).next_power_of_two()
+    }
+}
+
+/// The spiral contour `z_k = A * W^-k`, with `A = a_radius * exp(i*a_angle)`
+/// and `W^-1 = w_radius * exp(i*w_angle)`.
+#[derive(Clone, Copy)]
+pub(crate) struct Spiral {
+    pub(crate) a_radius: f64,
+    pub(crate) a_angle: f64,
+    pub(crate) w_radius: f64,
+    pub(crate) w_angle: f64,
+}
+
+impl Spiral {
+    /// The chirp factors `c[j] = W^(j^2/2)`, or their reciprocals, for
+    /// `j = 0, 1, 2, ...`.
+    #[inline]
+    fn chirps(self, reciprocal: bool) -> Chirp<impl Fn(u64) -> Complex<f64>> {
+        Chirp::new(move |j| self.chirp(j, reciprocal))
+    }
+
+    /// The factors `A^-j * c[j]` applied to the input values, for
+    /// `j = 0, 1, 2, ...`.
+    #[inline]
+    fn input_chirps(self) -> Chirp<impl Fn(u64) -> Complex<f64>> {
+        Chirp::new(move |j| self.start(j) * self.chirp(j, false))
+    }
+
+    /// The chirp factor `c[j] = W^(j^2/2)`, or its reciprocal.
+    #[inline]
+    fn chirp(&self, j: u64, reciprocal: bool) -> Complex<f64> {
+        let e = j * j;
+        let mut radius = powi(self.w_radius, e);
+        let mut angle = -self.w_angle * e as f64 / 2.;
+        if reciprocal {
+            angle = -angle;
+        } else {
+            radius = 1. / radius;
+        }
+        polar(math::sqrt(radius), angle)
+    }
+
+    /// The factor `A^-n`.
+    #[inline]
+    fn start(&self, n: u64) -> Complex<f64> {
+        let radius = powi(self.a_radius, n);
+        polar(1. / radius, -self.a_angle * n as f64)
+    }
+}
+
+#[inline]
+fn polar(radius: f64, angle: f64) -> Complex<f64> {
+    let (sin, cos) = math::sin_cos(angle);
+    Complex::new(radius * cos, radius * sin)
+}
+
+/// Compute `x^e` by repeated squaring.
+#[inline]
+fn powi(x: f64, e: u64) -> f64 {
+    if x == 1. {
+        return 1.;
+    }
+
+    let (mut base, mut e, mut result) = (x, e, 1.);
+    while e > 0 {
+        if e & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        e >>= 1;
+    }
+    result
+}
+
+/// Compute the chirp-Z transform of the `N` values returned by `x` into the
+/// `M` values of `y`, using `scratch` as working memory.
+///
+/// `scratch` must hold at least `2 * fft_len(N, M)` values. Returns an error
+/// if the power-of-two CFFT of that size is not available.
+pub(crate) fn czt<T: Float>(
+    x: impl Fn(usize) -> Complex<T>,
+    n: usize,
+    y: &mut [Complex<T>],
+    spiral: Spiral,
+    scratch: &mut [Complex<T>],
+) -> Result<(), Error> {
+    let m = y.len();
+    let zero = Complex::new(T::zero(), T::zero());
+    if n == 0 {
+        y.fill(zero);
+        return Ok(());
+    }
+    if m == 0 {
+        return Ok(());
+    }
+
+    let input = (0..n)
+        .zip(spiral.input_chirps())
+        .map(|(j, c)| x(j) * convert(c));
+    let kernel = spiral.chirps(true).map(convert);
+    let conv = bluestein::convolve(input, n, kernel, m, scratch)?;
+    for ((y, conv), c) in y.iter_mut().zip(conv.iter()).zip(spiral.chirps(false)) {
+        *y = *conv * convert(c);
+    }
+    Ok(())
+}
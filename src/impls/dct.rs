@@ -0,0 +1,119 @@
+//! DCT and DST via the real FFT.
+//!
+//! The DCT-II is computed following Makhoul: the input is reordered into
+//!
+//! ```text
+//! v[n] = x[2n],  v[N-1-n] = x[2n+1]
+//! ```
+//!
+//! and then `X[k] = Re(exp(-PI*i*k/(2N)) * V[k])`, with `V` the `N`-point DFT
+//! of `v`. Since `v` is real, `X[k]` and `X[N-k]` can both be derived from the
+//! RFFT output `V[k]`, so the whole computation is done in place. The DCT-III
+//! reverses these steps. The DST variants are reduced to the DCT variants by
+//! negating every other input value and reversing the output.
+
+use core::f64::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::irfft::*;
+use crate::impls::permute::permute;
+use crate::impls::rfft::*;
+use crate::impls::twiddle::Fine;
+
+pub(crate) trait Dct<T: Float> {
+    type RFft: RFft<T>;
+    type IRFft: IRFft<T>;
+
+    const N: usize = Self::RFft::N;
+
+    #[inline]
+    fn dct2(x: &mut [T]) {
+        let n = Self::N;
+        debug_assert_eq!(x.len(), n);
+
+        permute(x, |i| if i % 2 == 0 { i / 2 } else { n - 1 - i / 2 });
+        let v = Self::RFft::transform(x);
+
+        // Store `(X[k], X[N-k])` in place of `V[k]`.
+        let twiddles = Fine::<T>::new(4 * n);
+        let v0 = v[0];
+        v[0] = Complex::new(v0.re, v0.im * T::from_f64(FRAC_1_SQRT_2));
+        for (k, v_k) in v.iter_mut().enumerate().skip(1) {
+            let c = twiddles.get(k) * *v_k;
+            *v_k = Complex::new(c.re, -c.im);
+        }
+
+        permute(x, |i| match (i % 2, i / 2) {
+            (0, k) => k,
+            (_, 0) => n / 2,
+            (_, k) => n - k,
+        });
+    }
+
+    #[inline]
+    fn dct3(x: &mut [T]) {
+        let n = Self::N;
+        debug_assert_eq!(x.len(), n);
+
+        permute(x, |k| match k {
+            k if k < n / 2 => 2 * k,
+            k if k == n / 2 => 1,
+            k => 2 * (n - k) + 1,
+        });
+        let v = Self::RFft::pack_complex(x);
+
+        let twiddles = Fine::<T>::new(4 * n);
+        let v0 = v[0];
+        v[0] = Complex::new(v0.re, v0.im * T::from_f64(SQRT_2));
+        for (k, v_k) in v.iter_mut().enumerate().skip(1) {
+            *v_k = twiddles.get(k).conj() * Complex::new(v_k.re, -v_k.im);
+        }
+
+        Self::IRFft::transform(v);
+        permute(x, |i| {
+            if i < n / 2 {
+                2 * i
+            } else {
+                2 * (n - 1 - i) + 1
+            }
+        });
+    }
+
+    #[inline]
+    fn dst2(x: &mut [T]) {
+        negate_odd(x);
+        Self::dct2(x);
+        x.reverse();
+    }
+
+    #[inline]
+    fn dst3(x: &mut [T]) {
+        x.reverse();
+        Self::dct3(x);
+        negate_odd(x);
+    }
+}
+
+#[inline]
+fn negate_odd<T: Float>(x: &mut [T]) {
+    for v in x.iter_mut().skip(1).step_by(2) {
+        *v = -*v;
+    }
+}
+
+pub(crate) struct DctN<const N: usize>;
+
+macro_rules! dct_impls {
+    ( $( $N:expr ),* ) => {
+        $(
+            impl<T: Float> Dct<T> for DctN<$N> {
+                type RFft = RFftN<$N>;
+                type IRFft = IRFftN<$N>;
+            }
+        )*
+    };
+}
+
+dct_impls! { 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768 }
@@ -0,0 +1,193 @@
+//! Dispatch of transforms on runtime-sized inputs to the implementation for
+//! the respective size.
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::cfft::*;
+use crate::impls::ifft::*;
+use crate::impls::irfft::*;
+use crate::impls::rfft::*;
+use crate::Error;
+
+/// The largest supported power-of-two size.
+const MAX_POW2: usize = 32768;
+
+/// The supported mixed-radix sizes have the form `base * 2^k`, with `k` in
+/// `[2, 8]`.
+const MIXED_BASES: [usize; 3] = [3, 5, 15];
+const MIXED_MAX_SHIFT: u32 = 8;
+
+/// Call `$transform!(N)` for the CFFT size `N` matching `$len`, evaluating to
+/// `Ok` with its result, or to an error if there is none.
+macro_rules! complex_sizes {
+    ( $len:expr, $transform:ident ) => {
+        dispatch! {
+            $len, $transform;
+            1;
+            2;
+            4, "size-4";
+            8, "size-8";
+            16, "size-16";
+            32, "size-32";
+            64, "size-64";
+            128, "size-128";
+            256, "size-256";
+            512, "size-512";
+            1024, "size-1024";
+            2048, "size-2048";
+            4096, "size-4096";
+            8192, "size-8192";
+            16384, "size-16384";
+            32768, "size-32768";
+        }
+    };
+}
+
+/// Call `$transform!(N)` for the RFFT size `N` matching `$len`, evaluating to
+/// `Ok` with its result, or to an error if there is none.
+macro_rules! real_sizes {
+    ( $len:expr, $transform:ident ) => {
+        dispatch! {
+            $len, $transform;
+            2;
+            4;
+            8, "size-4";
+            16, "size-8";
+            32, "size-16";
+            64, "size-32";
+            128, "size-64";
+            256, "size-128";
+            512, "size-256";
+            1024, "size-512";
+            2048, "size-1024";
+            4096, "size-2048";
+            8192, "size-4096";
+            16384, "size-8192";
+            32768, "size-16384";
+        }
+    };
+}
+
+macro_rules! dispatch {
+    ( $len:expr, $transform:ident; $( $N:expr $(, $feature:expr)? ; )* ) => {
+        match $len {
+            $(
+                $( #[cfg(feature = $feature)] )?
+                $N => Ok($transform!($N)),
+            )*
+            n => mixed_sizes!(n, $transform),
+        }
+    };
+}
+
+macro_rules! mixed_sizes {
+    ( $len:expr, $transform:ident ) => {
+        mixed_dispatch! {
+            $len, $transform;
+            12, "size-12";
+            24, "size-24";
+            48, "size-48";
+            96, "size-96";
+            192, "size-192";
+            384, "size-384";
+            768, "size-768";
+            20, "size-20";
+            40, "size-40";
+            80, "size-80";
+            160, "size-160";
+            320, "size-320";
+            640, "size-640";
+            1280, "size-1280";
+            60, "size-60";
+            120, "size-120";
+            240, "size-240";
+            480, "size-480";
+            960, "size-960";
+            1920, "size-1920";
+            3840, "size-3840";
+        }
+    };
+}
+
+macro_rules! mixed_dispatch {
+    ( $len:expr, $transform:ident; $( $N:expr, $feature:expr ; )* ) => {
+        match $len {
+            $(
+                #[cfg(feature = $feature)]
+                $N => Ok($transform!($N)),
+            )*
+            n => Err(unsupported(n)),
+        }
+    };
+}
+
+/// Determine why no transform of size `n` is available.
+fn unsupported(n: usize) -> Error {
+    // Size 1 is only unsupported for RFFTs, which need at least two values.
+    if n > 1 && n.is_power_of_two() {
+        return if n > MAX_POW2 {
+            Error::TooLarge
+        } else {
+            Error::SizeNotEnabled
+        };
+    }
+
+    for base in MIXED_BASES {
+        if n % base == 0 && (n / base).is_power_of_two() {
+            let shift = (n / base).ilog2();
+            if shift > MIXED_MAX_SHIFT {
+                return Error::TooLarge;
+            } else if shift >= 2 {
+                return Error::SizeNotEnabled;
+            }
+        }
+    }
+
+    Error::UnsupportedSize
+}
+
+/// Perform an in-place CFFT on `x`.
+pub(crate) fn cfft<T: Float>(x: &mut [Complex<T>]) -> Result<(), Error> {
+    macro_rules! transform {
+        ( $N:expr ) => {{
+            CFftN::<$N>::transform(x);
+        }};
+    }
+
+    complex_sizes!(x.len(), transform)
+}
+
+/// Perform an in-place IFFT on `x`.
+pub(crate) fn ifft<T: Float>(x: &mut [Complex<T>]) -> Result<(), Error> {
+    macro_rules! transform {
+        ( $N:expr ) => {
+            IFftN::<$N>::transform(x)
+        };
+    }
+
+    complex_sizes!(x.len(), transform)
+}
+
+/// Perform an in-place RFFT on `x`.
+pub(crate) fn rfft<T: Float>(x: &mut [T]) -> Result<&mut [Complex<T>], Error> {
+    macro_rules! transform {
+        ( $N:expr ) => {
+            RFftN::<$N>::transform(x)
+        };
+    }
+
+    real_sizes!(x.len(), transform)
+}
+
+/// Perform an in-place inverse RFFT on `x`, which holds half as many values
+/// as the transform size.
+pub(crate) fn irfft<T: Float>(x: &mut [Complex<T>]) -> Result<&mut [T], Error> {
+    macro_rules! transform {
+        ( $N:expr ) => {
+            IRFftN::<$N>::transform(x)
+        };
+    }
+
+    real_sizes!(x.len() * 2, transform)
+}
@@ -0,0 +1,252 @@
+//! Discrete prolate spheroidal sequences.
+//!
+//! The DPSS of length `N` and half-bandwidth `W` are the eigenvectors of
+//! the `N x N` matrix `A[n][m] = sin(2*PI*W*(n-m)) / (PI*(n-m))`, whose
+//! eigenvalues are their spectral concentrations within `[-W, W]`. The same
+//! vectors are also the eigenvectors of a symmetric tridiagonal matrix,
+//! which is much easier to handle numerically:
+//!
+//! ```text
+//! T[n][n]   = ((N - 1 - 2n) / 2)^2 * cos(2*PI*W)
+//! T[n][n+1] = (n + 1) * (N - n - 1) / 2
+//! ```
+//!
+//! The eigenvalues of `T` are found by bisection using Sturm sequence
+//! counts, and the corresponding eigenvectors by inverse iteration.
+
+extern crate std;
+
+use std::vec;
+use std::vec::Vec;
+
+use core::f64::consts::PI;
+
+/// A symmetric tridiagonal matrix.
+struct Tridiagonal {
+    diag: Vec<f64>,
+    off: Vec<f64>,
+}
+
+impl Tridiagonal {
+    /// The matrix commuting with the `n x n` sinc matrix of half-bandwidth
+    /// `w`.
+    fn dpss(n: usize, w: f64) -> Self {
+        let cos = (2. * PI * w).cos();
+        let diag = (0..n)
+            .map(|i| {
+                let t = (n - 1) as f64 / 2. - i as f64;
+                t * t * cos
+            })
+            .collect();
+        let off = (1..n).map(|i| (i * (n - i)) as f64 / 2.).collect();
+        Self { diag, off }
+    }
+
+    /// The number of eigenvalues less than `x`.
+    fn count_below(&self, x: f64) -> usize {
+        let tiny = f64::MIN_POSITIVE / f64::EPSILON;
+        let mut count = 0;
+        let mut q = 1.;
+        for (i, d) in self.diag.iter().enumerate() {
+            q = d
+                - x
+                - if i > 0 {
+                    self.off[i - 1].powi(2) / q
+                } else {
+                    0.
+                };
+            if q == 0. {
+                q = -tiny;
+            }
+            if q < 0. {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// The eigenvalue with the (zero-based) index `k` in ascending order.
+    fn eigenvalue(&self, k: usize) -> f64 {
+        // Gershgorin bounds
+        let n = self.diag.len();
+        let radius = |i: usize| {
+            let left = if i > 0 { self.off[i - 1].abs() } else { 0. };
+            let right = if i + 1 < n { self.off[i].abs() } else { 0. };
+            left + right
+        };
+        let mut lo = (0..n)
+            .map(|i| self.diag[i] - radius(i))
+            .fold(f64::INFINITY, f64::min);
+        let mut hi = (0..n)
+            .map(|i| self.diag[i] + radius(i))
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        for _ in 0..200 {
+            let mid = (lo + hi) / 2.;
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if self.count_below(mid) > k {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        (lo + hi) / 2.
+    }
+
+    /// The normalized eigenvector for the eigenvalue `lambda`, computed by
+    /// inverse iteration.
+    fn eigenvector(&self, lambda: f64) -> Vec<f64> {
+        let n = self.diag.len();
+        let lu = Lu::new(self, lambda);
+
+        // an arbitrary start vector that is neither symmetric nor
+        // antisymmetric, so it is not orthogonal to the eigenvector
+        let mut v: Vec<f64> = (0..n).map(|i| 1. + i as f64 / n as f64).collect();
+        for _ in 0..4 {
+            lu.solve(&mut v);
+            let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            for x in v.iter_mut() {
+                *x /= norm;
+            }
+        }
+        v
+    }
+}
+
+/// The LU decomposition with partial pivoting of a shifted tridiagonal
+/// matrix `T - lambda*I`, in the format of LAPACK's `dgttrf`.
+struct Lu {
+    dl: Vec<f64>,
+    d: Vec<f64>,
+    du: Vec<f64>,
+    du2: Vec<f64>,
+    swapped: Vec<bool>,
+}
+
+impl Lu {
+    fn new(t: &Tridiagonal, lambda: f64) -> Self {
+        let n = t.diag.len();
+        let mut dl = t.off.clone();
+        let mut d: Vec<f64> = t.diag.iter().map(|d| d - lambda).collect();
+        let mut du = t.off.clone();
+        let mut du2 = vec![0.; n.saturating_sub(2)];
+        let mut swapped = vec![false; n.saturating_sub(1)];
+
+        for i in 0..n.saturating_sub(1) {
+            if d[i].abs() >= dl[i].abs() {
+                if d[i] != 0. {
+                    let fact = dl[i] / d[i];
+                    dl[i] = fact;
+                    d[i + 1] -= fact * du[i];
+                }
+            } else {
+                let fact = d[i] / dl[i];
+                d[i] = dl[i];
+                dl[i] = fact;
+                let temp = du[i];
+                du[i] = d[i + 1];
+                d[i + 1] = temp - fact * d[i + 1];
+                if i + 2 < n {
+                    du2[i] = du[i + 1];
+                    du[i + 1] *= -fact;
+                }
+                swapped[i] = true;
+            }
+        }
+
+        // The shift is an eigenvalue, so the matrix is singular up to
+        // rounding. Replace exactly zero pivots by a small value, which only
+        // affects the scale of the solution.
+        let scale = t
+            .diag
+            .iter()
+            .chain(&t.off)
+            .fold(0., |m: f64, x| m.max(x.abs()));
+        for d in d.iter_mut() {
+            if *d == 0. {
+                *d = f64::EPSILON * scale.max(1.);
+            }
+        }
+
+        Self {
+            dl,
+            d,
+            du,
+            du2,
+            swapped,
+        }
+    }
+
+    /// Solve `(T - lambda*I) x = b` in place.
+    fn solve(&self, b: &mut [f64]) {
+        let n = b.len();
+        for i in 0..n.saturating_sub(1) {
+            if self.swapped[i] {
+                let temp = b[i];
+                b[i] = b[i + 1];
+                b[i + 1] = temp - self.dl[i] * b[i];
+            } else {
+                b[i + 1] -= self.dl[i] * b[i];
+            }
+        }
+
+        for i in (0..n).rev() {
+            let mut x = b[i];
+            if i + 1 < n {
+                x -= self.du[i] * b[i + 1];
+            }
+            if i + 2 < n {
+                x -= self.du2[i] * b[i + 2];
+            }
+            b[i] = x / self.d[i];
+        }
+    }
+}
+
+/// Compute the `k` DPSS of length `n` with the time-bandwidth product `nw`,
+/// ordered by decreasing concentration, together with their concentrations.
+///
+/// Each taper is normalized to unit energy. Symmetric tapers are made to
+/// have a positive sum, antisymmetric ones to start with a positive lobe.
+pub(crate) fn dpss(n: usize, nw: f64, k: usize) -> Vec<(Vec<f64>, f64)> {
+    let w = nw / n as f64;
+    let t = Tridiagonal::dpss(n, w);
+
+    (0..k)
+        .map(|k| {
+            let mut v = t.eigenvector(t.eigenvalue(n - 1 - k));
+
+            let flip = if k % 2 == 0 {
+                v.iter().sum::<f64>() < 0.
+            } else {
+                let threshold = (1. / n as f64).max(1e-7);
+                v.iter()
+                    .find(|x| *x * *x > threshold)
+                    .map_or(false, |x| *x < 0.)
+            };
+            if flip {
+                for x in v.iter_mut() {
+                    *x = -*x;
+                }
+            }
+
+            let concentration = concentration(&v, w);
+            (v, concentration)
+        })
+        .collect()
+}
+
+/// The fraction of the energy of `v` within the frequency band `[-w, w]`.
+fn concentration(v: &[f64], w: f64) -> f64 {
+    // sum_n sum_m v[n] * v[m] * sin(2*PI*w*(n-m)) / (PI*(n-m)), expressed
+    // in terms of the autocorrelation of `v`
+    let n = v.len();
+    let mut sum = 2. * w * v.iter().map(|x| x * x).sum::<f64>();
+    for lag in 1..n {
+        let r: f64 = v[..n - lag].iter().zip(&v[lag..]).map(|(a, b)| a * b).sum();
+        sum += 2. * r * (2. * PI * w * lag as f64).sin() / (PI * lag as f64);
+    }
+    sum
+}
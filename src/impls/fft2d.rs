@@ -0,0 +1,62 @@
+//! Column pass of the 2D FFT.
+//!
+//! Instead of transposing the whole input, the columns are transformed in
+//! blocks of [`COLUMNS`] adjacent columns through a buffer holding one block.
+//! Each block is gathered by reading [`COLUMNS`] consecutive values from every
+//! row, so every cache line of a row that is loaded is used for several
+//! columns. The columns are reordered while gathering them, so only the
+//! butterflies have to be computed on the buffer.
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::cfft::CFft;
+
+/// The number of adjacent columns transformed together.
+pub(crate) const COLUMNS: usize = 4;
+
+/// Transform the columns of the `F::N` rows in `x`, using the `columns` as
+/// buffer for blocks of `columns.len()` adjacent columns.
+///
+/// If `inverse` is set, the normalized inverse transform is computed
+/// instead.
+#[inline]
+pub(crate) fn transform_columns<T, F, R, C>(x: &mut [R], columns: &mut [C], inverse: bool)
+where
+    T: Float,
+    F: CFft<T>,
+    R: AsMut<[Complex<T>]>,
+    C: AsMut<[Complex<T>]>,
+{
+    let n = F::N;
+    debug_assert_eq!(x.len(), n);
+    debug_assert!(!columns.is_empty());
+    debug_assert!(columns.iter_mut().all(|column| column.as_mut().len() == n));
+
+    let width = x.first_mut().map_or(0, |row| row.as_mut().len());
+    for start in (0..width).step_by(columns.len()) {
+        let end = width.min(start + columns.len());
+        let columns = &mut columns[..(end - start)];
+
+        for (i, row) in x.iter_mut().enumerate() {
+            // The inverse transform is computed by reversing the input.
+            let j = if inverse { (n - i) % n } else { i };
+            let j = F::reorder_index(j);
+            for (column, value) in columns.iter_mut().zip(&row.as_mut()[start..end]) {
+                column.as_mut()[j] = *value;
+            }
+        }
+
+        for column in columns.iter_mut() {
+            F::compute_butterflies(column.as_mut());
+        }
+
+        let scale = T::from_usize(n);
+        for (i, row) in x.iter_mut().enumerate() {
+            for (value, column) in row.as_mut()[start..end].iter_mut().zip(columns.iter_mut()) {
+                let y = column.as_mut()[i];
+                *value = if inverse { y / scale } else { y };
+            }
+        }
+    }
+}
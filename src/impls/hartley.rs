@@ -0,0 +1,53 @@
+//! DHT via the real FFT.
+//!
+//! The DHT of a real input is `H[k] = Re(X[k]) - Im(X[k])`, where `X` is its
+//! DFT. Since `X[N-k] = conj(X[k])`, both `H[k]` and `H[N-k]` can be derived
+//! from the RFFT output `X[k]`, so the whole computation is done in place.
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::permute::permute;
+use crate::impls::rfft::*;
+
+pub(crate) trait Dht<T: Float> {
+    type RFft: RFft<T>;
+
+    const N: usize = Self::RFft::N;
+
+    #[inline]
+    fn transform(x: &mut [T]) {
+        let n = Self::N;
+        debug_assert_eq!(x.len(), n);
+
+        // Store `(H[k], H[N-k])` in place of `X[k]`. The DC and Nyquist
+        // coefficients are already in place.
+        let spectrum = Self::RFft::transform(x);
+        for c in spectrum.iter_mut().skip(1) {
+            *c = Complex::new(c.re - c.im, c.re + c.im);
+        }
+
+        permute(x, |i| match (i % 2, i / 2) {
+            (0, k) => k,
+            (_, 0) => n / 2,
+            (_, k) => n - k,
+        });
+    }
+}
+
+pub(crate) struct DhtN<const N: usize>;
+
+macro_rules! dht_impls {
+    ( $( $N:expr ),* ) => {
+        $(
+            impl<T: Float> Dht<T> for DhtN<$N> {
+                type RFft = RFftN<$N>;
+            }
+        )*
+    };
+}
+
+dht_impls! { 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768 }
+dht_impls! { 12, 24, 48, 96, 192, 384, 768 }
+dht_impls! { 20, 40, 80, 160, 320, 640, 1280 }
+dht_impls! { 60, 120, 240, 480, 960, 1920, 3840 }
@@ -1,22 +1,47 @@
+use num_complex::Complex;
+
+use crate::float::Float;
 use crate::impls::cfft::*;
-use crate::Complex32;
 
-pub(crate) trait IFft {
-    type CFft: CFft;
+pub(crate) trait IFft<T: Float> {
+    type CFft: CFft<T>;
 
     const N: usize = Self::CFft::N;
 
     #[inline]
-    fn transform(x: &mut [Complex32]) {
+    fn transform(x: &mut [Complex<T>]) {
+        Self::transform_unnormalized(x);
+        Self::normalize(x);
+    }
+
+    /// Perform the transform without scaling the result by `1/N`.
+    #[inline]
+    fn transform_unnormalized(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), Self::N);
 
         Self::reorder(x);
         Self::CFft::transform(x);
-        Self::normalize(x);
+    }
+
+    /// Perform the transform of `x` into `y`, leaving `x` unmodified.
+    ///
+    /// Both reorderings of the input values are performed while copying them,
+    /// rather than as separate passes.
+    #[inline]
+    fn transform_into(x: &[Complex<T>], y: &mut [Complex<T>]) {
+        debug_assert_eq!(x.len(), Self::N);
+        debug_assert_eq!(y.len(), Self::N);
+
+        for (i, x_i) in x.iter().enumerate() {
+            let j = (Self::N - i) % Self::N;
+            y[Self::CFft::reorder_index(j)] = *x_i;
+        }
+        Self::CFft::compute_butterflies(y);
+        Self::normalize(y);
     }
 
     #[inline]
-    fn reorder(x: &mut [Complex32]) {
+    fn reorder(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), Self::N);
 
         let m = Self::N / 2;
@@ -26,18 +51,19 @@ pub(crate) trait IFft {
     }
 
     #[inline]
-    fn normalize(x: &mut [Complex32]) {
+    fn normalize(x: &mut [Complex<T>]) {
+        let n = T::from_usize(Self::N);
         for c in x {
-            *c /= Self::N as f32;
+            *c /= n;
         }
     }
 }
 
 pub(crate) struct IFftN<const N: usize>;
 
-impl<const N: usize> IFft for IFftN<N>
+impl<T: Float, const N: usize> IFft<T> for IFftN<N>
 where
-    CFftN<N>: CFft,
+    CFftN<N>: CFft<T>,
 {
     type CFft = CFftN<N>;
 }
@@ -1,32 +1,151 @@
 use core::slice;
 
+use num_complex::Complex;
 use static_assertions::{assert_eq_align, assert_eq_size};
 
+use crate::float::Float;
+use crate::impls::cfft::CFft;
 use crate::impls::ifft::*;
-use crate::{tables, Complex32};
+use crate::impls::twiddle;
+use crate::{Complex32, Complex64};
 
-pub(crate) trait IRFft {
-    type IFft: IFft;
+pub(crate) trait IRFft<T: Float> {
+    type IFft: IFft<T>;
 
     const N: usize = Self::IFft::N * 2;
 
     #[inline]
-    fn transform(x: &mut [Complex32]) -> &mut [f32] {
+    fn transform(x: &mut [Complex<T>]) -> &mut [T] {
         debug_assert_eq!(x.len(), Self::N / 2);
 
-        Self::recombine(x);
+        Self::recombine(x, None);
         Self::IFft::transform(x);
         Self::unpack_real(x)
     }
 
+    /// Perform the transform without normalization, scaling the result by
+    /// `scale` instead, if given.
+    ///
+    /// The scaling is performed as part of the recombination, so it does not
+    /// require an additional pass over the data.
     #[inline]
-    fn recombine(x: &mut [Complex32]) {
+    fn transform_scaled(x: &mut [Complex<T>], scale: Option<T>) -> &mut [T] {
+        debug_assert_eq!(x.len(), Self::N / 2);
+
+        // The unnormalized `N/2`-point IFFT scales by `N/2` instead of `N`.
+        let scale = scale.unwrap_or_else(T::one) * T::TWO;
+
+        Self::recombine(x, Some(scale));
+        Self::IFft::transform_unnormalized(x);
+        Self::unpack_real(x)
+    }
+
+    /// Perform the transform of `x` into `y`, leaving `x` unmodified.
+    ///
+    /// The recombined values are written directly to their reordered
+    /// positions in `y`, rather than copying the input first.
+    #[inline]
+    fn transform_into(x: &[Complex<T>], y: &mut [T]) {
+        debug_assert_eq!(x.len(), Self::N / 2);
+        debug_assert_eq!(y.len(), Self::N);
+
+        let y = Self::pack_complex(y);
+        Self::recombine_into(x, x[0], y);
+    }
+
+    /// Perform the transform of the `N/2 + 1` bins of `x` into `y`, leaving
+    /// `x` unmodified.
+    ///
+    /// The imaginary parts of the DC and Nyquist bins are ignored, since
+    /// they are zero for the spectrum of a real signal.
+    #[inline]
+    fn transform_full(x: &[Complex<T>], y: &mut [T]) {
+        debug_assert_eq!(x.len(), Self::N / 2 + 1);
+        debug_assert_eq!(y.len(), Self::N);
+
+        let m = Self::N / 2;
+        let y = Self::pack_complex(y);
+        Self::recombine_into(&x[..m], Complex::new(x[0].re, x[m].re), y);
+    }
+
+    /// Recombine the RFFT result `x`, with its packed DC value replaced by
+    /// `x0`, into `y` and finish the IFFT there.
+    ///
+    /// Each recombined value is stored at the position the IFFT's input
+    /// reordering and the CFFT's reordering would move it to, so neither
+    /// reordering needs a separate pass.
+    #[inline]
+    fn recombine_into(x: &[Complex<T>], x0: Complex<T>, y: &mut [Complex<T>]) {
+        type C<T, F> = <<F as IRFft<T>>::IFft as IFft<T>>::CFft;
+
         let m = Self::N / 2;
         debug_assert_eq!(x.len(), m);
+        debug_assert_eq!(y.len(), m);
 
-        let table_len = tables::SINE.len();
-        let table_stride = (table_len + 1) * 4 / Self::N;
+        let dest = |k: usize| C::<T, Self>::reorder_index((m - k) % m);
+
+        y[dest(0)] = Self::recombine_dc(x0);
+        if m > 1 {
+            let u = m / 2;
+            for k in 1..u {
+                let (y_k, y_nk) = Self::recombine_pair(x[k], x[m - k], k);
+                y[dest(k)] = y_k;
+                y[dest(m - k)] = y_nk;
+            }
+            y[dest(u)] = x[u].conj();
+        }
+
+        C::<T, Self>::compute_butterflies(y);
+        Self::IFft::normalize(y);
+    }
+
+    #[inline]
+    fn pack_complex(x: &mut [T]) -> &mut [Complex<T>] {
+        assert_eq_size!(Complex32, [f32; 2]);
+        assert_eq_align!(Complex32, f32);
+        assert_eq_size!(Complex64, [f64; 2]);
+        assert_eq_align!(Complex64, f64);
+        debug_assert_eq!(x.len(), Self::N);
+
+        let data = x.as_mut_ptr().cast::<Complex<T>>();
+        unsafe { slice::from_raw_parts_mut(data, Self::N / 2) }
+    }
+
+    /// Look up the twiddle factor `exp(-2*PI*i*k/N)` for `k` in `(0, N/4)`.
+    #[inline]
+    fn twiddle(k: usize) -> Complex<T> {
+        twiddle::quarter(k, Self::N)
+    }
+
+    /// Recombine the RFFT result into the input of the CFFT, scaling it by
+    /// `scale`, if given.
+    #[inline]
+    fn recombine(x: &mut [Complex<T>], scale: Option<T>) {
+        let m = Self::N / 2;
+        debug_assert_eq!(x.len(), m);
+
+        let scaled = |c: Complex<T>| match scale {
+            Some(s) => c.scale(s),
+            None => c,
+        };
+
+        x[0] = scaled(Self::recombine_dc(x[0]));
+
+        let u = m / 2;
+        for k in 1..u {
+            let (y_k, y_nk) = Self::recombine_pair(x[k], x[m - k], k);
+            x[k] = scaled(y_k);
+            x[m - k] = scaled(y_nk);
+        }
 
+        // The forward operation was:
+        // x[u] = (xu.re, -xu.im)
+        x[u] = scaled(x[u].conj());
+    }
+
+    /// Recombine the packed DC and Nyquist values `x0`.
+    #[inline]
+    fn recombine_dc(x0: Complex<T>) -> Complex<T> {
         // The forward operation was:
         // x[0] = (x0.re + x0.im, x0.re - x0.im)
         //
@@ -35,84 +154,82 @@ pub(crate) trait IRFft {
         // We can solve sum.re by adding equations of packed.re and packed.im.
         // And we can solve sum.im by substracting the equation of packed.im
         // from packed.re:
-        let x0 = x[0];
-        x[0] = Complex32::new((x0.re + x0.im) / 2., (x0.re - x0.im) / 2.);
-
-        let u = m / 2;
-        for k in 1..u {
-            // The forward operation was:
-            // 1) sum = (x_k + x_nk)/2
-            // 2) diff = (x_k - x_nk)/2
-            // 3) x_k.re = sum.re + twiddle_re * sum.im + twiddle_im * diff.re
-            // 4) x_k.im = diff.im + twiddle_im * sum.im - twiddle_re * diff.re
-            // 5) x_nk.re = sum.re - twiddle_re * sum.im - twiddle_im * diff.re
-            // 6) x_nk.im = -diff.im + twiddle_im * sum.im - twiddle_re * diff.re
-            //
-            // To invert the operation, we will first solve for sum and diff,
-            // and then use them to solve for the original x_k and x_nk.
-
-            let s = k * table_stride;
-            let twiddle_re = tables::SINE[table_len - s] * -1.; // -cos(2*PI*k/N)
-            let twiddle_im = tables::SINE[s - 1]; // sin(2*PI*k/N)
-
-            let (x_k, x_nk) = (x[k], x[m - k]);
-
-            // Let's solve for sum.re and diff.im, this can be done directly by
-            // adding equations 3 and 5, and subtracting equations 6 from 4:
-            let sum_re = (x_k.re + x_nk.re) / 2.;
-            let diff_im = (x_k.im - x_nk.im) / 2.;
-
-            // Now let's solve sum.im and diff.re. These two form a system of
-            // linear equations and can be solved using Cramer's rule:
-            let a = (x_k.re - x_nk.re) / 2.;
-            let b = (x_k.im + x_nk.im) / 2.;
-            let twiddle_norm_sq = twiddle_re * twiddle_re + twiddle_im * twiddle_im;
-            let sum_im = (a * twiddle_re + b * twiddle_im) / twiddle_norm_sq;
-            let diff_re = (a * twiddle_im - b * twiddle_re) / twiddle_norm_sq;
-
-            // Now we can reconstruct x_k and x_nk from sum and diff. This can
-            // be done by adding equations 1 and 2, and subtracting 2 from 1:
-            let sum = Complex32::new(sum_re, sum_im);
-            let diff = Complex32::new(diff_re, diff_im);
-            x[k] = sum + diff;
-            x[m - k] = sum - diff;
-        }
+        Complex::new((x0.re + x0.im) / T::TWO, (x0.re - x0.im) / T::TWO)
+    }
 
+    /// Recombine the values `x_k` and `x_nk` at `k` and `N/2 - k`, for `k`
+    /// in `(0, N/4)`.
+    #[inline]
+    fn recombine_pair(x_k: Complex<T>, x_nk: Complex<T>, k: usize) -> (Complex<T>, Complex<T>) {
         // The forward operation was:
-        // x[u] = (xu.re, -xu.im)
-        let xu = x[u];
-        x[u] = Complex32::new(xu.re, -xu.im);
+        // 1) sum = (x_k + x_nk)/2
+        // 2) diff = (x_k - x_nk)/2
+        // 3) x_k.re = sum.re + twiddle_re * sum.im + twiddle_im * diff.re
+        // 4) x_k.im = diff.im + twiddle_im * sum.im - twiddle_re * diff.re
+        // 5) x_nk.re = sum.re - twiddle_re * sum.im - twiddle_im * diff.re
+        // 6) x_nk.im = -diff.im + twiddle_im * sum.im - twiddle_re * diff.re
+        //
+        // To invert the operation, we will first solve for sum and diff,
+        // and then use them to solve for the original x_k and x_nk.
+
+        let twiddle = Self::twiddle(k);
+        let twiddle_re = twiddle.re; // cos(2*PI*k/N)
+        let twiddle_im = twiddle.im; // -sin(2*PI*k/N)
+
+        // Let's solve for sum.re and diff.im, this can be done directly by
+        // adding equations 3 and 5, and subtracting equations 6 from 4:
+        let sum_re = (x_k.re + x_nk.re) / T::TWO;
+        let diff_im = (x_k.im - x_nk.im) / T::TWO;
+
+        // Now let's solve sum.im and diff.re. These two form a system of
+        // linear equations and can be solved using Cramer's rule:
+        let a = (x_k.re - x_nk.re) / T::TWO;
+        let b = (x_k.im + x_nk.im) / T::TWO;
+        let twiddle_norm_sq = twiddle_re * twiddle_re + twiddle_im * twiddle_im;
+        let sum_im = (a * twiddle_re + b * twiddle_im) / twiddle_norm_sq;
+        let diff_re = (a * twiddle_im - b * twiddle_re) / twiddle_norm_sq;
+
+        // Now we can reconstruct x_k and x_nk from sum and diff. This can
+        // be done by adding equations 1 and 2, and subtracting 2 from 1:
+        let sum = Complex::new(sum_re, sum_im);
+        let diff = Complex::new(diff_re, diff_im);
+        (sum + diff, sum - diff)
     }
 
     #[inline]
-    fn unpack_real(x: &mut [Complex32]) -> &mut [f32] {
+    fn unpack_real(x: &mut [Complex<T>]) -> &mut [T] {
         assert_eq_size!(Complex32, [f32; 2]);
         assert_eq_align!(Complex32, f32);
+        assert_eq_size!(Complex64, [f64; 2]);
+        assert_eq_align!(Complex64, f64);
         debug_assert_eq!(x.len(), Self::N / 2);
 
-        let data = x.as_mut_ptr().cast::<f32>();
+        let data = x.as_mut_ptr().cast::<T>();
         unsafe { slice::from_raw_parts_mut(data, Self::N) }
     }
 }
 
 pub(crate) struct IRFftN<const N: usize>;
 
-impl IRFft for IRFftN<2> {
+impl<T: Float> IRFft<T> for IRFftN<2> {
     type IFft = IFftN<1>;
 
     #[inline]
-    fn recombine(x: &mut [Complex32]) {
+    fn recombine(x: &mut [Complex<T>], scale: Option<T>) {
         debug_assert_eq!(x.len(), 1);
 
-        let x0 = x[0];
-        x[0] = Complex32::new((x0.re + x0.im) / 2., (x0.re - x0.im) / 2.);
+        let x0 = Self::recombine_dc(x[0]);
+        x[0] = match scale {
+            Some(s) => x0.scale(s),
+            None => x0,
+        };
     }
 }
 
 macro_rules! irfft_impls {
     ( $( $N:expr ),* ) => {
         $(
-            impl IRFft for IRFftN<$N> {
+            impl<T: Float> IRFft<T> for IRFftN<$N> {
                 type IFft = IFftN<{$N / 2}>;
             }
         )*
@@ -120,3 +237,22 @@ macro_rules! irfft_impls {
 }
 
 irfft_impls! { 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768 }
+
+macro_rules! mixed_irfft_impls {
+    ( $( $N:expr ),* ) => {
+        $(
+            impl<T: Float> IRFft<T> for IRFftN<$N> {
+                type IFft = IFftN<{$N / 2}>;
+
+                #[inline]
+                fn twiddle(k: usize) -> Complex<T> {
+                    twiddle::mixed(k, $N)
+                }
+            }
+        )*
+    };
+}
+
+mixed_irfft_impls! { 12, 24, 48, 96, 192, 384, 768 }
+mixed_irfft_impls! { 20, 40, 80, 160, 320, 640, 1280 }
+mixed_irfft_impls! { 60, 120, 240, 480, 960, 1920, 3840 }
@@ -0,0 +1,137 @@
+//! MDCT and IMDCT via a DCT-IV.
+//!
+//! The `N`-point MDCT folds its input into `M = N/2` values, such that the
+//! result is the DCT-IV of the folded values. The IMDCT computes the DCT-IV
+//! of its input and unfolds the result into `N` values.
+//!
+//! The `M`-point DCT-IV is computed with an `M/2`-point CFFT, by combining
+//! the even and (reversed) odd input values into complex values:
+//!
+//! ```text
+//! z[n] = (u[2n] + i*u[M-1-2n]) * exp(-PI*i*(4n+1)/(4M))
+//! Y[k] = Z[k] * exp(-PI*i*k/M)
+//! X[2k] = Re(Y[k]),  X[M-1-2k] = -Im(Y[k])
+//! ```
+
+use core::slice;
+
+use num_complex::Complex;
+use static_assertions::{assert_eq_align, assert_eq_size};
+
+use crate::float::Float;
+use crate::impls::cfft::*;
+use crate::impls::twiddle::Fine;
+use crate::{Complex32, Complex64};
+
+pub(crate) trait Mdct<T: Float> {
+    type CFft: CFft<T>;
+
+    const N: usize = Self::CFft::N * 4;
+
+    /// Compute the MDCT of the `N` values in `x` into the `N/2` values of
+    /// `y`.
+    #[inline]
+    fn forward(x: &[T], y: &mut [T]) {
+        debug_assert_eq!(x.len(), Self::N);
+        debug_assert_eq!(y.len(), Self::N / 2);
+
+        // With the input split into quarters `(a, b, c, d)`, the folded
+        // values are `(-c_r - d, a - b_r)`, where `_r` denotes reversal.
+        let q = Self::N / 4;
+        let fold = |j: usize| {
+            if j < q {
+                -x[3 * q - 1 - j] - x[3 * q + j]
+            } else {
+                x[j - q] - x[3 * q - 1 - j]
+            }
+        };
+
+        Self::dct4(fold, y, None);
+    }
+
+    /// Compute the IMDCT of the `N/2` values in `x` into the `N` values of
+    /// `y`, scaled by `4/N`.
+    #[inline]
+    fn inverse(x: &[T], y: &mut [T]) {
+        debug_assert_eq!(x.len(), Self::N / 2);
+        debug_assert_eq!(y.len(), Self::N);
+
+        let m = Self::N / 2;
+        let h = m / 2;
+
+        let scale = T::TWO / T::from_usize(m);
+        Self::dct4(|j| x[j], &mut y[..m], Some(scale));
+
+        // Unfold the DCT-IV result `v` into `(v_2, -v_2r, -v_1r, -v_1)`,
+        // where `v_1` and `v_2` are its halves. The second half of the output
+        // only depends on `v_1`, so it has to be computed first.
+        for j in 0..h {
+            y[m + j] = -y[h - 1 - j];
+            y[m + h + j] = -y[j];
+        }
+        y.copy_within(h..m, 0);
+        for j in 0..h {
+            y[h + j] = -y[h - 1 - j];
+        }
+    }
+
+    /// Compute the DCT-IV of the `M` values returned by `u` into `y`,
+    /// scaling the result by `scale`, if given.
+    #[inline]
+    fn dct4(u: impl Fn(usize) -> T, y: &mut [T], scale: Option<T>) {
+        let m = Self::N / 2;
+        let h = Self::CFft::N;
+        debug_assert_eq!(y.len(), m);
+
+        let scaled = |c: Complex<T>| match scale {
+            Some(s) => c.scale(s),
+            None => c,
+        };
+
+        let twiddles = Fine::<T>::new(8 * m);
+        let z = pack_complex(y);
+
+        for i in 0..h {
+            let pair = Complex::new(u(2 * i), u(m - 1 - 2 * i));
+            z[Self::CFft::reorder_index(i)] = pair * twiddles.get(4 * i + 1);
+        }
+        Self::CFft::compute_butterflies(z);
+
+        // `X[2k]` and `X[M-1-2k]` end up in the real part of `z[k]` and the
+        // imaginary part of `z[h-1-k]`, respectively.
+        for k in 0..(h + 1) / 2 {
+            let j = h - 1 - k;
+            let y_k = scaled(z[k] * twiddles.get(4 * k));
+            let y_j = scaled(z[j] * twiddles.get(4 * j));
+            z[k] = Complex::new(y_k.re, -y_j.im);
+            z[j] = Complex::new(y_j.re, -y_k.im);
+        }
+    }
+}
+
+#[inline]
+fn pack_complex<T: Float>(x: &mut [T]) -> &mut [Complex<T>] {
+    assert_eq_size!(Complex32, [f32; 2]);
+    assert_eq_align!(Complex32, f32);
+    assert_eq_size!(Complex64, [f64; 2]);
+    assert_eq_align!(Complex64, f64);
+    assert_eq!(x.len() % 2, 0);
+
+    let len = x.len() / 2;
+    let data = x.as_mut_ptr().cast::<Complex<T>>();
+    unsafe { slice::from_raw_parts_mut(data, len) }
+}
+
+pub(crate) struct MdctN<const N: usize>;
+
+macro_rules! mdct_impls {
+    ( $( $N:expr ),* ) => {
+        $(
+            impl<T: Float> Mdct<T> for MdctN<$N> {
+                type CFft = CFftN<{$N / 4}>;
+            }
+        )*
+    };
+}
+
+mdct_impls! { 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768 }
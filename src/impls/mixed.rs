@@ -0,0 +1,231 @@
+//! Mixed-radix CFFT for sizes of the form `3 * 2^k`, `5 * 2^k` and
+//! `15 * 2^k`.
+//!
+//! An `N`-point CFFT with `N = R * L` and `R` in `{3, 5}` is computed by
+//! splitting the input into `R` interleaved subsequences, computing their
+//! `L`-point CFFTs and combining the results with a radix-`R` butterfly.
+//! The sub-CFFTs recurse until only a power of two is left, which is handled
+//! by the radix-2 implementation.
+//!
+//! The twiddle factors are looked up in a sine table whose period is
+//! `15 * 2^k`, so only sizes dividing it are implemented. Higher powers of 3
+//! or 5 would require a different table.
+//!
+//! The input reordering generalizes the bit-reversal of the radix-2 case:
+//! input value `i` is moved into block `i % R`, at the position that its
+//! index `i / R` is moved to by the sub-CFFT. It is performed in two passes:
+//! The first one moves all values into their power-of-two blocks, by rotating
+//! the cycles of that permutation. Their smallest indices are computed at
+//! compile time. The second pass bit-reverses each of the blocks.
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::cfft::*;
+use crate::impls::permute::permute_cycles;
+use crate::impls::twiddle;
+
+/// The radices of the two mixed-radix stages of an `n`-point CFFT, and the
+/// length of its power-of-two sub-CFFTs.
+///
+/// The first stage is radix-5 if `n` has a factor of 5, like in the
+/// implementations below. Sizes with a single factor of 3 or 5 have a
+/// radix-1 second stage.
+#[inline]
+const fn radices(n: usize) -> (usize, usize, usize) {
+    let r1 = if n % 5 == 0 { 5 } else { 3 };
+    let r2 = if n % 15 == 0 { 3 } else { 1 };
+    (r1, r2, n / (r1 * r2))
+}
+
+/// The index the input value at index `i` is moved to by the reordering of
+/// an `n`-point mixed-radix CFFT, without the bit-reversal within the
+/// power-of-two blocks.
+#[inline]
+const fn block_index(i: usize, n: usize) -> usize {
+    let (r1, r2, m) = radices(n);
+    (i % r1) * (n / r1) + (i / r1 % r2) * m + i / (r1 * r2)
+}
+
+/// The index the input value at index `i` is moved to by the reordering of
+/// an `n`-point mixed-radix CFFT.
+#[inline]
+const fn reorder_index(i: usize, n: usize) -> usize {
+    let (_, _, m) = radices(n);
+    let j = block_index(i, n);
+    if m == 1 {
+        return j;
+    }
+    let shift = usize::BITS - m.ilog2();
+    j - j % m + ((j % m).reverse_bits() >> shift)
+}
+
+/// The smallest index of each cycle of [`block_index`] for an `N`-point
+/// mixed-radix CFFT that is longer than one element, followed by unused
+/// entries, and the number of those cycles.
+const fn cycle_leaders<const N: usize>() -> ([u16; N], usize) {
+    let mut leaders = [0; N];
+    let mut count = 0;
+    let mut visited = [false; N];
+
+    let mut start = 0;
+    while start < N {
+        if !visited[start] && block_index(start, N) != start {
+            leaders[count] = start as u16;
+            count += 1;
+
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                i = block_index(i, N);
+            }
+        }
+        start += 1;
+    }
+
+    (leaders, count)
+}
+
+/// The first `C` entries of `x`.
+const fn truncate<const N: usize, const C: usize>(x: &[u16; N]) -> [u16; C] {
+    let mut y = [0; C];
+    let mut i = 0;
+    while i < C {
+        y[i] = x[i];
+        i += 1;
+    }
+    y
+}
+
+/// Multiply `x` by `i`.
+#[inline]
+fn mul_i<T: Float>(x: Complex<T>) -> Complex<T> {
+    Complex::new(-x.im, x.re)
+}
+
+#[inline]
+fn radix_3_butterflies<T: Float, Sub: CFft<T>>(x: &mut [Complex<T>]) {
+    let l = Sub::N;
+    let n = l * 3;
+    debug_assert_eq!(x.len(), n);
+
+    for block in x.chunks_exact_mut(l) {
+        Sub::compute_butterflies(block);
+    }
+
+    // exp(-2*PI*i/3) = -1/2 - i*sin(PI/3)
+    let sin = -twiddle::mixed::<T>(1, 3).im;
+
+    let stride = twiddle::mixed_stride::<T>(n);
+    for k in 0..l {
+        let s = k * stride;
+        let y0 = x[k];
+        let y1 = x[k + l] * twiddle::mixed_at(s);
+        let y2 = x[k + 2 * l] * twiddle::mixed_at(2 * s);
+
+        let sum = y1 + y2;
+        let a = y0 - sum / T::TWO;
+        let b = mul_i((y2 - y1) * sin);
+
+        x[k] = y0 + sum;
+        x[k + l] = a + b;
+        x[k + 2 * l] = a - b;
+    }
+}
+
+#[inline]
+fn radix_5_butterflies<T: Float, Sub: CFft<T>>(x: &mut [Complex<T>]) {
+    let l = Sub::N;
+    let n = l * 5;
+    debug_assert_eq!(x.len(), n);
+
+    for block in x.chunks_exact_mut(l) {
+        Sub::compute_butterflies(block);
+    }
+
+    let w1 = twiddle::mixed::<T>(1, 5);
+    let w2 = twiddle::mixed::<T>(2, 5);
+    let (cos_1, sin_1) = (w1.re, -w1.im);
+    let (cos_2, sin_2) = (w2.re, -w2.im);
+
+    let stride = twiddle::mixed_stride::<T>(n);
+    for k in 0..l {
+        let s = k * stride;
+        let y0 = x[k];
+        let y1 = x[k + l] * twiddle::mixed_at(s);
+        let y2 = x[k + 2 * l] * twiddle::mixed_at(2 * s);
+        let y3 = x[k + 3 * l] * twiddle::mixed_at(3 * s);
+        let y4 = x[k + 4 * l] * twiddle::mixed_at(4 * s);
+
+        let (sum_14, sum_23) = (y1 + y4, y2 + y3);
+        let (diff_14, diff_23) = (y1 - y4, y2 - y3);
+
+        let a1 = y0 + sum_14 * cos_1 + sum_23 * cos_2;
+        let a2 = y0 + sum_14 * cos_2 + sum_23 * cos_1;
+        let b1 = mul_i(diff_14 * sin_1 + diff_23 * sin_2);
+        let b2 = mul_i(diff_14 * sin_2 - diff_23 * sin_1);
+
+        x[k] = y0 + sum_14 + sum_23;
+        x[k + l] = a1 - b1;
+        x[k + 2 * l] = a2 - b2;
+        x[k + 3 * l] = a2 + b2;
+        x[k + 4 * l] = a1 + b1;
+    }
+}
+
+macro_rules! mixed_cfft_impls {
+    ( $( $N:expr => $radix:tt ),* ) => {
+        $(
+            impl<T: Float> CFft<T> for CFftN<$N> {
+                type Half = Self;
+
+                const N: usize = $N;
+
+                #[inline]
+                fn bit_reverse_reorder(x: &mut [Complex<T>]) {
+                    debug_assert_eq!(x.len(), $N);
+
+                    const CYCLES: ([u16; $N], usize) = cycle_leaders::<$N>();
+                    const LEADERS: [u16; CYCLES.1] = truncate(&CYCLES.0);
+                    permute_cycles(x, &LEADERS, |i| block_index(i, $N));
+
+                    let (_, _, m) = radices($N);
+                    for block in x.chunks_exact_mut(m) {
+                        bit_reverse_reorder(block);
+                    }
+                }
+
+                #[inline]
+                fn reorder_index(i: usize) -> usize {
+                    debug_assert!(i < $N);
+
+                    reorder_index(i, $N)
+                }
+
+                #[inline]
+                fn twiddle(k: usize) -> Complex<T> {
+                    twiddle::mixed(k, $N)
+                }
+
+                #[inline]
+                fn compute_butterflies(x: &mut [Complex<T>]) {
+                    mixed_cfft_impls!(@butterflies $radix, x, CFftN<{$N / $radix}>);
+                }
+            }
+        )*
+    };
+    (@butterflies 3, $x:ident, $sub:ty) => { radix_3_butterflies::<T, $sub>($x) };
+    (@butterflies 5, $x:ident, $sub:ty) => { radix_5_butterflies::<T, $sub>($x) };
+}
+
+mixed_cfft_impls! {
+    3 => 3, 6 => 3, 12 => 3, 24 => 3, 48 => 3, 96 => 3, 192 => 3, 384 => 3, 768 => 3
+}
+
+mixed_cfft_impls! {
+    5 => 5, 10 => 5, 20 => 5, 40 => 5, 80 => 5, 160 => 5, 320 => 5, 640 => 5, 1280 => 5
+}
+
+mixed_cfft_impls! {
+    15 => 5, 30 => 5, 60 => 5, 120 => 5, 240 => 5, 480 => 5, 960 => 5, 1920 => 5, 3840 => 5
+}
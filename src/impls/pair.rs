@@ -0,0 +1,119 @@
+//! Two RFFTs via one CFFT.
+//!
+//! Two real sequences `a` and `b` are combined into `z = a + i*b`. Since the
+//! spectra of real sequences are Hermitian, the spectra of `a` and `b` can be
+//! separated from the `N`-point CFFT of `z`:
+//!
+//! ```text
+//! A[k] = (Z[k] + conj(Z[N-k])) / 2
+//! B[k] = (Z[k] - conj(Z[N-k])) / (2i)
+//! ```
+//!
+//! Both are stored in the packed RFFT format, `A` in the first and `B` in the
+//! second half of the output. The values `Z[k]`, `Z[N/2-k]`, `Z[N/2+k]` and
+//! `Z[N-k]` determine `A` and `B` at `k` and `N/2-k`, whose packed values
+//! are stored in their place, so the separation is done in place.
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::cfft::*;
+
+/// Compute the packed RFFTs of `a` and `b` into the first and second half of
+/// `y`, respectively.
+#[inline]
+pub(crate) fn forward<T: Float, F: CFft<T>>(a: &[T], b: &[T], y: &mut [Complex<T>]) {
+    let n = F::N;
+    let h = n / 2;
+    debug_assert_eq!(a.len(), n);
+    debug_assert_eq!(b.len(), n);
+    debug_assert_eq!(y.len(), n);
+
+    for (i, (a_i, b_i)) in a.iter().zip(b).enumerate() {
+        y[F::reorder_index(i)] = Complex::new(*a_i, *b_i);
+    }
+    F::compute_butterflies(y);
+
+    // The DC and Nyquist values of `A` and `B` are the real and imaginary
+    // parts of `Z[0]` and `Z[N/2]`.
+    let (z_0, z_h) = (y[0], y[h]);
+    y[0] = Complex::new(z_0.re, z_h.re);
+    y[h] = Complex::new(z_0.im, z_h.im);
+
+    for k in 1..=h / 2 {
+        let j = h - k;
+        let (a_k, b_k) = separate(y[k], y[n - k]);
+        if j == k {
+            y[k] = a_k;
+            y[h + k] = b_k;
+        } else {
+            let (a_j, b_j) = separate(y[j], y[n - j]);
+            y[k] = a_k;
+            y[j] = a_j;
+            y[h + k] = b_k;
+            y[h + j] = b_j;
+        }
+    }
+}
+
+/// Compute the inverse RFFTs of the packed spectra in the first and second
+/// half of `x` into `a` and `b`, respectively.
+///
+/// `x` is used as scratch space.
+#[inline]
+pub(crate) fn inverse<T: Float, F: CFft<T>>(x: &mut [Complex<T>], a: &mut [T], b: &mut [T]) {
+    let n = F::N;
+    let h = n / 2;
+    debug_assert_eq!(x.len(), n);
+    debug_assert_eq!(a.len(), n);
+    debug_assert_eq!(b.len(), n);
+
+    let (p_0, p_h) = (x[0], x[h]);
+    x[0] = Complex::new(p_0.re, p_h.re);
+    x[h] = Complex::new(p_0.im, p_h.im);
+
+    for k in 1..=h / 2 {
+        let j = h - k;
+        let (z_k, z_nk) = combine(x[k], x[h + k]);
+        if j == k {
+            x[k] = z_k;
+            x[n - k] = z_nk;
+        } else {
+            let (z_j, z_nj) = combine(x[j], x[h + j]);
+            x[k] = z_k;
+            x[j] = z_j;
+            x[n - k] = z_nk;
+            x[n - j] = z_nj;
+        }
+    }
+
+    // The inverse transform is computed by reversing the input.
+    for i in 1..h {
+        x.swap(i, n - i);
+    }
+    F::transform(x);
+
+    let scale = T::from_usize(n);
+    for (i, z_i) in x.iter().enumerate() {
+        a[i] = z_i.re / scale;
+        b[i] = z_i.im / scale;
+    }
+}
+
+/// Separate `(A[k], B[k])` from `Z[k]` and `Z[N-k]`.
+#[inline]
+fn separate<T: Float>(z_k: Complex<T>, z_nk: Complex<T>) -> (Complex<T>, Complex<T>) {
+    let sum = (z_k + z_nk.conj()) / T::TWO;
+    let diff = (z_k - z_nk.conj()) / T::TWO;
+    // division by `i`
+    (sum, Complex::new(diff.im, -diff.re))
+}
+
+/// Combine `A[k]` and `B[k]` into `(Z[k], Z[N-k])`.
+#[inline]
+fn combine<T: Float>(a_k: Complex<T>, b_k: Complex<T>) -> (Complex<T>, Complex<T>) {
+    // multiplication by `i`
+    let ib_k = Complex::new(-b_k.im, b_k.re);
+    let ib_nk = Complex::new(b_k.im, b_k.re);
+    (a_k + ib_k, a_k.conj() + ib_nk)
+}
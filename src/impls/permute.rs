@@ -0,0 +1,45 @@
+/// Permute the elements of `x` in place, moving the element at index `i` to
+/// index `dest(i)`.
+///
+/// `dest` must be a permutation of the indices of `x`. Each cycle of the
+/// permutation is rotated once, starting from its smallest index.
+#[inline]
+pub(crate) fn permute<E: Copy>(x: &mut [E], dest: impl Fn(usize) -> usize) {
+    for start in 0..x.len() {
+        // skip cycles that have already been handled
+        let mut i = dest(start);
+        while i > start {
+            i = dest(i);
+        }
+        if i < start {
+            continue;
+        }
+
+        rotate_cycle(x, start, &dest);
+    }
+}
+
+/// Permute the elements of `x` in place, moving the element at index `i` to
+/// index `dest(i)`.
+///
+/// Unlike [`permute`], the cycles of the permutation are not searched for,
+/// but given by `leaders`, which must contain exactly one index of each cycle
+/// that is longer than one element.
+#[inline]
+pub(crate) fn permute_cycles<E: Copy>(x: &mut [E], leaders: &[u16], dest: impl Fn(usize) -> usize) {
+    for &start in leaders {
+        rotate_cycle(x, usize::from(start), &dest);
+    }
+}
+
+/// Rotate the elements of the cycle of `dest` that contains `start`.
+#[inline]
+fn rotate_cycle<E: Copy>(x: &mut [E], start: usize, dest: &impl Fn(usize) -> usize) {
+    let mut carry = x[start];
+    let mut i = dest(start);
+    while i != start {
+        core::mem::swap(&mut carry, &mut x[i]);
+        i = dest(i);
+    }
+    x[start] = carry;
+}
@@ -0,0 +1,291 @@
+//! Fixed-point FFTs using block floating-point scaling.
+//!
+//! Fixed-point butterflies can overflow, so all values share a common block
+//! exponent. Before every stage that can grow the values, the current
+//! headroom is checked and the stage output is scaled down just enough to
+//! guarantee it still fits. The number of right shifts applied in total is
+//! accumulated into the exponent reported to the caller.
+
+use core::slice;
+
+use num_complex::Complex;
+use num_traits::{One, Signed, Zero};
+use static_assertions::{assert_eq_align, assert_eq_size};
+
+use crate::fixed::Fixed;
+use crate::impls::cfft::bit_reverse_reorder;
+
+type Wide<T> = <T as Fixed>::Wide;
+
+pub(crate) struct QFftN<const N: usize>;
+
+impl<const N: usize> QFftN<N> {
+    const LOG2_N: i32 = N.ilog2() as i32;
+
+    /// Perform an `N`-point CFFT, returning the block exponent of the result.
+    #[inline]
+    pub(crate) fn cfft<T: Fixed>(x: &mut [Complex<T>]) -> i32 {
+        debug_assert_eq!(x.len(), N);
+
+        bit_reverse_reorder(x);
+        compute_butterflies(x)
+    }
+
+    /// Perform an `N`-point IFFT, returning the block exponent of the result.
+    #[inline]
+    pub(crate) fn ifft<T: Fixed>(x: &mut [Complex<T>]) -> i32 {
+        debug_assert_eq!(x.len(), N);
+
+        for i in 1..(N / 2) {
+            x.swap(i, N - i);
+        }
+
+        // The division by `N` is a pure exponent adjustment.
+        Self::cfft(x) - Self::LOG2_N
+    }
+
+    /// Perform an `N`-point RFFT, returning the block exponent of the result.
+    #[inline]
+    pub(crate) fn rfft<T: Fixed>(x: &mut [T]) -> (&mut [Complex<T>], i32) {
+        debug_assert_eq!(x.len(), N);
+
+        let x = pack_complex(x);
+        let exponent = if N == 2 {
+            0
+        } else {
+            bit_reverse_reorder(x);
+            compute_butterflies(x)
+        };
+
+        let exponent = exponent + rfft_recombine(x);
+        (x, exponent)
+    }
+
+    /// Perform an `N`-point inverse RFFT, returning the block exponent of the
+    /// result.
+    #[inline]
+    pub(crate) fn irfft<T: Fixed>(x: &mut [Complex<T>]) -> (&mut [T], i32) {
+        debug_assert_eq!(x.len(), N / 2);
+
+        let mut exponent = irfft_recombine(x);
+        if N > 2 {
+            let m = N / 2;
+            for i in 1..(m / 2) {
+                x.swap(i, m - i);
+            }
+
+            bit_reverse_reorder(x);
+            exponent += compute_butterflies(x) - (Self::LOG2_N - 1);
+        }
+
+        (unpack_real(x), exponent)
+    }
+}
+
+/// Determine by how many bits the values in `x` must be shifted down so that
+/// an operation growing their components by a factor of up to `1 + sqrt(2)`
+/// cannot overflow.
+#[inline]
+fn headroom_shift<T: Fixed>(x: &[Complex<T>]) -> usize {
+    let max = x.iter().fold(Wide::<T>::zero(), |max, c| {
+        max.max(c.re.widen().abs()).max(c.im.widen().abs())
+    });
+
+    let quarter = Wide::<T>::one() << (T::FRAC_BITS - 2);
+    if max < quarter {
+        0
+    } else if max < quarter << 1 {
+        1
+    } else {
+        2
+    }
+}
+
+/// Shift `x` right by `shift` bits, rounding to nearest.
+#[inline]
+fn shr_round<W: num_traits::PrimInt>(x: W, shift: usize) -> W {
+    if shift == 0 {
+        x
+    } else {
+        (x + (W::one() << (shift - 1))) >> shift
+    }
+}
+
+/// Compute `(a * b + c * d) / 2^FRAC_BITS`, rounding to nearest.
+///
+/// This cannot overflow as long as all inputs are at most `2^FRAC_BITS` in
+/// magnitude and `b` and `d` are less than that.
+#[inline]
+fn mul_add<T: Fixed>(a: Wide<T>, b: Wide<T>, c: Wide<T>, d: Wide<T>) -> Wide<T> {
+    shr_round(a * b + c * d, T::FRAC_BITS)
+}
+
+#[inline]
+fn narrow<T: Fixed>(x: Complex<Wide<T>>, shift: usize) -> Complex<T> {
+    Complex::new(
+        T::narrow(shr_round(x.re, shift)),
+        T::narrow(shr_round(x.im, shift)),
+    )
+}
+
+#[inline]
+fn widen<T: Fixed>(x: Complex<T>) -> Complex<Wide<T>> {
+    Complex::new(x.re.widen(), x.im.widen())
+}
+
+/// Look up the twiddle factor `exp(-2*PI*i*k/n)` for `k` in `(0, n/4)`.
+#[inline]
+fn twiddle<T: Fixed>(k: usize, n: usize) -> Complex<Wide<T>> {
+    let table_len = T::SINE.len();
+    let s = k * (table_len + 1) * 4 / n;
+    let re = -T::SINE[table_len - s].widen();
+    let im = T::SINE[s - 1].widen();
+    Complex::new(re, im)
+}
+
+/// Multiply `x` by the twiddle factor `exp(-2*PI*i*k/n)` for `k` in `[0, n/2)`.
+#[inline]
+fn rotate<T: Fixed>(x: Complex<Wide<T>>, k: usize, n: usize) -> Complex<Wide<T>> {
+    let u = n / 4;
+    if k == 0 {
+        x
+    } else if k < u {
+        let w = twiddle::<T>(k, n);
+        Complex::new(
+            mul_add::<T>(x.re, w.re, -x.im, w.im),
+            mul_add::<T>(x.re, w.im, x.im, w.re),
+        )
+    } else if k == u {
+        Complex::new(x.im, -x.re)
+    } else {
+        // exp(-2*PI*i*k/n) = -i * exp(-2*PI*i*(k-u)/n)
+        let w = twiddle::<T>(k - u, n);
+        Complex::new(
+            mul_add::<T>(x.re, w.im, x.im, w.re),
+            -mul_add::<T>(x.re, w.re, -x.im, w.im),
+        )
+    }
+}
+
+/// Compute the radix-2 butterflies on bit-reversed input, one stage at a time.
+#[inline]
+fn compute_butterflies<T: Fixed>(x: &mut [Complex<T>]) -> i32 {
+    let n = x.len();
+    let mut exponent = 0;
+
+    let mut size = 2;
+    while size <= n {
+        let shift = headroom_shift(x);
+        exponent += shift as i32;
+
+        let m = size / 2;
+        for k in 0..m {
+            for base in (0..n).step_by(size) {
+                let (i, j) = (base + k, base + k + m);
+                let x_k = widen(x[i]);
+                let y = rotate::<T>(widen(x[j]), k, size);
+                x[i] = narrow(x_k + y, shift);
+                x[j] = narrow(x_k - y, shift);
+            }
+        }
+
+        size *= 2;
+    }
+
+    exponent
+}
+
+#[inline]
+fn pack_complex<T: Fixed>(x: &mut [T]) -> &mut [Complex<T>] {
+    assert_eq_size!(Complex<i16>, [i16; 2]);
+    assert_eq_align!(Complex<i16>, i16);
+    assert_eq_size!(Complex<i32>, [i32; 2]);
+    assert_eq_align!(Complex<i32>, i32);
+
+    let len = x.len() / 2;
+    let data = x.as_mut_ptr().cast::<Complex<T>>();
+    unsafe { slice::from_raw_parts_mut(data, len) }
+}
+
+#[inline]
+fn unpack_real<T: Fixed>(x: &mut [Complex<T>]) -> &mut [T] {
+    let len = x.len() * 2;
+    let data = x.as_mut_ptr().cast::<T>();
+    unsafe { slice::from_raw_parts_mut(data, len) }
+}
+
+/// Fixed-point equivalent of `RFft::recombine`.
+#[inline]
+fn rfft_recombine<T: Fixed>(x: &mut [Complex<T>]) -> i32 {
+    let m = x.len();
+    let n = m * 2;
+    let shift = headroom_shift(x);
+
+    let x0 = widen(x[0]);
+    x[0] = narrow(Complex::new(x0.re + x0.im, x0.re - x0.im), shift);
+    if m == 1 {
+        return shift as i32;
+    }
+
+    let u = m / 2;
+    for k in 1..u {
+        let (x_k, x_nk) = (widen(x[k]), widen(x[m - k]));
+        let sum = Complex::new(
+            shr_round(x_k.re + x_nk.re, 1),
+            shr_round(x_k.im + x_nk.im, 1),
+        );
+        let diff = Complex::new(
+            shr_round(x_k.re - x_nk.re, 1),
+            shr_round(x_k.im - x_nk.im, 1),
+        );
+
+        let w = twiddle::<T>(k, n);
+        let a = mul_add::<T>(w.re, sum.im, w.im, diff.re);
+        let b = mul_add::<T>(w.im, sum.im, -w.re, diff.re);
+
+        x[k] = narrow(Complex::new(sum.re + a, diff.im + b), shift);
+        x[m - k] = narrow(Complex::new(sum.re - a, -diff.im + b), shift);
+    }
+
+    let xu = widen(x[u]);
+    x[u] = narrow(Complex::new(xu.re, -xu.im), shift);
+
+    shift as i32
+}
+
+/// Fixed-point equivalent of `IRFft::recombine`.
+#[inline]
+fn irfft_recombine<T: Fixed>(x: &mut [Complex<T>]) -> i32 {
+    let m = x.len();
+    let n = m * 2;
+    let shift = headroom_shift(x);
+
+    let x0 = widen(x[0]);
+    x[0] = narrow(Complex::new(x0.re + x0.im, x0.re - x0.im), shift + 1);
+    if m == 1 {
+        return shift as i32;
+    }
+
+    let u = m / 2;
+    for k in 1..u {
+        let (x_k, x_nk) = (widen(x[k]), widen(x[m - k]));
+        let sum_re = shr_round(x_k.re + x_nk.re, 1);
+        let diff_im = shr_round(x_k.im - x_nk.im, 1);
+        let a = shr_round(x_k.re - x_nk.re, 1);
+        let b = shr_round(x_k.im + x_nk.im, 1);
+
+        // The twiddle factors have unit magnitude, so the inversion performed
+        // in the floating-point version reduces to a rotation.
+        let w = twiddle::<T>(k, n);
+        let sum_im = mul_add::<T>(a, w.re, b, w.im);
+        let diff_re = mul_add::<T>(a, w.im, -b, w.re);
+
+        x[k] = narrow(Complex::new(sum_re + diff_re, sum_im + diff_im), shift);
+        x[m - k] = narrow(Complex::new(sum_re - diff_re, sum_im - diff_im), shift);
+    }
+
+    let xu = widen(x[u]);
+    x[u] = narrow(Complex::new(xu.re, -xu.im), shift);
+
+    shift as i32
+}
@@ -1,97 +1,156 @@
 use core::slice;
 
+use num_complex::Complex;
 use static_assertions::{assert_eq_align, assert_eq_size};
 
+use crate::float::Float;
 use crate::impls::cfft::*;
-use crate::{tables, Complex32};
+use crate::impls::twiddle;
+use crate::{Complex32, Complex64};
 
-pub(crate) trait RFft {
-    type CFft: CFft;
+pub(crate) trait RFft<T: Float> {
+    type CFft: CFft<T>;
 
     const N: usize = Self::CFft::N * 2;
 
     #[inline]
-    fn transform(x: &mut [f32]) -> &mut [Complex32] {
+    fn transform(x: &mut [T]) -> &mut [Complex<T>] {
+        Self::transform_scaled(x, None)
+    }
+
+    /// Perform the transform, scaling the result by `scale`, if given.
+    ///
+    /// The scaling is performed as part of the recombination, so it does not
+    /// require an additional pass over the data.
+    #[inline]
+    fn transform_scaled(x: &mut [T], scale: Option<T>) -> &mut [Complex<T>] {
         debug_assert_eq!(x.len(), Self::N);
 
         let x = Self::pack_complex(x);
 
         Self::CFft::transform(x);
-        Self::recombine(x);
+        Self::recombine(x, scale);
         x
     }
 
+    /// Perform the transform of `x` into `y`, leaving `x` unmodified.
+    ///
+    /// The packing and reordering of the input values is performed while
+    /// copying them, rather than as separate passes.
+    #[inline]
+    fn transform_into(x: &[T], y: &mut [Complex<T>]) {
+        debug_assert_eq!(x.len(), Self::N);
+        debug_assert_eq!(y.len(), Self::N / 2);
+
+        for (i, pair) in x.chunks_exact(2).enumerate() {
+            y[Self::CFft::reorder_index(i)] = Complex::new(pair[0], pair[1]);
+        }
+        Self::CFft::compute_butterflies(y);
+        Self::recombine(y, None);
+    }
+
+    /// Perform the transform of `x` into the `N/2 + 1` bins of `y`, leaving
+    /// `x` unmodified.
+    ///
+    /// The Nyquist coefficient is written to the last bin instead of being
+    /// packed into the imaginary part of the DC bin.
     #[inline]
-    fn pack_complex(x: &mut [f32]) -> &mut [Complex32] {
+    fn transform_full(x: &[T], y: &mut [Complex<T>]) {
+        debug_assert_eq!(y.len(), Self::N / 2 + 1);
+
+        let (y, nyquist) = y.split_at_mut(Self::N / 2);
+        Self::transform_into(x, y);
+        nyquist[0] = Complex::new(y[0].im, T::zero());
+        y[0].im = T::zero();
+    }
+
+    #[inline]
+    fn pack_complex(x: &mut [T]) -> &mut [Complex<T>] {
         assert_eq_size!(Complex32, [f32; 2]);
         assert_eq_align!(Complex32, f32);
+        assert_eq_size!(Complex64, [f64; 2]);
+        assert_eq_align!(Complex64, f64);
         assert_eq!(x.len(), Self::N);
 
         let len = Self::N / 2;
-        let data = x.as_mut_ptr().cast::<Complex32>();
+        let data = x.as_mut_ptr().cast::<Complex<T>>();
         unsafe { slice::from_raw_parts_mut(data, len) }
     }
 
+    /// Look up the twiddle factor `exp(-2*PI*i*k/N)` for `k` in `(0, N/4)`.
     #[inline]
-    fn recombine(x: &mut [Complex32]) {
+    fn twiddle(k: usize) -> Complex<T> {
+        twiddle::quarter(k, Self::N)
+    }
+
+    /// Recombine the CFFT result into the RFFT result, scaling it by `scale`,
+    /// if given.
+    #[inline]
+    fn recombine(x: &mut [Complex<T>], scale: Option<T>) {
         let m = Self::CFft::N;
         debug_assert_eq!(x.len(), m);
 
-        let table_len = tables::SINE.len();
-        let table_stride = (table_len + 1) * 4 / Self::N;
+        let scaled = |c: Complex<T>| match scale {
+            Some(s) => c.scale(s),
+            None => c,
+        };
 
         // The real part of the first element is the DC value.
         // Additionally, the real-valued coefficient at the Nyquist frequency
         // is stored in the imaginary part.
         let x0 = x[0];
-        x[0] = Complex32::new(x0.re + x0.im, x0.re - x0.im);
+        x[0] = scaled(Complex::new(x0.re + x0.im, x0.re - x0.im));
 
         let u = m / 2;
         for k in 1..u {
-            let s = k * table_stride;
-            let twiddle_re = tables::SINE[table_len - s] * -1.;
-            let twiddle_im = tables::SINE[s - 1];
+            let twiddle = Self::twiddle(k);
+            let (twiddle_re, twiddle_im) = (twiddle.re, twiddle.im);
 
             let (x_k, x_nk) = (x[k], x[m - k]);
-            let sum = (x_k + x_nk) / 2.;
-            let diff = (x_k - x_nk) / 2.;
+            let sum = (x_k + x_nk) / T::TWO;
+            let diff = (x_k - x_nk) / T::TWO;
 
-            x[k] = Complex32::new(
+            x[k] = scaled(Complex::new(
                 sum.re + twiddle_re * sum.im + twiddle_im * diff.re,
                 diff.im + twiddle_im * sum.im - twiddle_re * diff.re,
-            );
-            x[m - k] = Complex32::new(
+            ));
+            x[m - k] = scaled(Complex::new(
                 sum.re - twiddle_re * sum.im - twiddle_im * diff.re,
                 -diff.im + twiddle_im * sum.im - twiddle_re * diff.re,
-            );
+            ));
         }
 
         let xu = x[u];
-        x[u] = Complex32::new(xu.re, -xu.im);
+        x[u] = scaled(Complex::new(xu.re, -xu.im));
     }
 }
 
 pub(crate) struct RFftN<const N: usize>;
 
-impl RFft for RFftN<2> {
+impl<T: Float> RFft<T> for RFftN<2> {
     type CFft = CFftN<1>;
 
     #[inline]
-    fn recombine(x: &mut [Complex32]) {
+    fn recombine(x: &mut [Complex<T>], scale: Option<T>) {
         debug_assert_eq!(x.len(), 1);
 
+        let scaled = |c: Complex<T>| match scale {
+            Some(s) => c.scale(s),
+            None => c,
+        };
+
         // The real part of the first element is the DC value.
         // Additionally, the real-valued coefficient at the Nyquist frequency
         // is stored in the imaginary part.
         let x0 = x[0];
-        x[0] = Complex32::new(x0.re + x0.im, x0.re - x0.im);
+        x[0] = scaled(Complex::new(x0.re + x0.im, x0.re - x0.im));
     }
 }
 
 macro_rules! rfft_impls {
     ( $( $N:expr ),* ) => {
         $(
-            impl RFft for RFftN<$N> {
+            impl<T: Float> RFft<T> for RFftN<$N> {
                 type CFft = CFftN<{$N / 2}>;
             }
         )*
@@ -99,3 +158,22 @@ macro_rules! rfft_impls {
 }
 
 rfft_impls! { 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768 }
+
+macro_rules! mixed_rfft_impls {
+    ( $( $N:expr ),* ) => {
+        $(
+            impl<T: Float> RFft<T> for RFftN<$N> {
+                type CFft = CFftN<{$N / 2}>;
+
+                #[inline]
+                fn twiddle(k: usize) -> Complex<T> {
+                    twiddle::mixed(k, $N)
+                }
+            }
+        )*
+    };
+}
+
+mixed_rfft_impls! { 12, 24, 48, 96, 192, 384, 768 }
+mixed_rfft_impls! { 20, 40, 80, 160, 320, 640, 1280 }
+mixed_rfft_impls! { 60, 120, 240, 480, 960, 1920, 3840 }
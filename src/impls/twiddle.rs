@@ -0,0 +1,153 @@
+//! Twiddle factor lookups.
+//!
+//! The sine tables only store the values of a quarter period, excluding the
+//! endpoints. All other values are derived from those via symmetry.
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::math;
+
+/// Look up the twiddle factor `exp(-2*PI*i*k/n)` for `k` in `(0, n/4)`,
+/// where `n` is a power of two.
+#[inline]
+pub(crate) fn quarter<T: Float>(k: usize, n: usize) -> Complex<T> {
+    let table_len = T::SINE.len();
+    let table_stride = (table_len + 1) * 4 / n;
+
+    let s = k * table_stride;
+    let re = -T::SINE[table_len - s];
+    let im = T::SINE[s - 1];
+    Complex::new(re, im)
+}
+
+/// Look up the twiddle factor `exp(-2*PI*i*k/n)` for any `k`, where `n`
+/// is a power of two.
+#[inline]
+pub(crate) fn pow2<T: Float>(k: usize, n: usize) -> Complex<T> {
+    lookup(T::SINE, k, n)
+}
+
+/// Look up the twiddle factor `exp(-2*PI*i*k/n)` for any `k`, where `n`
+/// divides the size of the mixed-radix sine table.
+#[inline]
+pub(crate) fn mixed<T: Float>(k: usize, n: usize) -> Complex<T> {
+    lookup(T::SINE_MIXED, k, n)
+}
+
+/// The distance in the mixed-radix sine table between the twiddle factors
+/// `exp(-2*PI*i*k/n)` of consecutive `k`, where `n` divides the size of the
+/// table.
+#[inline]
+pub(crate) fn mixed_stride<T: Float>(n: usize) -> usize {
+    let period = (T::SINE_MIXED.len() + 1) * 4;
+    debug_assert_eq!(period % n, 0);
+
+    period / n
+}
+
+/// Look up the twiddle factor `exp(-2*PI*i*j/period)` for `j` in
+/// `[0, period)`, where `period` is the size of the mixed-radix sine table.
+///
+/// With `j = k * mixed_stride(n)` this is the twiddle factor
+/// `exp(-2*PI*i*k/n)`.
+#[inline]
+pub(crate) fn mixed_at<T: Float>(j: usize) -> Complex<T> {
+    at(T::SINE_MIXED, j)
+}
+
+/// Get the twiddle factor `exp(-2*PI*i*k/n)` for any `k` and `n > 0`.
+///
+/// It is looked up from the sine tables if `n` divides the period of one of
+/// them, and computed otherwise.
+#[inline]
+pub(crate) fn any<T: Float>(k: usize, n: usize) -> Complex<T> {
+    if ((T::SINE.len() + 1) * 4) % n == 0 {
+        lookup(T::SINE, k, n)
+    } else if ((T::SINE_MIXED.len() + 1) * 4) % n == 0 {
+        lookup(T::SINE_MIXED, k, n)
+    } else {
+        let angle = -2. * core::f64::consts::PI * (k % n) as f64 / n as f64;
+        let (sin, cos) = math::sin_cos(angle);
+        Complex::new(T::from_f64(cos), T::from_f64(sin))
+    }
+}
+
+/// Twiddle factors `exp(-2*PI*i*k/n)` for any `k`, where `n` is a power of
+/// two of up to sixteen times the period of the sine table.
+///
+/// Twiddle factors between the table entries are derived by rotating the
+/// preceding table entry by a small angle. The few rotations needed for
+/// this are computed once, when constructing the lookup.
+pub(crate) struct Fine<T> {
+    n: usize,
+    ratio: usize,
+    rotations: [Complex<T>; 16],
+}
+
+impl<T: Float> Fine<T> {
+    #[inline]
+    pub(crate) fn new(n: usize) -> Self {
+        let period = (T::SINE.len() + 1) * 4;
+        let ratio = if n > period { n / period } else { 1 };
+        debug_assert!(ratio <= 16);
+
+        let mut rotations = [Complex::new(T::one(), T::zero()); 16];
+        for (r, rotation) in rotations.iter_mut().enumerate().take(ratio).skip(1) {
+            let angle = -2. * core::f64::consts::PI * r as f64 / n as f64;
+            let (sin, cos) = math::sin_cos(angle);
+            *rotation = Complex::new(T::from_f64(cos), T::from_f64(sin));
+        }
+
+        Self {
+            n,
+            ratio,
+            rotations,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get(&self, k: usize) -> Complex<T> {
+        let (q, r) = (k / self.ratio, k % self.ratio);
+        let coarse = lookup(T::SINE, q, self.n / self.ratio);
+        if r == 0 {
+            coarse
+        } else {
+            coarse * self.rotations[r]
+        }
+    }
+}
+
+#[inline]
+fn lookup<T: Float>(table: &[T], k: usize, n: usize) -> Complex<T> {
+    let table_len = table.len();
+    let quarter = table_len + 1;
+    let period = quarter * 4;
+    debug_assert_eq!(period % n, 0);
+
+    at(table, (k % n) * (period / n))
+}
+
+#[inline]
+fn at<T: Float>(table: &[T], j: usize) -> Complex<T> {
+    let table_len = table.len();
+    let quarter = table_len + 1;
+    debug_assert!(j < quarter * 4);
+
+    let (q, r) = (j / quarter, j % quarter);
+
+    // exp(-2*PI*i*r/period), with `r` in the first quadrant
+    let (re, im) = if r == 0 {
+        (T::one(), T::zero())
+    } else {
+        (-table[table_len - r], table[r - 1])
+    };
+
+    // rotate by `-PI/2` for each additional quadrant
+    match q {
+        0 => Complex::new(re, im),
+        1 => Complex::new(im, -re),
+        2 => Complex::new(-re, -im),
+        _ => Complex::new(-im, re),
+    }
+}
@@ -1,10 +1,22 @@
 //! Inverse FFT (IFFT)
 
+use crate::impls::fft2d::COLUMNS;
 use crate::impls::ifft::*;
-use crate::Complex32;
+use crate::impls::qfft::*;
+use crate::normalization;
+use crate::size::{CFftImpl, CFftSize, Size};
+use crate::{Complex32, Complex64, ComplexQ15, ComplexQ31, Normalization};
 
 macro_rules! ifft_impls {
-    ( $( $N:expr => ($ifft_N:ident $(, $feature:expr)?), )* ) => {
+    (
+        $(
+            $N:expr => (
+                $ifft_N:ident,
+                $ifft_N_f64:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
         $(
             #[doc = concat!("Perform an in-place ", stringify!($N), "-point IFFT.")]
             #[doc = ""]
@@ -23,24 +35,372 @@ macro_rules! ifft_impls {
                 IFftN::<$N>::transform(input);
                 input
             }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point IFFT on double-precision values.")]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{Complex64, inverse::", stringify!($ifft_N_f64), "};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [Complex64::default(); ", stringify!($N), "];")]
+            #[doc = concat!("let result = ", stringify!($ifft_N_f64), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $ifft_N_f64(input: &mut [Complex64; $N]) -> &mut [Complex64; $N] {
+                IFftN::<$N>::transform(input);
+                input
+            }
+        )*
+    };
+}
+
+macro_rules! fixed_ifft_impls {
+    (
+        $(
+            $N:expr => (
+                $ifft_N_q15:ident,
+                $ifft_N_q31:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
+        $(
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point IFFT on Q15 fixed-point values.")]
+            #[doc = ""]
+            #[doc = "Values are scaled down as needed during the computation to avoid overflows."]
+            #[doc = "Returns the result together with its block exponent `e`, meaning the actual"]
+            #[doc = "transform values are the returned values multiplied by `2^e`."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{ComplexQ15, inverse::", stringify!($ifft_N_q15), "};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [ComplexQ15::default(); ", stringify!($N), "];")]
+            #[doc = concat!("let (result, exponent) = ", stringify!($ifft_N_q15), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $ifft_N_q15(input: &mut [ComplexQ15; $N]) -> (&mut [ComplexQ15; $N], i32) {
+                let exponent = QFftN::<$N>::ifft(input);
+                (input, exponent)
+            }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point IFFT on Q31 fixed-point values.")]
+            #[doc = ""]
+            #[doc = "Values are scaled down as needed during the computation to avoid overflows."]
+            #[doc = "Returns the result together with its block exponent `e`, meaning the actual"]
+            #[doc = "transform values are the returned values multiplied by `2^e`."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{ComplexQ31, inverse::", stringify!($ifft_N_q31), "};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [ComplexQ31::default(); ", stringify!($N), "];")]
+            #[doc = concat!("let (result, exponent) = ", stringify!($ifft_N_q31), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $ifft_N_q31(input: &mut [ComplexQ31; $N]) -> (&mut [ComplexQ31; $N], i32) {
+                let exponent = QFftN::<$N>::ifft(input);
+                (input, exponent)
+            }
         )*
     };
 }
 
 ifft_impls! {
-    2 => (ifft_2),
-    4 => (ifft_4, "size-4"),
-    8 => (ifft_8, "size-8"),
-    16 => (ifft_16, "size-16"),
-    32 => (ifft_32, "size-32"),
-    64 => (ifft_64, "size-64"),
-    128 => (ifft_128, "size-128"),
-    256 => (ifft_256, "size-256"),
-    512 => (ifft_512, "size-512"),
-    1024 => (ifft_1024, "size-1024"),
-    2048 => (ifft_2048, "size-2048"),
-    4096 => (ifft_4096, "size-4096"),
-    8192 => (ifft_8192, "size-8192"),
-    16384 => (ifft_16384, "size-16384"),
-    32768 => (ifft_32768, "size-32768"),
+    2 => (ifft_2, ifft_2_f64),
+    4 => (ifft_4, ifft_4_f64, "size-4"),
+    8 => (ifft_8, ifft_8_f64, "size-8"),
+    16 => (ifft_16, ifft_16_f64, "size-16"),
+    32 => (ifft_32, ifft_32_f64, "size-32"),
+    64 => (ifft_64, ifft_64_f64, "size-64"),
+    128 => (ifft_128, ifft_128_f64, "size-128"),
+    256 => (ifft_256, ifft_256_f64, "size-256"),
+    512 => (ifft_512, ifft_512_f64, "size-512"),
+    1024 => (ifft_1024, ifft_1024_f64, "size-1024"),
+    2048 => (ifft_2048, ifft_2048_f64, "size-2048"),
+    4096 => (ifft_4096, ifft_4096_f64, "size-4096"),
+    8192 => (ifft_8192, ifft_8192_f64, "size-8192"),
+    16384 => (ifft_16384, ifft_16384_f64, "size-16384"),
+    32768 => (ifft_32768, ifft_32768_f64, "size-32768"),
+}
+
+ifft_impls! {
+    12 => (ifft_12, ifft_12_f64, "size-12"),
+    24 => (ifft_24, ifft_24_f64, "size-24"),
+    48 => (ifft_48, ifft_48_f64, "size-48"),
+    96 => (ifft_96, ifft_96_f64, "size-96"),
+    192 => (ifft_192, ifft_192_f64, "size-192"),
+    384 => (ifft_384, ifft_384_f64, "size-384"),
+    768 => (ifft_768, ifft_768_f64, "size-768"),
+    20 => (ifft_20, ifft_20_f64, "size-20"),
+    40 => (ifft_40, ifft_40_f64, "size-40"),
+    80 => (ifft_80, ifft_80_f64, "size-80"),
+    160 => (ifft_160, ifft_160_f64, "size-160"),
+    320 => (ifft_320, ifft_320_f64, "size-320"),
+    640 => (ifft_640, ifft_640_f64, "size-640"),
+    1280 => (ifft_1280, ifft_1280_f64, "size-1280"),
+    60 => (ifft_60, ifft_60_f64, "size-60"),
+    120 => (ifft_120, ifft_120_f64, "size-120"),
+    240 => (ifft_240, ifft_240_f64, "size-240"),
+    480 => (ifft_480, ifft_480_f64, "size-480"),
+    960 => (ifft_960, ifft_960_f64, "size-960"),
+    1920 => (ifft_1920, ifft_1920_f64, "size-1920"),
+    3840 => (ifft_3840, ifft_3840_f64, "size-3840"),
+}
+
+fixed_ifft_impls! {
+    2 => (ifft_2_q15, ifft_2_q31),
+    4 => (ifft_4_q15, ifft_4_q31, "size-4"),
+    8 => (ifft_8_q15, ifft_8_q31, "size-8"),
+    16 => (ifft_16_q15, ifft_16_q31, "size-16"),
+    32 => (ifft_32_q15, ifft_32_q31, "size-32"),
+    64 => (ifft_64_q15, ifft_64_q31, "size-64"),
+    128 => (ifft_128_q15, ifft_128_q31, "size-128"),
+    256 => (ifft_256_q15, ifft_256_q31, "size-256"),
+    512 => (ifft_512_q15, ifft_512_q31, "size-512"),
+    1024 => (ifft_1024_q15, ifft_1024_q31, "size-1024"),
+    2048 => (ifft_2048_q15, ifft_2048_q31, "size-2048"),
+    4096 => (ifft_4096_q15, ifft_4096_q31, "size-4096"),
+    8192 => (ifft_8192_q15, ifft_8192_q31, "size-8192"),
+    16384 => (ifft_16384_q15, ifft_16384_q31, "size-16384"),
+    32768 => (ifft_32768_q15, ifft_32768_q31, "size-32768"),
+}
+
+/// Perform an in-place `N`-point IFFT.
+///
+/// This is the const-generic equivalent of the `ifft_N` functions. It
+/// can be used with any `N` for which [`Size<N>`](Size) implements
+/// [`CFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, inverse::ifft};
+///
+/// let mut input = [Complex32::default(); 16];
+/// let result = ifft(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn ifft<const N: usize>(input: &mut [Complex32; N]) -> &mut [Complex32; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::ifft(input);
+    input
+}
+
+/// Perform an in-place `N`-point IFFT on double-precision values.
+///
+/// This is the const-generic equivalent of the `ifft_N_f64` functions. It
+/// can be used with any `N` for which [`Size<N>`](Size) implements
+/// [`CFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, inverse::ifft_f64};
+///
+/// let mut input = [Complex64::default(); 16];
+/// let result = ifft_f64(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn ifft_f64<const N: usize>(input: &mut [Complex64; N]) -> &mut [Complex64; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::ifft(input);
+    input
+}
+
+/// Perform an out-of-place `N`-point IFFT.
+///
+/// Like [`ifft`], but leaves `input` unmodified and writes the result into
+/// `output` instead.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, inverse::ifft_into};
+///
+/// let input = [Complex32::default(); 16];
+/// let mut output = [Complex32::default(); 16];
+/// let result = ifft_into(&input, &mut output);
+/// ```
+#[inline]
+pub fn ifft_into<'a, const N: usize>(
+    input: &[Complex32; N],
+    output: &'a mut [Complex32; N],
+) -> &'a mut [Complex32; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::ifft_into(input, output);
+    output
+}
+
+/// Perform an out-of-place `N`-point IFFT on double-precision values.
+///
+/// Like [`ifft_f64`], but leaves `input` unmodified and writes the result into
+/// `output` instead.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, inverse::ifft_into_f64};
+///
+/// let input = [Complex64::default(); 16];
+/// let mut output = [Complex64::default(); 16];
+/// let result = ifft_into_f64(&input, &mut output);
+/// ```
+#[inline]
+pub fn ifft_into_f64<'a, const N: usize>(
+    input: &[Complex64; N],
+    output: &'a mut [Complex64; N],
+) -> &'a mut [Complex64; N]
+where
+    Size<N>: CFftSize,
+{
+    Size::<N>::ifft_into(input, output);
+    output
+}
+
+/// Perform an in-place `N`-point IFFT, using the given normalization.
+///
+/// Like [`ifft`], but scales the result according to the inverse
+/// transform part of `normalization`.
+/// If no scaling is required, the pass over the data that normally performs
+/// the `1/N` normalization is skipped.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, Normalization, inverse::ifft_normalized};
+///
+/// let mut input = [Complex32::default(); 16];
+/// let result = ifft_normalized(&mut input, Normalization::Orthonormal);
+/// ```
+#[inline]
+#[must_use]
+pub fn ifft_normalized<const N: usize>(
+    input: &mut [Complex32; N],
+    normalization: Normalization,
+) -> &mut [Complex32; N]
+where
+    Size<N>: CFftSize,
+{
+    if normalization == Normalization::Inverse {
+        Size::<N>::ifft(input);
+    } else {
+        Size::<N>::ifft_unnormalized(input);
+        normalization::scale(input, normalization.inverse_scale(N));
+    }
+    input
+}
+
+/// Perform an in-place `N`-point IFFT on double-precision values, using the given normalization.
+///
+/// Like [`ifft_f64`], but scales the result according to the inverse
+/// transform part of `normalization`.
+/// If no scaling is required, the pass over the data that normally performs
+/// the `1/N` normalization is skipped.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, Normalization, inverse::ifft_normalized_f64};
+///
+/// let mut input = [Complex64::default(); 16];
+/// let result = ifft_normalized_f64(&mut input, Normalization::Orthonormal);
+/// ```
+#[inline]
+#[must_use]
+pub fn ifft_normalized_f64<const N: usize>(
+    input: &mut [Complex64; N],
+    normalization: Normalization,
+) -> &mut [Complex64; N]
+where
+    Size<N>: CFftSize,
+{
+    if normalization == Normalization::Inverse {
+        Size::<N>::ifft(input);
+    } else {
+        Size::<N>::ifft_unnormalized(input);
+        normalization::scale(input, normalization.inverse_scale(N));
+    }
+    input
+}
+
+/// Perform an in-place 2D IFFT on `H` rows of `W` values each.
+///
+/// This is the inverse of [`fft2d`](crate::complex::fft2d), including the
+/// scaling by `1/(W*H)`. Like it, the columns are transformed in blocks of 4
+/// adjacent columns through a buffer of `4 * H` values on the stack, which
+/// takes up `32 * H` bytes.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, inverse::ifft2d};
+///
+/// let mut input = [[Complex32::default(); 32]; 16];
+/// let result = ifft2d(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn ifft2d<const W: usize, const H: usize>(
+    input: &mut [[Complex32; W]; H],
+) -> &mut [[Complex32; W]; H]
+where
+    Size<W>: CFftSize,
+    Size<H>: CFftSize,
+{
+    for row in input.iter_mut() {
+        Size::<W>::ifft(row);
+    }
+    let mut columns = [[Complex32::default(); H]; COLUMNS];
+    Size::<H>::ifft_columns(input, &mut columns);
+    input
+}
+
+/// Perform an in-place 2D IFFT on `H` rows of `W` double-precision values each.
+///
+/// This is the inverse of [`fft2d_f64`](crate::complex::fft2d_f64), including
+/// the scaling by `1/(W*H)`. Like it, the columns are transformed in blocks
+/// of 4 adjacent columns through a buffer of `4 * H` values on the stack,
+/// which takes up `64 * H` bytes.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, inverse::ifft2d_f64};
+///
+/// let mut input = [[Complex64::default(); 32]; 16];
+/// let result = ifft2d_f64(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn ifft2d_f64<const W: usize, const H: usize>(
+    input: &mut [[Complex64; W]; H],
+) -> &mut [[Complex64; W]; H]
+where
+    Size<W>: CFftSize,
+    Size<H>: CFftSize,
+{
+    for row in input.iter_mut() {
+        Size::<W>::ifft(row);
+    }
+    let mut columns = [[Complex64::default(); H]; COLUMNS];
+    Size::<H>::ifft_columns(input, &mut columns);
+    input
 }
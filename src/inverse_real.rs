@@ -4,6 +4,8 @@
 //! `N/2` complex frequency-domain samples and producing `N` real
 //! time-domain samples. It expects the Nyquist frequency coefficient
 //! to be stored in the imaginary part of the DC bin.
+//! [`irfft_full`] instead accepts the standard `N/2 + 1` bins, with the
+//! Nyquist coefficient in the last bin.
 //!
 //! The inverse real FFT (IRFFT) is optimized by internally using an
 //! `N/2`-point inverse CFFT, roughly doubling the computation speed
@@ -11,11 +13,24 @@
 
 use core::convert::TryInto;
 
+use crate::impls::fft2d::COLUMNS;
 use crate::impls::irfft::*;
-use crate::Complex32;
+use crate::impls::qfft::*;
+use crate::size::{CFftImpl, CFftSize, RFftImpl, RFftSize, Size};
+use crate::{Complex32, Complex64, ComplexQ15, ComplexQ31, Normalization};
 
 macro_rules! irfft_impls {
-    ( $( $N:expr => ($irfft_N:ident $(, $feature:expr)?), )* ) => {
+    (
+        $(
+            $N:expr => (
+                $irfft_N:ident,
+                $irfft_N_f64:ident,
+                $irfft_N_into:ident,
+                $irfft_N_into_f64:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
         $(
             #[doc = concat!("Perform an in-place ", stringify!($N), "-point inverse RFFT.")]
             #[doc = ""]
@@ -36,24 +51,603 @@ macro_rules! irfft_impls {
             pub fn $irfft_N(input: &mut [Complex32; $N / 2]) -> &mut [f32; $N] {
                 IRFftN::<$N>::transform(input).try_into().unwrap()
             }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point inverse RFFT on double-precision values.")]
+            #[doc = ""]
+            #[doc = "Takes N/2 complex frequency-domain samples and produces N real time-domain samples."]
+            #[doc = "The Nyquist frequency coefficient should be stored in the imaginary part of the DC bin."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{Complex64, inverse_real::", stringify!($irfft_N_f64), "};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [Complex64::default(); ", stringify!($N), " / 2];")]
+            #[doc = concat!("let result = ", stringify!($irfft_N_f64), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $irfft_N_f64(input: &mut [Complex64; $N / 2]) -> &mut [f64; $N] {
+                IRFftN::<$N>::transform(input).try_into().unwrap()
+            }
+
+            #[doc = concat!("Perform an out-of-place ", stringify!($N), "-point inverse RFFT.")]
+            #[doc = ""]
+            #[doc = concat!("Like [`", stringify!($irfft_N), "`], but leaves `input` unmodified and writes the result into")]
+            #[doc = "`output` instead."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{Complex32, inverse_real::", stringify!($irfft_N_into), "};")]
+            #[doc = ""]
+            #[doc = concat!("let input = [Complex32::default(); ", stringify!($N), " / 2];")]
+            #[doc = concat!("let mut output = [0.; ", stringify!($N), "];")]
+            #[doc = concat!("let result = ", stringify!($irfft_N_into), "(&input, &mut output);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            pub fn $irfft_N_into<'a>(
+                input: &[Complex32; $N / 2],
+                output: &'a mut [f32; $N],
+            ) -> &'a mut [f32; $N] {
+                IRFftN::<$N>::transform_into(input, output);
+                output
+            }
+
+            #[doc = concat!("Perform an out-of-place ", stringify!($N), "-point inverse RFFT on double-precision values.")]
+            #[doc = ""]
+            #[doc = concat!("Like [`", stringify!($irfft_N_f64), "`], but leaves `input` unmodified and writes the result into")]
+            #[doc = "`output` instead."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{Complex64, inverse_real::", stringify!($irfft_N_into_f64), "};")]
+            #[doc = ""]
+            #[doc = concat!("let input = [Complex64::default(); ", stringify!($N), " / 2];")]
+            #[doc = concat!("let mut output = [0.; ", stringify!($N), "];")]
+            #[doc = concat!("let result = ", stringify!($irfft_N_into_f64), "(&input, &mut output);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            pub fn $irfft_N_into_f64<'a>(
+                input: &[Complex64; $N / 2],
+                output: &'a mut [f64; $N],
+            ) -> &'a mut [f64; $N] {
+                IRFftN::<$N>::transform_into(input, output);
+                output
+            }
+        )*
+    };
+}
+
+macro_rules! fixed_irfft_impls {
+    (
+        $(
+            $N:expr => (
+                $irfft_N_q15:ident,
+                $irfft_N_q31:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
+        $(
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point inverse RFFT on Q15 fixed-point values.")]
+            #[doc = ""]
+            #[doc = "Takes N/2 complex frequency-domain samples and produces N real time-domain samples."]
+            #[doc = "The Nyquist frequency coefficient should be stored in the imaginary part of the DC bin."]
+            #[doc = ""]
+            #[doc = "Values are scaled down as needed during the computation to avoid overflows."]
+            #[doc = "Returns the result together with its block exponent `e`, meaning the actual"]
+            #[doc = "transform values are the returned values multiplied by `2^e`."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{ComplexQ15, inverse_real::", stringify!($irfft_N_q15), "};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [ComplexQ15::default(); ", stringify!($N), " / 2];")]
+            #[doc = concat!("let (result, exponent) = ", stringify!($irfft_N_q15), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $irfft_N_q15(input: &mut [ComplexQ15; $N / 2]) -> (&mut [i16; $N], i32) {
+                let (output, exponent) = QFftN::<$N>::irfft(input);
+                (output.try_into().unwrap(), exponent)
+            }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point inverse RFFT on Q31 fixed-point values.")]
+            #[doc = ""]
+            #[doc = "Takes N/2 complex frequency-domain samples and produces N real time-domain samples."]
+            #[doc = "The Nyquist frequency coefficient should be stored in the imaginary part of the DC bin."]
+            #[doc = ""]
+            #[doc = "Values are scaled down as needed during the computation to avoid overflows."]
+            #[doc = "Returns the result together with its block exponent `e`, meaning the actual"]
+            #[doc = "transform values are the returned values multiplied by `2^e`."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{ComplexQ31, inverse_real::", stringify!($irfft_N_q31), "};")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [ComplexQ31::default(); ", stringify!($N), " / 2];")]
+            #[doc = concat!("let (result, exponent) = ", stringify!($irfft_N_q31), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $irfft_N_q31(input: &mut [ComplexQ31; $N / 2]) -> (&mut [i32; $N], i32) {
+                let (output, exponent) = QFftN::<$N>::irfft(input);
+                (output.try_into().unwrap(), exponent)
+            }
         )*
     };
 }
 
 irfft_impls! {
-    2 => (irfft_2),
-    4 => (irfft_4),
-    8 => (irfft_8, "size-4"),
-    16 => (irfft_16, "size-8"),
-    32 => (irfft_32, "size-16"),
-    64 => (irfft_64, "size-32"),
-    128 => (irfft_128, "size-64"),
-    256 => (irfft_256, "size-128"),
-    512 => (irfft_512, "size-256"),
-    1024 => (irfft_1024, "size-512"),
-    2048 => (irfft_2048, "size-1024"),
-    4096 => (irfft_4096, "size-2048"),
-    8192 => (irfft_8192, "size-4096"),
-    16384 => (irfft_16384, "size-8192"),
-    32768 => (irfft_32768, "size-16384"),
+    2 => (irfft_2, irfft_2_f64, irfft_2_into, irfft_2_into_f64),
+    4 => (irfft_4, irfft_4_f64, irfft_4_into, irfft_4_into_f64),
+    8 => (irfft_8, irfft_8_f64, irfft_8_into, irfft_8_into_f64, "size-4"),
+    16 => (irfft_16, irfft_16_f64, irfft_16_into, irfft_16_into_f64, "size-8"),
+    32 => (irfft_32, irfft_32_f64, irfft_32_into, irfft_32_into_f64, "size-16"),
+    64 => (irfft_64, irfft_64_f64, irfft_64_into, irfft_64_into_f64, "size-32"),
+    128 => (irfft_128, irfft_128_f64, irfft_128_into, irfft_128_into_f64, "size-64"),
+    256 => (irfft_256, irfft_256_f64, irfft_256_into, irfft_256_into_f64, "size-128"),
+    512 => (irfft_512, irfft_512_f64, irfft_512_into, irfft_512_into_f64, "size-256"),
+    1024 => (irfft_1024, irfft_1024_f64, irfft_1024_into, irfft_1024_into_f64, "size-512"),
+    2048 => (irfft_2048, irfft_2048_f64, irfft_2048_into, irfft_2048_into_f64, "size-1024"),
+    4096 => (irfft_4096, irfft_4096_f64, irfft_4096_into, irfft_4096_into_f64, "size-2048"),
+    8192 => (irfft_8192, irfft_8192_f64, irfft_8192_into, irfft_8192_into_f64, "size-4096"),
+    16384 => (irfft_16384, irfft_16384_f64, irfft_16384_into, irfft_16384_into_f64, "size-8192"),
+    32768 => (irfft_32768, irfft_32768_f64, irfft_32768_into, irfft_32768_into_f64, "size-16384"),
+}
+
+irfft_impls! {
+    12 => (irfft_12, irfft_12_f64, irfft_12_into, irfft_12_into_f64, "size-12"),
+    24 => (irfft_24, irfft_24_f64, irfft_24_into, irfft_24_into_f64, "size-24"),
+    48 => (irfft_48, irfft_48_f64, irfft_48_into, irfft_48_into_f64, "size-48"),
+    96 => (irfft_96, irfft_96_f64, irfft_96_into, irfft_96_into_f64, "size-96"),
+    192 => (irfft_192, irfft_192_f64, irfft_192_into, irfft_192_into_f64, "size-192"),
+    384 => (irfft_384, irfft_384_f64, irfft_384_into, irfft_384_into_f64, "size-384"),
+    768 => (irfft_768, irfft_768_f64, irfft_768_into, irfft_768_into_f64, "size-768"),
+    20 => (irfft_20, irfft_20_f64, irfft_20_into, irfft_20_into_f64, "size-20"),
+    40 => (irfft_40, irfft_40_f64, irfft_40_into, irfft_40_into_f64, "size-40"),
+    80 => (irfft_80, irfft_80_f64, irfft_80_into, irfft_80_into_f64, "size-80"),
+    160 => (irfft_160, irfft_160_f64, irfft_160_into, irfft_160_into_f64, "size-160"),
+    320 => (irfft_320, irfft_320_f64, irfft_320_into, irfft_320_into_f64, "size-320"),
+    640 => (irfft_640, irfft_640_f64, irfft_640_into, irfft_640_into_f64, "size-640"),
+    1280 => (irfft_1280, irfft_1280_f64, irfft_1280_into, irfft_1280_into_f64, "size-1280"),
+    60 => (irfft_60, irfft_60_f64, irfft_60_into, irfft_60_into_f64, "size-60"),
+    120 => (irfft_120, irfft_120_f64, irfft_120_into, irfft_120_into_f64, "size-120"),
+    240 => (irfft_240, irfft_240_f64, irfft_240_into, irfft_240_into_f64, "size-240"),
+    480 => (irfft_480, irfft_480_f64, irfft_480_into, irfft_480_into_f64, "size-480"),
+    960 => (irfft_960, irfft_960_f64, irfft_960_into, irfft_960_into_f64, "size-960"),
+    1920 => (irfft_1920, irfft_1920_f64, irfft_1920_into, irfft_1920_into_f64, "size-1920"),
+    3840 => (irfft_3840, irfft_3840_f64, irfft_3840_into, irfft_3840_into_f64, "size-3840"),
+}
+
+fixed_irfft_impls! {
+    2 => (irfft_2_q15, irfft_2_q31),
+    4 => (irfft_4_q15, irfft_4_q31),
+    8 => (irfft_8_q15, irfft_8_q31, "size-4"),
+    16 => (irfft_16_q15, irfft_16_q31, "size-8"),
+    32 => (irfft_32_q15, irfft_32_q31, "size-16"),
+    64 => (irfft_64_q15, irfft_64_q31, "size-32"),
+    128 => (irfft_128_q15, irfft_128_q31, "size-64"),
+    256 => (irfft_256_q15, irfft_256_q31, "size-128"),
+    512 => (irfft_512_q15, irfft_512_q31, "size-256"),
+    1024 => (irfft_1024_q15, irfft_1024_q31, "size-512"),
+    2048 => (irfft_2048_q15, irfft_2048_q31, "size-1024"),
+    4096 => (irfft_4096_q15, irfft_4096_q31, "size-2048"),
+    8192 => (irfft_8192_q15, irfft_8192_q31, "size-4096"),
+    16384 => (irfft_16384_q15, irfft_16384_q31, "size-8192"),
+    32768 => (irfft_32768_q15, irfft_32768_q31, "size-16384"),
+}
+
+/// Perform an in-place `N`-point inverse RFFT.
+///
+/// This is the const-generic equivalent of the `irfft_N` functions. It
+/// can be used with any `N` for which [`Size<N>`](Size) implements
+/// [`RFftSize`].
+///
+/// Since `N` cannot be inferred from the input type, it has to be
+/// specified explicitly.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, inverse_real::irfft};
+///
+/// let mut input = [Complex32::default(); 8];
+/// let result = irfft::<16>(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn irfft<const N: usize>(input: &mut <Size<N> as RFftSize>::Half<Complex32>) -> &mut [f32; N]
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::irfft(input).try_into().unwrap()
+}
+
+/// Perform an in-place `N`-point inverse RFFT on double-precision values.
+///
+/// This is the const-generic equivalent of the `irfft_N_f64` functions. It
+/// can be used with any `N` for which [`Size<N>`](Size) implements
+/// [`RFftSize`].
+///
+/// Since `N` cannot be inferred from the input type, it has to be
+/// specified explicitly.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, inverse_real::irfft_f64};
+///
+/// let mut input = [Complex64::default(); 8];
+/// let result = irfft_f64::<16>(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn irfft_f64<const N: usize>(
+    input: &mut <Size<N> as RFftSize>::Half<Complex64>,
+) -> &mut [f64; N]
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::irfft(input).try_into().unwrap()
+}
+
+/// Perform an out-of-place `N`-point inverse RFFT.
+///
+/// Like [`irfft`], but leaves `input` unmodified and writes the result into
+/// `output` instead.
+///
+/// Since `N` cannot be inferred from the input type, it has to be
+/// specified explicitly.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, inverse_real::irfft_into};
+///
+/// let input = [Complex32::default(); 8];
+/// let mut output = [0.; 16];
+/// let result = irfft_into::<16>(&input, &mut output);
+/// ```
+#[inline]
+pub fn irfft_into<'a, const N: usize>(
+    input: &<Size<N> as RFftSize>::Half<Complex32>,
+    output: &'a mut [f32; N],
+) -> &'a mut [f32; N]
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::irfft_into(input, output);
+    output
+}
+
+/// Perform an out-of-place `N`-point inverse RFFT on double-precision values.
+///
+/// Like [`irfft_f64`], but leaves `input` unmodified and writes the result into
+/// `output` instead.
+///
+/// Since `N` cannot be inferred from the input type, it has to be
+/// specified explicitly.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, inverse_real::irfft_into_f64};
+///
+/// let input = [Complex64::default(); 8];
+/// let mut output = [0.; 16];
+/// let result = irfft_into_f64::<16>(&input, &mut output);
+/// ```
+#[inline]
+pub fn irfft_into_f64<'a, const N: usize>(
+    input: &<Size<N> as RFftSize>::Half<Complex64>,
+    output: &'a mut [f64; N],
+) -> &'a mut [f64; N]
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::irfft_into(input, output);
+    output
+}
+
+/// Perform an in-place `N`-point inverse RFFT, using the given normalization.
+///
+/// Like [`irfft`], but scales the result according to the inverse
+/// transform part of `normalization`.
+/// The scaling is performed as part of the recombination step of the
+/// algorithm and so does not require an additional pass over the data.
+///
+/// Since `N` cannot be inferred from the input type, it has to be
+/// specified explicitly.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, Normalization, inverse_real::irfft_normalized};
+///
+/// let mut input = [Complex32::default(); 8];
+/// let result = irfft_normalized::<16>(&mut input, Normalization::Orthonormal);
+/// ```
+#[inline]
+#[must_use]
+pub fn irfft_normalized<const N: usize>(
+    input: &mut <Size<N> as RFftSize>::Half<Complex32>,
+    normalization: Normalization,
+) -> &mut [f32; N]
+where
+    Size<N>: RFftSize,
+{
+    let output = if normalization == Normalization::Inverse {
+        Size::<N>::irfft(input)
+    } else {
+        Size::<N>::irfft_scaled(input, normalization.inverse_scale(N))
+    };
+    output.try_into().unwrap()
+}
+
+/// Perform an in-place `N`-point inverse RFFT on double-precision values, using the given normalization.
+///
+/// Like [`irfft_f64`], but scales the result according to the inverse
+/// transform part of `normalization`.
+/// The scaling is performed as part of the recombination step of the
+/// algorithm and so does not require an additional pass over the data.
+///
+/// Since `N` cannot be inferred from the input type, it has to be
+/// specified explicitly.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, Normalization, inverse_real::irfft_normalized_f64};
+///
+/// let mut input = [Complex64::default(); 8];
+/// let result = irfft_normalized_f64::<16>(&mut input, Normalization::Orthonormal);
+/// ```
+#[inline]
+#[must_use]
+pub fn irfft_normalized_f64<const N: usize>(
+    input: &mut <Size<N> as RFftSize>::Half<Complex64>,
+    normalization: Normalization,
+) -> &mut [f64; N]
+where
+    Size<N>: RFftSize,
+{
+    let output = if normalization == Normalization::Inverse {
+        Size::<N>::irfft(input)
+    } else {
+        Size::<N>::irfft_scaled(input, normalization.inverse_scale(N))
+    };
+    output.try_into().unwrap()
+}
+
+/// Perform an out-of-place `N`-point inverse RFFT on all `N/2 + 1` bins.
+///
+/// This is the inverse of [`rfft_full`](crate::real::rfft_full). Unlike
+/// [`irfft_into`], `input` holds the standard `N/2 + 1` non-negative
+/// frequency terms, with the real-valued coefficient at the Nyquist
+/// frequency in the last bin. The imaginary parts of the DC and Nyquist
+/// bins are ignored.
+///
+/// Since `N` cannot be inferred from the input type, it has to be
+/// specified explicitly.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, inverse_real::irfft_full};
+///
+/// let mut input = [Complex32::default(); 9];
+/// input[0] = Complex32::new(16., 0.);
+/// let mut output = [0.; 16];
+/// let result = irfft_full::<16>(&input, &mut output);
+/// assert_eq!(result, &[1.; 16]);
+/// ```
+#[inline]
+pub fn irfft_full<'a, const N: usize>(
+    input: &<Size<N> as RFftSize>::Full<Complex32>,
+    output: &'a mut [f32; N],
+) -> &'a mut [f32; N]
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::irfft_full(input, output);
+    output
+}
+
+/// Perform an out-of-place `N`-point inverse RFFT on all `N/2 + 1` double-precision bins.
+///
+/// This is the inverse of [`rfft_full_f64`](crate::real::rfft_full_f64).
+/// Unlike [`irfft_into_f64`], `input` holds the standard `N/2 + 1`
+/// non-negative frequency terms, with the real-valued coefficient at the
+/// Nyquist frequency in the last bin. The imaginary parts of the DC and
+/// Nyquist bins are ignored.
+///
+/// Since `N` cannot be inferred from the input type, it has to be
+/// specified explicitly.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, inverse_real::irfft_full_f64};
+///
+/// let mut input = [Complex64::default(); 9];
+/// input[0] = Complex64::new(16., 0.);
+/// let mut output = [0.; 16];
+/// let result = irfft_full_f64::<16>(&input, &mut output);
+/// assert_eq!(result, &[1.; 16]);
+/// ```
+#[inline]
+pub fn irfft_full_f64<'a, const N: usize>(
+    input: &<Size<N> as RFftSize>::Full<Complex64>,
+    output: &'a mut [f64; N],
+) -> &'a mut [f64; N]
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::irfft_full(input, output);
+    output
+}
+
+/// Perform a 2D inverse RFFT on the half-plane of `H` rows of `W/2 + 1`
+/// bins, producing `H` rows of `W` real values.
+///
+/// This is the inverse of [`rfft2d`](crate::real::rfft2d). The columns of
+/// `input` are inverse transformed in place first, so `input` is used as
+/// scratch space and does not hold the spectrum anymore afterwards. Like in
+/// `rfft2d`, they are transformed in blocks of 4 adjacent columns through a
+/// buffer of `4 * H` values on the stack, which takes up `32 * H` bytes. Then
+/// each row is inverse transformed into `output` with [`irfft_full`], so the
+/// Nyquist coefficients are expected in the last column. The imaginary parts
+/// that remain in the DC and Nyquist columns after the column pass are
+/// ignored, so only the Hermitian part of the input is recovered.
+///
+/// Since `W` cannot be inferred from the input type, it has to be
+/// specified explicitly.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, inverse_real::irfft2d};
+///
+/// let mut input = [[Complex32::default(); 17]; 16];
+/// input[0][0] = Complex32::new(512., 0.);
+/// let mut output = [[0.; 32]; 16];
+/// let result = irfft2d::<32, 16>(&mut input, &mut output);
+/// assert_eq!(result, &[[1.; 32]; 16]);
+/// ```
+#[inline]
+pub fn irfft2d<'a, const W: usize, const H: usize>(
+    input: &mut [<Size<W> as RFftSize>::Full<Complex32>; H],
+    output: &'a mut [[f32; W]; H],
+) -> &'a mut [[f32; W]; H]
+where
+    Size<W>: RFftSize,
+    Size<H>: CFftSize,
+{
+    let mut columns = [[Complex32::default(); H]; COLUMNS];
+    Size::<H>::ifft_columns(input, &mut columns);
+    for (x, y) in input.iter().zip(output.iter_mut()) {
+        Size::<W>::irfft_full(x, y);
+    }
+    output
+}
+
+/// Perform a 2D inverse RFFT on the half-plane of `H` rows of `W/2 + 1`
+/// double-precision bins, producing `H` rows of `W` real values.
+///
+/// This is the inverse of [`rfft2d_f64`](crate::real::rfft2d_f64). The
+/// columns of `input` are inverse transformed in place first, so `input` is
+/// used as scratch space and does not hold the spectrum anymore afterwards.
+/// Like in `rfft2d_f64`, they are transformed in blocks of 4 adjacent columns
+/// through a buffer of `4 * H` values on the stack, which takes up `64 * H`
+/// bytes. Then each row is inverse transformed into `output` with [`irfft_full_f64`], so the
+/// Nyquist coefficients are expected in the last column. The imaginary parts
+/// that remain in the DC and Nyquist columns after the column pass are
+/// ignored, so only the Hermitian part of the input is recovered.
+///
+/// Since `W` cannot be inferred from the input type, it has to be
+/// specified explicitly.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, inverse_real::irfft2d_f64};
+///
+/// let mut input = [[Complex64::default(); 17]; 16];
+/// input[0][0] = Complex64::new(512., 0.);
+/// let mut output = [[0.; 32]; 16];
+/// let result = irfft2d_f64::<32, 16>(&mut input, &mut output);
+/// assert_eq!(result, &[[1.; 32]; 16]);
+/// ```
+#[inline]
+pub fn irfft2d_f64<'a, const W: usize, const H: usize>(
+    input: &mut [<Size<W> as RFftSize>::Full<Complex64>; H],
+    output: &'a mut [[f64; W]; H],
+) -> &'a mut [[f64; W]; H]
+where
+    Size<W>: RFftSize,
+    Size<H>: CFftSize,
+{
+    let mut columns = [[Complex64::default(); H]; COLUMNS];
+    Size::<H>::ifft_columns(input, &mut columns);
+    for (x, y) in input.iter().zip(output.iter_mut()) {
+        Size::<W>::irfft_full(x, y);
+    }
+    output
+}
+
+/// Perform two `N`-point inverse RFFTs at the cost of one `N`-point inverse
+/// CFFT.
+///
+/// This is the inverse of [`rfft_pair`](crate::real::rfft_pair). `input`
+/// holds the packed spectrum of `a` in its first half and that of `b` in its
+/// second half. They are combined into the spectrum of a single complex
+/// signal in place, so `input` is used as scratch space and does not hold
+/// the spectra anymore afterwards.
+///
+/// It can be used with any `N` for which [`Size<N>`](Size) implements both
+/// [`CFftSize`] and [`RFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, inverse_real::irfft_pair};
+///
+/// let mut input = [Complex32::default(); 16];
+/// input[0] = Complex32::new(16., 0.);
+/// let (mut left, mut right) = ([0.; 16], [0.; 16]);
+/// irfft_pair(&mut input, &mut left, &mut right);
+/// assert_eq!(left, [1.; 16]);
+/// assert_eq!(right, [0.; 16]);
+/// ```
+#[inline]
+pub fn irfft_pair<const N: usize>(input: &mut [Complex32; N], a: &mut [f32; N], b: &mut [f32; N])
+where
+    Size<N>: CFftSize + RFftSize,
+{
+    Size::<N>::irfft_pair(input, a, b);
+}
+
+/// Perform two `N`-point inverse RFFTs on double-precision values at the
+/// cost of one `N`-point inverse CFFT.
+///
+/// This is the inverse of [`rfft_pair_f64`](crate::real::rfft_pair_f64).
+/// `input` holds the packed spectrum of `a` in its first half and that of
+/// `b` in its second half. They are combined into the spectrum of a single
+/// complex signal in place, so `input` is used as scratch space and does not
+/// hold the spectra anymore afterwards.
+///
+/// It can be used with any `N` for which [`Size<N>`](Size) implements both
+/// [`CFftSize`] and [`RFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, inverse_real::irfft_pair_f64};
+///
+/// let mut input = [Complex64::default(); 16];
+/// input[0] = Complex64::new(16., 0.);
+/// let (mut left, mut right) = ([0.; 16], [0.; 16]);
+/// irfft_pair_f64(&mut input, &mut left, &mut right);
+/// assert_eq!(left, [1.; 16]);
+/// assert_eq!(right, [0.; 16]);
+/// ```
+#[inline]
+pub fn irfft_pair_f64<const N: usize>(
+    input: &mut [Complex64; N],
+    a: &mut [f64; N],
+    b: &mut [f64; N],
+) where
+    Size<N>: CFftSize + RFftSize,
+{
+    Size::<N>::irfft_pair(input, a, b);
 }
@@ -16,6 +16,57 @@
 //!    an `N/2`-point IFFT, making it roughly twice as fast as the complex
 //!    variant.
 //!
+//! Each module provides functions for specific sizes, like
+//! `complex::cfft_16`, as well as a const-generic function, like
+//! `complex::cfft`, that works with any supported size. See the [`size`]
+//! module for details. For inputs whose size is only known at runtime, the
+//! [`slice`] module provides functions operating on slices.
+//! Two-dimensional transforms are provided by `complex::fft2d` and
+//! `inverse::ifft2d`, and by `real::rfft2d` and `inverse_real::irfft2d` for
+//! real inputs. The [`batch`] module transforms several channels of the same
+//! size in one call.
+//!
+//! Each of these is available for single-precision (`f32`/[`Complex32`]) and
+//! double-precision (`f64`/[`Complex64`]) values. The double-precision
+//! functions carry an `_f64` suffix, e.g. `cfft_16_f64`.
+//!
+//! Besides powers of two, sizes of the form `3 * 2^k`, `5 * 2^k` and
+//! `15 * 2^k`, with `k` from 2 to 8, are supported by combining radix-3 and
+//! radix-5 butterflies with the radix-2 algorithm. Each of these sizes must be
+//! enabled through its `size-*` feature. Other sizes with factors of 3 and 5,
+//! like 9, 45 or 360, are not supported.
+//!
+//! For transform lengths that are not supported by these, [`bluestein`]
+//! computes DFTs of arbitrary length using Bluestein's algorithm. Based on
+//! the same approach, [`czt`] provides the chirp-Z transform and a zoom FFT,
+//! which evaluate the DFT at arbitrary, evenly spaced frequencies.
+//!
+//! Built on top of the RFFT, [`dct`] provides in-place discrete cosine and
+//! sine transforms, and [`hartley`] the discrete Hartley transform. The
+//! [`mdct`] module provides the modified discrete cosine transform, together
+//! with windows and overlap-add helpers for time-domain aliasing
+//! cancellation.
+//!
+//! The [`window`] module provides the common window functions, to be applied
+//! to blocks of samples before transforming them, and the [`scaling`] module
+//! converts the resulting spectra into physical units. The [`welch`] module
+//! estimates power spectral densities of long or streaming signals, and the
+//! [`multitaper`] module of single blocks using Slepian tapers.
+//!
+//! If only a few bins are needed, the [`goertzel`] module computes them
+//! sample by sample, and the [`sliding`] module updates a whole spectrum
+//! with every new sample.
+//!
+//! For targets without a floating-point unit, all power-of-two transforms are
+//! also available for Q15 (`i16`/[`ComplexQ15`]) and Q31 (`i32`/[`ComplexQ31`])
+//! fixed-point values, with `_q15` and `_q31` suffixes respectively. These
+//! use block floating-point scaling to avoid overflows and return the block
+//! exponent of their result alongside it.
+//!
+//! The packed RFFT output can be wrapped in a [`RealSpectrum`], which provides
+//! access to the `N/2 + 1` logical bins without having to deal with the
+//! packing of the Nyquist coefficient.
+//!
 //! # Example
 //!
 //! ```
@@ -52,29 +103,90 @@
 //! }
 //! ```
 //!
+//! [`batch`]: batch/index.html
+//! [`bluestein`]: bluestein/index.html
 //! [`complex`]: complex/index.html
+//! [`czt`]: czt/index.html
+//! [`dct`]: dct/index.html
+//! [`goertzel`]: goertzel/index.html
+//! [`hartley`]: hartley/index.html
 //! [`inverse`]: inverse/index.html
 //! [`inverse_real`]: inverse_real/index.html
+//! [`mdct`]: mdct/index.html
+//! [`multitaper`]: multitaper/index.html
 //! [`real`]: real/index.html
+//! [`scaling`]: scaling/index.html
+//! [`size`]: size/index.html
+//! [`sliding`]: sliding/index.html
+//! [`slice`]: slice/index.html
+//! [`welch`]: welch/index.html
+//! [`window`]: window/index.html
 //! [`Complex32`]: type.Complex32.html
+//! [`Complex64`]: type.Complex64.html
+//! [`RealSpectrum`]: struct.RealSpectrum.html
+//! [`ComplexQ15`]: type.ComplexQ15.html
+//! [`ComplexQ31`]: type.ComplexQ31.html
 
 #![no_std]
 #![deny(missing_docs)]
 #![warn(rust_2018_idioms)]
 
+pub mod batch;
+pub mod bluestein;
 pub mod complex;
+pub mod czt;
+pub mod dct;
+pub mod goertzel;
+pub mod hartley;
 pub mod inverse;
 pub mod inverse_real;
+pub mod mdct;
+pub mod multitaper;
 pub mod real;
+pub mod scaling;
+pub mod size;
+pub mod sliding;
+pub mod slice;
+pub mod welch;
+pub mod window;
+
+pub use error::Error;
+pub use normalization::Normalization;
+pub use num_complex::{Complex32, Complex64};
+pub use spectrum::RealSpectrum;
 
-pub use num_complex::Complex32;
+/// A complex number in Q15 fixed-point format.
+pub type ComplexQ15 = num_complex::Complex<i16>;
+/// A complex number in Q31 fixed-point format.
+pub type ComplexQ31 = num_complex::Complex<i32>;
 
+mod error;
+mod fixed;
+mod float;
 mod impls {
+    pub(crate) mod batch;
+    pub(crate) mod bluestein;
     pub(crate) mod cfft;
+    pub(crate) mod czt;
+    pub(crate) mod dct;
+    pub(crate) mod dispatch;
+    #[cfg(feature = "std")]
+    pub(crate) mod dpss;
+    pub(crate) mod fft2d;
+    pub(crate) mod hartley;
     pub(crate) mod ifft;
     pub(crate) mod irfft;
+    pub(crate) mod mdct;
+    pub(crate) mod mixed;
+    pub(crate) mod pair;
+    pub(crate) mod permute;
+    pub(crate) mod qfft;
     pub(crate) mod rfft;
+    pub(crate) mod twiddle;
 }
+mod math;
+mod normalization;
+mod spectrum;
 mod tables;
 
 #[cfg(any(test, feature = "test-utils"))]
@@ -0,0 +1,239 @@
+//! Elementary functions for `no_std` environments.
+//!
+//! `core` does not provide trigonometric functions, so the few that are
+//! needed to compute coefficients at runtime are implemented here. They are
+//! accurate to a few ULP in double precision, which is sufficient for
+//! deriving single- and double-precision coefficients.
+
+use core::f32::consts as f32_consts;
+use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, LN_2, PI};
+
+// `PI / 2` split into a high part with trailing zero bits and a low part,
+// to allow for exact range reduction of moderately large arguments.
+const FRAC_PI_2_HI: f64 = 1.570_796_326_734_125_6;
+const FRAC_PI_2_LO: f64 = 6.077_100_506_506_192e-11;
+
+/// Compute the sine and cosine of `x` (in radians).
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    // reduce `x` to `r` in `[-PI/4, PI/4]`, with `x = r + q * PI/2`
+    let q = round(x * FRAC_2_PI);
+    let r = (x - q * FRAC_PI_2_HI) - q * FRAC_PI_2_LO;
+    let (sin, cos) = (sin_kernel(r), cos_kernel(r));
+
+    match (q as i64).rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// Round `x` to the nearest integer, rounding half-way cases away from zero.
+fn round(x: f64) -> f64 {
+    let offset = if x < 0. { -0.5 } else { 0.5 };
+    (x + offset) as i64 as f64
+}
+
+/// Taylor series of `sin(x)` for `x` in `[-PI/4, PI/4]`.
+fn sin_kernel(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = x;
+    for i in 1..10 {
+        let k = (2 * i) as f64;
+        term *= -x2 / (k * (k + 1.));
+        sum += term;
+    }
+    sum
+}
+
+/// Taylor series of `cos(x)` for `x` in `[-PI/4, PI/4]`.
+fn cos_kernel(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut term = 1.;
+    let mut sum = 1.;
+    for i in 1..10 {
+        let k = (2 * i) as f64;
+        term *= -x2 / ((k - 1.) * k);
+        sum += term;
+    }
+    sum
+}
+
+/// Compute the square root of `x`, which must not be negative.
+pub(crate) fn sqrt(x: f64) -> f64 {
+    if x == 0. {
+        return 0.;
+    }
+
+    // Halving the exponent gives an initial estimate within a factor of two,
+    // which Newton's method refines to full precision in a few iterations.
+    let mut y = f64::from_bits((x.to_bits() >> 1) + (1023 << 51));
+    for _ in 0..6 {
+        y = (y + x / y) / 2.;
+    }
+    y
+}
+
+/// Compute the square root of `x` in single precision, which must not be
+/// negative.
+pub(crate) fn sqrt_f32(x: f32) -> f32 {
+    if x == 0. {
+        return 0.;
+    }
+
+    let mut y = f32::from_bits((x.to_bits() >> 1) + (127 << 22));
+    for _ in 0..4 {
+        y = (y + x / y) / 2.;
+    }
+    y
+}
+
+/// Compute `e^x`.
+pub(crate) fn exp(x: f64) -> f64 {
+    if x < -746. {
+        return 0.;
+    }
+    if x > 710. {
+        return f64::INFINITY;
+    }
+
+    // reduce `x` to `r` in `[-ln(2)/2, ln(2)/2]`, with `x = r + k * ln(2)`
+    let k = round(x / LN_2);
+    let r = x - k * LN_2;
+
+    let mut term = 1.;
+    let mut sum = 1.;
+    for i in 1..18 {
+        term *= r / i as f64;
+        sum += term;
+    }
+
+    // Scale by `2^k` in two steps, since `2^k` itself may not be
+    // representable if the result is subnormal.
+    let k = k as i64;
+    sum * pow2i(k / 2) * pow2i(k - k / 2)
+}
+
+/// Compute `2^e` for `e` in `[-1022, 1023]`.
+fn pow2i(e: i64) -> f64 {
+    f64::from_bits(((e + 1023) as u64) << 52)
+}
+
+/// Compute the four-quadrant arctangent of `y / x` (in radians), in
+/// `[-PI, PI]`.
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    if x == 0. && y == 0. {
+        return 0.;
+    }
+
+    // reduce to the first octant, where `t = min/max` is in `[0, 1]`
+    let (ax, ay) = (abs(x), abs(y));
+    let t = if ay > ax { ax / ay } else { ay / ax };
+    let mut a = atan_kernel(t);
+    if ay > ax {
+        a = FRAC_PI_2 - a;
+    }
+    if x < 0. {
+        a = PI - a;
+    }
+    if y < 0. {
+        -a
+    } else {
+        a
+    }
+}
+
+/// Compute `atan(t)` for `t` in `[0, 1]`.
+fn atan_kernel(t: f64) -> f64 {
+    // Applying `atan(t) = 2 * atan(t / (1 + sqrt(1 + t^2)))` twice reduces
+    // the argument to `[0, tan(PI/16)]`, where the Taylor series converges
+    // quickly.
+    let mut t = t;
+    for _ in 0..2 {
+        t /= 1. + sqrt(1. + t * t);
+    }
+
+    let t2 = t * t;
+    let mut term = t;
+    let mut sum = t;
+    for i in 1..13 {
+        term *= -t2;
+        sum += term / (2 * i + 1) as f64;
+    }
+    sum * 4.
+}
+
+/// Compute the four-quadrant arctangent of `y / x` (in radians) in single
+/// precision, in `[-PI, PI]`.
+pub(crate) fn atan2_f32(y: f32, x: f32) -> f32 {
+    if x == 0. && y == 0. {
+        return 0.;
+    }
+
+    let (ax, ay) = (abs_f32(x), abs_f32(y));
+    let t = if ay > ax { ax / ay } else { ay / ax };
+    let mut a = atan_kernel_f32(t);
+    if ay > ax {
+        a = f32_consts::FRAC_PI_2 - a;
+    }
+    if x < 0. {
+        a = f32_consts::PI - a;
+    }
+    if y < 0. {
+        -a
+    } else {
+        a
+    }
+}
+
+/// Compute `atan(t)` for `t` in `[0, 1]` in single precision.
+fn atan_kernel_f32(t: f32) -> f32 {
+    // same reduction as `atan_kernel`, fewer terms suffice for `f32`
+    let mut t = t;
+    for _ in 0..2 {
+        t /= 1. + sqrt_f32(1. + t * t);
+    }
+
+    let t2 = t * t;
+    let mut term = t;
+    let mut sum = t;
+    for i in 1..6 {
+        term *= -t2;
+        sum += term / (2 * i + 1) as f32;
+    }
+    sum * 4.
+}
+
+/// Compute the absolute value of `x`.
+fn abs(x: f64) -> f64 {
+    if x < 0. {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Compute the absolute value of `x` in single precision.
+fn abs_f32(x: f32) -> f32 {
+    if x < 0. {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Compute the zeroth-order modified Bessel function of the first kind.
+pub(crate) fn bessel_i0(x: f64) -> f64 {
+    // I0(x) = sum_k ((x/2)^k / k!)^2
+    let y = x * x / 4.;
+    let mut term = 1.;
+    let mut sum = 1.;
+    let mut k = 1.;
+    while term > sum * 1e-17 {
+        term *= y / (k * k);
+        sum += term;
+        k += 1.;
+    }
+    sum
+}
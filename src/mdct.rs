@@ -0,0 +1,175 @@
+//! Modified discrete cosine transform (MDCT)
+//!
+//! The `N`-point MDCT maps a block of `N` input values to `N/2`
+//! coefficients:
+//!
+//! ```text
+//! X[k] = sum_n x[n] * cos(2*PI/N * (n + 1/2 + N/4) * (k + 1/2))
+//! ```
+//!
+//! The IMDCT maps `N/2` coefficients back to `N` values, which contain
+//! time-domain aliasing:
+//!
+//! ```text
+//! y[n] = 4/N * sum_k X[k] * cos(2*PI/N * (n + 1/2 + N/4) * (k + 1/2))
+//! ```
+//!
+//! The aliasing is cancelled by applying a window that satisfies the
+//! Princen-Bradley condition, like [`Window::Sine`] or [`Window::Kbd`], to the
+//! blocks before the MDCT and after the IMDCT, and overlap-adding successive
+//! blocks that overlap by `N/2` values with [`overlap_add`] (TDAC).
+//!
+//! The transforms are computed using an `N/4`-point CFFT, plus `O(N)` pre-
+//! and post-processing. They are available for power-of-two sizes, whose
+//! `N/4`-point CFFT is enabled.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::mdct;
+//! use microfft::window::{self, Window};
+//!
+//! let mut sine = [0.; 16];
+//! Window::Sine.fill(&mut sine);
+//!
+//! let signal: Vec<f32> = (0..64).map(|i| (i as f32 * 0.3).sin()).collect();
+//! let mut overlap = [0.; 8];
+//! let mut output = Vec::new();
+//!
+//! for start in (0..=48).step_by(8) {
+//!     let mut block = [0.; 16];
+//!     block.copy_from_slice(&signal[start..start + 16]);
+//!     window::apply(&mut block, &sine);
+//!
+//!     let mut coefficients = [0.; 8];
+//!     mdct::mdct_16(&block, &mut coefficients);
+//!
+//!     let block = mdct::imdct_16(&coefficients, &mut block);
+//!     window::apply(block, &sine);
+//!
+//!     let mut samples = [0.; 8];
+//!     mdct::overlap_add(block, &mut overlap, &mut samples);
+//!     output.extend_from_slice(&samples);
+//! }
+//!
+//! // The first output block lacks the overlap with a preceding block.
+//! for (x, y) in signal[8..56].iter().zip(&output[8..]) {
+//!     assert!((x - y).abs() < 1e-4);
+//! }
+//! ```
+//!
+//! [`Window::Sine`]: crate::window::Window::Sine
+//! [`Window::Kbd`]: crate::window::Window::Kbd
+
+use crate::float::Float;
+use crate::impls::mdct::*;
+
+macro_rules! mdct_impls {
+    (
+        $(
+            $N:expr => (
+                $mdct_N:ident,
+                $imdct_N:ident,
+                $mdct_N_f64:ident,
+                $imdct_N_f64:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
+        $(
+            mdct_impls!(@fn f32, $N, $mdct_N, $imdct_N, "" $(, $feature)?);
+            mdct_impls!(@fn f64, $N, $mdct_N_f64, $imdct_N_f64, " on double-precision values" $(, $feature)?);
+        )*
+    };
+    (@fn $T:ty, $N:expr, $mdct_N:ident, $imdct_N:ident, $suffix:expr $(, $feature:expr)?) => {
+        #[doc = concat!("Perform a ", stringify!($N), "-point MDCT", $suffix, ".")]
+        #[doc = ""]
+        #[doc = concat!("Writes the ", stringify!($N), " / 2 coefficients of `input` into `output`.")]
+        #[doc = ""]
+        #[doc = "# Example"]
+        #[doc = ""]
+        #[doc = "```"]
+        #[doc = concat!("use microfft::mdct::", stringify!($mdct_N), ";")]
+        #[doc = ""]
+        #[doc = concat!("let input = [0.; ", stringify!($N), "];")]
+        #[doc = concat!("let mut output = [0.; ", stringify!($N), " / 2];")]
+        #[doc = concat!("let result = ", stringify!($mdct_N), "(&input, &mut output);")]
+        #[doc = "```"]
+        $( #[cfg(feature = $feature)] )?
+        #[inline]
+        pub fn $mdct_N<'a>(
+            input: &[$T; $N],
+            output: &'a mut [$T; $N / 2],
+        ) -> &'a mut [$T; $N / 2] {
+            MdctN::<$N>::forward(input, output);
+            output
+        }
+
+        #[doc = concat!("Perform a ", stringify!($N), "-point IMDCT", $suffix, ".")]
+        #[doc = ""]
+        #[doc = concat!("Writes the ", stringify!($N), " time-domain values for the coefficients in `input` into `output`.")]
+        #[doc = ""]
+        #[doc = "# Example"]
+        #[doc = ""]
+        #[doc = "```"]
+        #[doc = concat!("use microfft::mdct::", stringify!($imdct_N), ";")]
+        #[doc = ""]
+        #[doc = concat!("let input = [0.; ", stringify!($N), " / 2];")]
+        #[doc = concat!("let mut output = [0.; ", stringify!($N), "];")]
+        #[doc = concat!("let result = ", stringify!($imdct_N), "(&input, &mut output);")]
+        #[doc = "```"]
+        $( #[cfg(feature = $feature)] )?
+        #[inline]
+        pub fn $imdct_N<'a>(input: &[$T; $N / 2], output: &'a mut [$T; $N]) -> &'a mut [$T; $N] {
+            MdctN::<$N>::inverse(input, output);
+            output
+        }
+    };
+}
+
+mdct_impls! {
+    4 => (mdct_4, imdct_4, mdct_4_f64, imdct_4_f64),
+    8 => (mdct_8, imdct_8, mdct_8_f64, imdct_8_f64),
+    16 => (mdct_16, imdct_16, mdct_16_f64, imdct_16_f64, "size-4"),
+    32 => (mdct_32, imdct_32, mdct_32_f64, imdct_32_f64, "size-8"),
+    64 => (mdct_64, imdct_64, mdct_64_f64, imdct_64_f64, "size-16"),
+    128 => (mdct_128, imdct_128, mdct_128_f64, imdct_128_f64, "size-32"),
+    256 => (mdct_256, imdct_256, mdct_256_f64, imdct_256_f64, "size-64"),
+    512 => (mdct_512, imdct_512, mdct_512_f64, imdct_512_f64, "size-128"),
+    1024 => (mdct_1024, imdct_1024, mdct_1024_f64, imdct_1024_f64, "size-256"),
+    2048 => (mdct_2048, imdct_2048, mdct_2048_f64, imdct_2048_f64, "size-512"),
+    4096 => (mdct_4096, imdct_4096, mdct_4096_f64, imdct_4096_f64, "size-1024"),
+    8192 => (mdct_8192, imdct_8192, mdct_8192_f64, imdct_8192_f64, "size-2048"),
+    16384 => (mdct_16384, imdct_16384, mdct_16384_f64, imdct_16384_f64, "size-4096"),
+    32768 => (mdct_32768, imdct_32768, mdct_32768_f64, imdct_32768_f64, "size-8192"),
+}
+
+/// Overlap-add an IMDCT output `block` of `N` values.
+///
+/// Writes the sum of the first half of `block` and the `N/2` values in
+/// `overlap`, which hold the second half of the previous block, into
+/// `output`. Then stores the second half of `block` in `overlap`, for the
+/// next call.
+///
+/// # Panics
+///
+/// Panics if `overlap` or `output` do not have half the length of `block`.
+#[inline]
+pub fn overlap_add<T: Float>(block: &[T], overlap: &mut [T], output: &mut [T]) {
+    let half = block.len() / 2;
+    assert_eq!(
+        overlap.len() * 2,
+        block.len(),
+        "overlap length must be half the block length"
+    );
+    assert_eq!(
+        output.len() * 2,
+        block.len(),
+        "output length must be half the block length"
+    );
+
+    for ((y, o), x) in output.iter_mut().zip(overlap.iter()).zip(&block[..half]) {
+        *y = *o + *x;
+    }
+    overlap.copy_from_slice(&block[half..]);
+}
@@ -0,0 +1,260 @@
+//! Multitaper power spectral density estimation
+//!
+//! The multitaper method estimates the power spectral density (PSD) of a
+//! block of `N` samples by multiplying it with `K` orthogonal tapers,
+//! computing the periodogram (eigenspectrum) of each product with an
+//! `N`-point RFFT, and combining them. Since the tapers are orthogonal, the
+//! eigenspectra are nearly uncorrelated, so combining them reduces the
+//! variance of the estimate by a factor of about `K`, while the bias stays as
+//! low as that of a single taper.
+//!
+//! The optimal tapers are the discrete prolate spheroidal sequences (DPSS,
+//! or Slepian sequences), which concentrate as much of their energy as
+//! possible within a frequency band of `[-W, W]`. They are defined by the
+//! time-bandwidth product `NW`, and about `2NW - 1` of them are well
+//! concentrated. With the `std` feature, `dpss` generates them. Targets
+//! without `std` can use precomputed tapers instead, since generating them is
+//! only needed once.
+//!
+//! A [`Multitaper`] estimator combines the eigenspectra either weighted by
+//! the concentrations of their tapers ([`psd`](Multitaper::psd)), or with
+//! Thomson's adaptive weights ([`adaptive_psd`](Multitaper::adaptive_psd)),
+//! which suppress the broadband leakage of the less concentrated tapers
+//! where the spectrum is small, e.g. next to strong peaks.
+//!
+//! The resulting one-sided PSD is scaled as described in the
+//! [`scaling`](crate::scaling) module, in squared units of the input per Hz.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::multitaper::{self, Multitaper};
+//!
+//! // 7 tapers with a time-bandwidth product of 4
+//! let mut tapers = [[0f32; 256]; 7];
+//! let concentrations = multitaper::dpss(4., &mut tapers);
+//! assert!(concentrations.iter().all(|&c| c > 0.9));
+//!
+//! // a 1 kHz tone with an amplitude of 1 V, sampled at 8 kHz
+//! let signal: [f32; 256] =
+//!     core::array::from_fn(|i| (2. * core::f32::consts::PI * 1000. * i as f32 / 8000.).sin());
+//!
+//! let estimator = Multitaper::new(&tapers, concentrations);
+//! let mut psd = [0.; 129];
+//! estimator.psd(&signal, 8000., &mut psd);
+//!
+//! // almost all of the power of 0.5 V^2 is contained in the bandwidth of
+//! // +-4 bins of 31.25 Hz around the tone at bin 32
+//! let power: f32 = psd[28..=36].iter().sum::<f32>() * 31.25;
+//! assert!((power - 0.5).abs() < 5e-3);
+//! ```
+
+use crate::float::Float;
+use crate::scaling;
+use crate::size::{RFftImpl, RFftSize, Size};
+
+/// Generate the `K` discrete prolate spheroidal sequences of length `N` with
+/// the time-bandwidth product `nw` into `tapers`, and return their
+/// concentrations.
+///
+/// The tapers are ordered by decreasing concentration, i.e. the fraction of
+/// their energy within the frequency band `[-nw/N, nw/N]` (in cycles per
+/// sample). They are normalized to unit energy. Symmetric tapers have a
+/// positive sum, and antisymmetric ones start with a positive lobe.
+///
+/// The sequences are computed in double precision, using an eigenvalue
+/// decomposition of a tridiagonal matrix. Computing the concentrations takes
+/// `O(K * N^2)` operations.
+///
+/// This function requires the `std` feature.
+///
+/// # Panics
+///
+/// Panics if `nw` is not in `(0, N/2)`, or if `K` is greater than `N`.
+#[cfg(feature = "std")]
+pub fn dpss<T: Float, const N: usize, const K: usize>(
+    nw: f64,
+    tapers: &mut [[T; N]; K],
+) -> [f64; K] {
+    assert!(
+        nw > 0. && nw < N as f64 / 2.,
+        "time-bandwidth product must be in (0, N/2)"
+    );
+    assert!(K <= N, "number of tapers must not exceed the taper length");
+
+    let sequences = crate::impls::dpss::dpss(N, nw, K);
+    let mut concentrations = [0.; K];
+    for ((taper, concentration), (v, c)) in tapers
+        .iter_mut()
+        .zip(concentrations.iter_mut())
+        .zip(sequences)
+    {
+        for (t, x) in taper.iter_mut().zip(v) {
+            *t = T::from_f64(x);
+        }
+        *concentration = c;
+    }
+    concentrations
+}
+
+/// A multitaper PSD estimator for blocks of `N` samples, using `K` tapers.
+#[derive(Clone, Copy, Debug)]
+pub struct Multitaper<'t, T, const N: usize, const K: usize> {
+    tapers: &'t [[T; N]; K],
+    concentrations: [f64; K],
+    energies: [f64; K],
+}
+
+impl<'t, T: Float, const N: usize, const K: usize> Multitaper<'t, T, N, K>
+where
+    Size<N>: RFftSize,
+{
+    /// Create an estimator using the `tapers`, with their spectral
+    /// `concentrations` as returned by `dpss`.
+    ///
+    /// The tapers do not have to be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is zero, or if a taper is zero.
+    pub fn new(tapers: &'t [[T; N]; K], concentrations: [f64; K]) -> Self {
+        assert!(K > 0, "at least one taper is required");
+
+        let energies = core::array::from_fn(|k| {
+            let energy = tapers[k].iter().map(|v| v.to_f64() * v.to_f64()).sum();
+            assert!(energy > 0., "tapers must not be zero");
+            energy
+        });
+        Self {
+            tapers,
+            concentrations,
+            energies,
+        }
+    }
+
+    /// The tapers of the estimator.
+    #[inline]
+    pub fn tapers(&self) -> &'t [[T; N]; K] {
+        self.tapers
+    }
+
+    /// The spectral concentrations of the tapers.
+    #[inline]
+    pub fn concentrations(&self) -> &[f64; K] {
+        &self.concentrations
+    }
+
+    /// Estimate the PSD of `signal` as the average of the eigenspectra,
+    /// weighted by the concentrations of their tapers, and write it into
+    /// `output`.
+    ///
+    /// This computes one `N`-point RFFT per taper.
+    pub fn psd<'a>(
+        &self,
+        signal: &[T; N],
+        sample_rate: T,
+        output: &'a mut <Size<N> as RFftSize>::Full<T>,
+    ) -> &'a mut <Size<N> as RFftSize>::Full<T> {
+        let values = output.as_mut();
+        values.fill(T::zero());
+
+        let total: f64 = self.concentrations.iter().sum();
+        for k in 0..K {
+            let weight = T::from_f64(self.concentrations[k] / total);
+            self.eigenspectrum(k, signal, |j, s| values[j] += weight * s);
+        }
+
+        scale::<T, N>(values, sample_rate);
+        output
+    }
+
+    /// Estimate the PSD of `signal` using Thomson's adaptive weighting, and
+    /// write it into `output`.
+    ///
+    /// The weights of the eigenspectra are determined per bin, such that the
+    /// expected broadband leakage of each taper, based on the mean square of
+    /// the signal and the concentration of the taper, is small compared to
+    /// the estimate. The eigenspectra are stored in `spectra`.
+    ///
+    /// This computes one `N`-point RFFT per taper.
+    pub fn adaptive_psd<'a>(
+        &self,
+        signal: &[T; N],
+        sample_rate: T,
+        spectra: &mut [<Size<N> as RFftSize>::Full<T>; K],
+        output: &'a mut <Size<N> as RFftSize>::Full<T>,
+    ) -> &'a mut <Size<N> as RFftSize>::Full<T> {
+        for (k, spectrum) in spectra.iter_mut().enumerate() {
+            let spectrum = spectrum.as_mut();
+            self.eigenspectrum(k, signal, |j, s| spectrum[j] = s);
+        }
+
+        // The eigenspectra of white noise with this mean square have the
+        // same expected value in all bins.
+        let variance = signal.iter().map(|x| x.to_f64() * x.to_f64()).sum::<f64>() / N as f64;
+
+        let values = output.as_mut();
+        for (j, value) in values.iter_mut().enumerate() {
+            let s: [f64; K] = core::array::from_fn(|k| spectra[k].as_ref()[j].to_f64());
+            *value = T::from_f64(self.adaptive_bin(&s, variance));
+        }
+
+        scale::<T, N>(values, sample_rate);
+        output
+    }
+
+    /// Combine the eigenspectra `s` of a single bin using adaptive weights.
+    fn adaptive_bin(&self, s: &[f64; K], variance: f64) -> f64 {
+        // start with the average of the two best concentrated tapers
+        let mut estimate = s[..K.min(2)].iter().sum::<f64>() / K.min(2) as f64;
+
+        for _ in 0..100 {
+            if estimate == 0. {
+                break;
+            }
+
+            let (mut num, mut den) = (0., 0.);
+            for (s_k, lambda) in s.iter().zip(&self.concentrations) {
+                let b = estimate / (lambda * estimate + (1. - lambda) * variance);
+                let weight = lambda * b * b;
+                num += weight * s_k;
+                den += weight;
+            }
+
+            let next = num / den;
+            let change = if next > estimate {
+                next - estimate
+            } else {
+                estimate - next
+            };
+            let converged = change <= 1e-10 * next;
+            estimate = next;
+            if converged {
+                break;
+            }
+        }
+        estimate
+    }
+
+    /// Compute the eigenspectrum of `signal` for taper `k`, normalized to
+    /// unit taper energy, and pass its `N/2 + 1` bins to `f`.
+    fn eigenspectrum(&self, k: usize, signal: &[T; N], mut f: impl FnMut(usize, T)) {
+        let taper = &self.tapers[k];
+        let mut x: [T; N] = core::array::from_fn(|n| signal[n] * taper[n]);
+        let spectrum = Size::<N>::rfft(&mut x);
+        let spectrum = spectrum.as_ref();
+
+        let norm = T::from_f64(1. / self.energies[k]);
+        for (j, p) in scaling::power(spectrum).enumerate() {
+            f(j, p * norm);
+        }
+    }
+}
+
+/// Scale the `N/2 + 1` eigenspectrum `values` to a one-sided PSD.
+fn scale<T: Float, const N: usize>(values: &mut [T], sample_rate: T) {
+    let factor = T::one() / sample_rate;
+    for (j, value) in values.iter_mut().enumerate() {
+        *value *= scaling::one_sided_scale(j, N, factor);
+    }
+}
@@ -0,0 +1,59 @@
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::math;
+
+/// Normalization conventions for pairs of forward and inverse transforms.
+///
+/// An unnormalized forward transform followed by an unnormalized inverse
+/// transform scales the input by `N`. The conventions differ in how this
+/// factor is compensated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Normalization {
+    /// Neither transform is scaled.
+    None,
+    /// The inverse transform is scaled by `1/N`.
+    ///
+    /// This is the convention used by all transforms without a normalization
+    /// parameter.
+    #[default]
+    Inverse,
+    /// The forward transform is scaled by `1/N`.
+    Forward,
+    /// Both transforms are scaled by `1/sqrt(N)`.
+    Orthonormal,
+}
+
+impl Normalization {
+    /// The factor to scale the result of an `n`-point forward transform with,
+    /// or `None` if it should not be scaled.
+    #[inline]
+    pub(crate) fn forward_scale<T: Float>(self, n: usize) -> Option<T> {
+        match self {
+            Self::None | Self::Inverse => None,
+            Self::Forward => Some(T::from_f64(1. / n as f64)),
+            Self::Orthonormal => Some(T::from_f64(1. / math::sqrt(n as f64))),
+        }
+    }
+
+    /// The factor to scale the result of an `n`-point inverse transform with,
+    /// or `None` if it should not be scaled.
+    #[inline]
+    pub(crate) fn inverse_scale<T: Float>(self, n: usize) -> Option<T> {
+        match self {
+            Self::None | Self::Forward => None,
+            Self::Inverse => Some(T::from_f64(1. / n as f64)),
+            Self::Orthonormal => Some(T::from_f64(1. / math::sqrt(n as f64))),
+        }
+    }
+}
+
+/// Multiply all values in `x` by `factor`, if given.
+#[inline]
+pub(crate) fn scale<T: Float>(x: &mut [Complex<T>], factor: Option<T>) {
+    if let Some(factor) = factor {
+        for c in x {
+            *c = c.scale(factor);
+        }
+    }
+}
@@ -12,14 +12,31 @@
 //! of the DC bin. The negative-frequency terms
 //! are not computed, since they can be calculated from the
 //! positive-frequency terms and are therefore redundant.
+//!
+//! If the standard `N/2 + 1` bins are needed instead, [`rfft_full`] writes
+//! them into a separate output array, with the Nyquist coefficient in the
+//! last bin.
 
 use core::convert::TryInto;
 
+use crate::impls::fft2d::COLUMNS;
+use crate::impls::qfft::*;
 use crate::impls::rfft::*;
-use crate::Complex32;
+use crate::size::{CFftImpl, CFftSize, RFftImpl, RFftSize, Size};
+use crate::{Complex32, Complex64, ComplexQ15, ComplexQ31, Normalization};
 
 macro_rules! rfft_impls {
-    ( $( $N:expr => ($rfft_N:ident $(, $feature:expr)?), )* ) => {
+    (
+        $(
+            $N:expr => (
+                $rfft_N:ident,
+                $rfft_N_f64:ident,
+                $rfft_N_into:ident,
+                $rfft_N_into_f64:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
         $(
             #[doc = concat!("Perform an in-place ", stringify!($N), "-point RFFT.")]
             #[doc = ""]
@@ -37,24 +54,570 @@ macro_rules! rfft_impls {
             pub fn $rfft_N(input: &mut [f32; $N]) -> &mut [Complex32; $N / 2] {
                 RFftN::<$N>::transform(input).try_into().unwrap()
             }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point RFFT on double-precision values.")]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::real::", stringify!($rfft_N_f64), ";")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [0.; ", stringify!($N), "];")]
+            #[doc = concat!("let result = ", stringify!($rfft_N_f64), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $rfft_N_f64(input: &mut [f64; $N]) -> &mut [Complex64; $N / 2] {
+                RFftN::<$N>::transform(input).try_into().unwrap()
+            }
+
+            #[doc = concat!("Perform an out-of-place ", stringify!($N), "-point RFFT.")]
+            #[doc = ""]
+            #[doc = concat!("Like [`", stringify!($rfft_N), "`], but leaves `input` unmodified and writes the result into")]
+            #[doc = "`output` instead."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{Complex32, real::", stringify!($rfft_N_into), "};")]
+            #[doc = ""]
+            #[doc = concat!("let input = [0.; ", stringify!($N), "];")]
+            #[doc = concat!("let mut output = [Complex32::default(); ", stringify!($N), " / 2];")]
+            #[doc = concat!("let result = ", stringify!($rfft_N_into), "(&input, &mut output);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            pub fn $rfft_N_into<'a>(
+                input: &[f32; $N],
+                output: &'a mut [Complex32; $N / 2],
+            ) -> &'a mut [Complex32; $N / 2] {
+                RFftN::<$N>::transform_into(input, output);
+                output
+            }
+
+            #[doc = concat!("Perform an out-of-place ", stringify!($N), "-point RFFT on double-precision values.")]
+            #[doc = ""]
+            #[doc = concat!("Like [`", stringify!($rfft_N_f64), "`], but leaves `input` unmodified and writes the result into")]
+            #[doc = "`output` instead."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::{Complex64, real::", stringify!($rfft_N_into_f64), "};")]
+            #[doc = ""]
+            #[doc = concat!("let input = [0.; ", stringify!($N), "];")]
+            #[doc = concat!("let mut output = [Complex64::default(); ", stringify!($N), " / 2];")]
+            #[doc = concat!("let result = ", stringify!($rfft_N_into_f64), "(&input, &mut output);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            pub fn $rfft_N_into_f64<'a>(
+                input: &[f64; $N],
+                output: &'a mut [Complex64; $N / 2],
+            ) -> &'a mut [Complex64; $N / 2] {
+                RFftN::<$N>::transform_into(input, output);
+                output
+            }
         )*
     };
 }
 
+macro_rules! fixed_rfft_impls {
+    (
+        $(
+            $N:expr => (
+                $rfft_N_q15:ident,
+                $rfft_N_q31:ident
+                $(, $feature:expr)?
+            ),
+        )*
+    ) => {
+        $(
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point RFFT on Q15 fixed-point values.")]
+            #[doc = ""]
+            #[doc = "Values are scaled down as needed during the computation to avoid overflows."]
+            #[doc = "Returns the result together with its block exponent `e`, meaning the actual"]
+            #[doc = "transform values are the returned values multiplied by `2^e`."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::real::", stringify!($rfft_N_q15), ";")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [0; ", stringify!($N), "];")]
+            #[doc = concat!("let (result, exponent) = ", stringify!($rfft_N_q15), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $rfft_N_q15(input: &mut [i16; $N]) -> (&mut [ComplexQ15; $N / 2], i32) {
+                let (output, exponent) = QFftN::<$N>::rfft(input);
+                (output.try_into().unwrap(), exponent)
+            }
+
+            #[doc = concat!("Perform an in-place ", stringify!($N), "-point RFFT on Q31 fixed-point values.")]
+            #[doc = ""]
+            #[doc = "Values are scaled down as needed during the computation to avoid overflows."]
+            #[doc = "Returns the result together with its block exponent `e`, meaning the actual"]
+            #[doc = "transform values are the returned values multiplied by `2^e`."]
+            #[doc = ""]
+            #[doc = "# Example"]
+            #[doc = ""]
+            #[doc = "```"]
+            #[doc = concat!("use microfft::real::", stringify!($rfft_N_q31), ";")]
+            #[doc = ""]
+            #[doc = concat!("let mut input = [0; ", stringify!($N), "];")]
+            #[doc = concat!("let (result, exponent) = ", stringify!($rfft_N_q31), "(&mut input);")]
+            #[doc = "```"]
+            $( #[cfg(feature = $feature)] )?
+            #[inline]
+            #[must_use]
+            pub fn $rfft_N_q31(input: &mut [i32; $N]) -> (&mut [ComplexQ31; $N / 2], i32) {
+                let (output, exponent) = QFftN::<$N>::rfft(input);
+                (output.try_into().unwrap(), exponent)
+            }
+        )*
+    };
+}
+
+rfft_impls! {
+    2 => (rfft_2, rfft_2_f64, rfft_2_into, rfft_2_into_f64),
+    4 => (rfft_4, rfft_4_f64, rfft_4_into, rfft_4_into_f64),
+    8 => (rfft_8, rfft_8_f64, rfft_8_into, rfft_8_into_f64, "size-4"),
+    16 => (rfft_16, rfft_16_f64, rfft_16_into, rfft_16_into_f64, "size-8"),
+    32 => (rfft_32, rfft_32_f64, rfft_32_into, rfft_32_into_f64, "size-16"),
+    64 => (rfft_64, rfft_64_f64, rfft_64_into, rfft_64_into_f64, "size-32"),
+    128 => (rfft_128, rfft_128_f64, rfft_128_into, rfft_128_into_f64, "size-64"),
+    256 => (rfft_256, rfft_256_f64, rfft_256_into, rfft_256_into_f64, "size-128"),
+    512 => (rfft_512, rfft_512_f64, rfft_512_into, rfft_512_into_f64, "size-256"),
+    1024 => (rfft_1024, rfft_1024_f64, rfft_1024_into, rfft_1024_into_f64, "size-512"),
+    2048 => (rfft_2048, rfft_2048_f64, rfft_2048_into, rfft_2048_into_f64, "size-1024"),
+    4096 => (rfft_4096, rfft_4096_f64, rfft_4096_into, rfft_4096_into_f64, "size-2048"),
+    8192 => (rfft_8192, rfft_8192_f64, rfft_8192_into, rfft_8192_into_f64, "size-4096"),
+    16384 => (rfft_16384, rfft_16384_f64, rfft_16384_into, rfft_16384_into_f64, "size-8192"),
+    32768 => (rfft_32768, rfft_32768_f64, rfft_32768_into, rfft_32768_into_f64, "size-16384"),
+}
+
 rfft_impls! {
-    2 => (rfft_2),
-    4 => (rfft_4),
-    8 => (rfft_8, "size-4"),
-    16 => (rfft_16, "size-8"),
-    32 => (rfft_32, "size-16"),
-    64 => (rfft_64, "size-32"),
-    128 => (rfft_128, "size-64"),
-    256 => (rfft_256, "size-128"),
-    512 => (rfft_512, "size-256"),
-    1024 => (rfft_1024, "size-512"),
-    2048 => (rfft_2048, "size-1024"),
-    4096 => (rfft_4096, "size-2048"),
-    8192 => (rfft_8192, "size-4096"),
-    16384 => (rfft_16384, "size-8192"),
-    32768 => (rfft_32768, "size-16384"),
+    12 => (rfft_12, rfft_12_f64, rfft_12_into, rfft_12_into_f64, "size-12"),
+    24 => (rfft_24, rfft_24_f64, rfft_24_into, rfft_24_into_f64, "size-24"),
+    48 => (rfft_48, rfft_48_f64, rfft_48_into, rfft_48_into_f64, "size-48"),
+    96 => (rfft_96, rfft_96_f64, rfft_96_into, rfft_96_into_f64, "size-96"),
+    192 => (rfft_192, rfft_192_f64, rfft_192_into, rfft_192_into_f64, "size-192"),
+    384 => (rfft_384, rfft_384_f64, rfft_384_into, rfft_384_into_f64, "size-384"),
+    768 => (rfft_768, rfft_768_f64, rfft_768_into, rfft_768_into_f64, "size-768"),
+    20 => (rfft_20, rfft_20_f64, rfft_20_into, rfft_20_into_f64, "size-20"),
+    40 => (rfft_40, rfft_40_f64, rfft_40_into, rfft_40_into_f64, "size-40"),
+    80 => (rfft_80, rfft_80_f64, rfft_80_into, rfft_80_into_f64, "size-80"),
+    160 => (rfft_160, rfft_160_f64, rfft_160_into, rfft_160_into_f64, "size-160"),
+    320 => (rfft_320, rfft_320_f64, rfft_320_into, rfft_320_into_f64, "size-320"),
+    640 => (rfft_640, rfft_640_f64, rfft_640_into, rfft_640_into_f64, "size-640"),
+    1280 => (rfft_1280, rfft_1280_f64, rfft_1280_into, rfft_1280_into_f64, "size-1280"),
+    60 => (rfft_60, rfft_60_f64, rfft_60_into, rfft_60_into_f64, "size-60"),
+    120 => (rfft_120, rfft_120_f64, rfft_120_into, rfft_120_into_f64, "size-120"),
+    240 => (rfft_240, rfft_240_f64, rfft_240_into, rfft_240_into_f64, "size-240"),
+    480 => (rfft_480, rfft_480_f64, rfft_480_into, rfft_480_into_f64, "size-480"),
+    960 => (rfft_960, rfft_960_f64, rfft_960_into, rfft_960_into_f64, "size-960"),
+    1920 => (rfft_1920, rfft_1920_f64, rfft_1920_into, rfft_1920_into_f64, "size-1920"),
+    3840 => (rfft_3840, rfft_3840_f64, rfft_3840_into, rfft_3840_into_f64, "size-3840"),
+}
+
+fixed_rfft_impls! {
+    2 => (rfft_2_q15, rfft_2_q31),
+    4 => (rfft_4_q15, rfft_4_q31),
+    8 => (rfft_8_q15, rfft_8_q31, "size-4"),
+    16 => (rfft_16_q15, rfft_16_q31, "size-8"),
+    32 => (rfft_32_q15, rfft_32_q31, "size-16"),
+    64 => (rfft_64_q15, rfft_64_q31, "size-32"),
+    128 => (rfft_128_q15, rfft_128_q31, "size-64"),
+    256 => (rfft_256_q15, rfft_256_q31, "size-128"),
+    512 => (rfft_512_q15, rfft_512_q31, "size-256"),
+    1024 => (rfft_1024_q15, rfft_1024_q31, "size-512"),
+    2048 => (rfft_2048_q15, rfft_2048_q31, "size-1024"),
+    4096 => (rfft_4096_q15, rfft_4096_q31, "size-2048"),
+    8192 => (rfft_8192_q15, rfft_8192_q31, "size-4096"),
+    16384 => (rfft_16384_q15, rfft_16384_q31, "size-8192"),
+    32768 => (rfft_32768_q15, rfft_32768_q31, "size-16384"),
+}
+
+/// Perform an in-place `N`-point RFFT.
+///
+/// This is the const-generic equivalent of the `rfft_N` functions. It
+/// can be used with any `N` for which [`Size<N>`](Size) implements
+/// [`RFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft;
+///
+/// let mut input = [0.; 16];
+/// let result = rfft(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfft<const N: usize>(input: &mut [f32; N]) -> &mut <Size<N> as RFftSize>::Half<Complex32>
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft(input)
+}
+
+/// Perform an in-place `N`-point RFFT on double-precision values.
+///
+/// This is the const-generic equivalent of the `rfft_N_f64` functions. It
+/// can be used with any `N` for which [`Size<N>`](Size) implements
+/// [`RFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_f64;
+///
+/// let mut input = [0.; 16];
+/// let result = rfft_f64(&mut input);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfft_f64<const N: usize>(input: &mut [f64; N]) -> &mut <Size<N> as RFftSize>::Half<Complex64>
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft(input)
+}
+
+/// Perform an out-of-place `N`-point RFFT.
+///
+/// Like [`rfft`], but leaves `input` unmodified and writes the result into
+/// `output` instead.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, real::rfft_into};
+///
+/// let input = [0.; 16];
+/// let mut output = [Complex32::default(); 8];
+/// let result = rfft_into(&input, &mut output);
+/// ```
+#[inline]
+pub fn rfft_into<'a, const N: usize>(
+    input: &[f32; N],
+    output: &'a mut <Size<N> as RFftSize>::Half<Complex32>,
+) -> &'a mut <Size<N> as RFftSize>::Half<Complex32>
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft_into(input, output);
+    output
+}
+
+/// Perform an out-of-place `N`-point RFFT on double-precision values.
+///
+/// Like [`rfft_f64`], but leaves `input` unmodified and writes the result into
+/// `output` instead.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, real::rfft_into_f64};
+///
+/// let input = [0.; 16];
+/// let mut output = [Complex64::default(); 8];
+/// let result = rfft_into_f64(&input, &mut output);
+/// ```
+#[inline]
+pub fn rfft_into_f64<'a, const N: usize>(
+    input: &[f64; N],
+    output: &'a mut <Size<N> as RFftSize>::Half<Complex64>,
+) -> &'a mut <Size<N> as RFftSize>::Half<Complex64>
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft_into(input, output);
+    output
+}
+
+/// Perform an in-place `N`-point RFFT, using the given normalization.
+///
+/// Like [`rfft`], but scales the result according to the forward
+/// transform part of `normalization`.
+/// The scaling is performed as part of the recombination step of the
+/// algorithm and so does not require an additional pass over the data.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Normalization, real::rfft_normalized};
+///
+/// let mut input = [0.; 16];
+/// let result = rfft_normalized(&mut input, Normalization::Orthonormal);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfft_normalized<const N: usize>(
+    input: &mut [f32; N],
+    normalization: Normalization,
+) -> &mut <Size<N> as RFftSize>::Half<Complex32>
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft_scaled(input, normalization.forward_scale(N))
+}
+
+/// Perform an in-place `N`-point RFFT on double-precision values, using the given normalization.
+///
+/// Like [`rfft_f64`], but scales the result according to the forward
+/// transform part of `normalization`.
+/// The scaling is performed as part of the recombination step of the
+/// algorithm and so does not require an additional pass over the data.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Normalization, real::rfft_normalized_f64};
+///
+/// let mut input = [0.; 16];
+/// let result = rfft_normalized_f64(&mut input, Normalization::Orthonormal);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfft_normalized_f64<const N: usize>(
+    input: &mut [f64; N],
+    normalization: Normalization,
+) -> &mut <Size<N> as RFftSize>::Half<Complex64>
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft_scaled(input, normalization.forward_scale(N))
+}
+
+/// Perform an out-of-place `N`-point RFFT, producing all `N/2 + 1` bins.
+///
+/// Unlike [`rfft_into`], the real-valued coefficient at the Nyquist
+/// frequency is not packed into the imaginary part of the DC bin. Instead,
+/// `output` receives the standard `N/2 + 1` non-negative frequency terms, as
+/// produced by other FFT libraries. The imaginary parts of the DC and
+/// Nyquist bins are always zero.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, real::rfft_full};
+///
+/// let input = [1.; 16];
+/// let mut output = [Complex32::default(); 9];
+/// let result = rfft_full(&input, &mut output);
+/// assert_eq!(result[0], Complex32::new(16., 0.));
+/// assert_eq!(result[8], Complex32::new(0., 0.));
+/// ```
+#[inline]
+pub fn rfft_full<'a, const N: usize>(
+    input: &[f32; N],
+    output: &'a mut <Size<N> as RFftSize>::Full<Complex32>,
+) -> &'a mut <Size<N> as RFftSize>::Full<Complex32>
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft_full(input, output);
+    output
+}
+
+/// Perform an out-of-place `N`-point RFFT on double-precision values, producing all `N/2 + 1` bins.
+///
+/// Unlike [`rfft_into_f64`], the real-valued coefficient at the Nyquist
+/// frequency is not packed into the imaginary part of the DC bin. Instead,
+/// `output` receives the standard `N/2 + 1` non-negative frequency terms, as
+/// produced by other FFT libraries. The imaginary parts of the DC and
+/// Nyquist bins are always zero.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, real::rfft_full_f64};
+///
+/// let input = [1.; 16];
+/// let mut output = [Complex64::default(); 9];
+/// let result = rfft_full_f64(&input, &mut output);
+/// assert_eq!(result[0], Complex64::new(16., 0.));
+/// assert_eq!(result[8], Complex64::new(0., 0.));
+/// ```
+#[inline]
+pub fn rfft_full_f64<'a, const N: usize>(
+    input: &[f64; N],
+    output: &'a mut <Size<N> as RFftSize>::Full<Complex64>,
+) -> &'a mut <Size<N> as RFftSize>::Full<Complex64>
+where
+    Size<N>: RFftSize,
+{
+    Size::<N>::rfft_full(input, output);
+    output
+}
+
+/// Perform an out-of-place 2D RFFT on `H` rows of `W` values each,
+/// producing the half-plane of `H` rows of `W/2 + 1` bins.
+///
+/// The rows are transformed with [`rfft_full`], so the real-valued Nyquist
+/// coefficient of each row ends up in the last column, rather than packed
+/// into the DC bin. The `W/2 + 1` columns are then transformed with a CFFT,
+/// in blocks of 4 adjacent columns through a buffer of `4 * H` values on the
+/// stack, which takes up `32 * H` bytes.
+///
+/// The result is the left half of the output of the corresponding 2D CFFT,
+/// including the DC and Nyquist columns. The remaining columns follow from
+/// the Hermitian symmetry `X[y][x] = conj(X[(H - y) % H][W - x])`.
+///
+/// It can be used with any `W` and `H` for which [`Size<W>`](Size)
+/// implements [`RFftSize`] and [`Size<H>`](Size) implements
+/// [`CFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, real::rfft2d};
+///
+/// let input = [[1.; 32]; 16];
+/// let mut output = [[Complex32::default(); 17]; 16];
+/// let result = rfft2d(&input, &mut output);
+/// assert_eq!(result[0][0], Complex32::new(512., 0.));
+/// ```
+#[inline]
+pub fn rfft2d<'a, const W: usize, const H: usize>(
+    input: &[[f32; W]; H],
+    output: &'a mut [<Size<W> as RFftSize>::Full<Complex32>; H],
+) -> &'a mut [<Size<W> as RFftSize>::Full<Complex32>; H]
+where
+    Size<W>: RFftSize,
+    Size<H>: CFftSize,
+{
+    for (x, y) in input.iter().zip(output.iter_mut()) {
+        Size::<W>::rfft_full(x, y);
+    }
+    let mut columns = [[Complex32::default(); H]; COLUMNS];
+    Size::<H>::cfft_columns(output, &mut columns);
+    output
+}
+
+/// Perform an out-of-place 2D RFFT on `H` rows of `W` double-precision
+/// values each, producing the half-plane of `H` rows of `W/2 + 1` bins.
+///
+/// The rows are transformed with [`rfft_full_f64`], so the real-valued
+/// Nyquist coefficient of each row ends up in the last column, rather than
+/// packed into the DC bin. The `W/2 + 1` columns are then transformed with
+/// a CFFT, in blocks of 4 adjacent columns through a buffer of `4 * H` values
+/// on the stack, which takes up `64 * H` bytes.
+///
+/// The result is the left half of the output of the corresponding 2D CFFT,
+/// including the DC and Nyquist columns. The remaining columns follow from
+/// the Hermitian symmetry `X[y][x] = conj(X[(H - y) % H][W - x])`.
+///
+/// It can be used with any `W` and `H` for which [`Size<W>`](Size)
+/// implements [`RFftSize`] and [`Size<H>`](Size) implements
+/// [`CFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, real::rfft2d_f64};
+///
+/// let input = [[1.; 32]; 16];
+/// let mut output = [[Complex64::default(); 17]; 16];
+/// let result = rfft2d_f64(&input, &mut output);
+/// assert_eq!(result[0][0], Complex64::new(512., 0.));
+/// ```
+#[inline]
+pub fn rfft2d_f64<'a, const W: usize, const H: usize>(
+    input: &[[f64; W]; H],
+    output: &'a mut [<Size<W> as RFftSize>::Full<Complex64>; H],
+) -> &'a mut [<Size<W> as RFftSize>::Full<Complex64>; H]
+where
+    Size<W>: RFftSize,
+    Size<H>: CFftSize,
+{
+    for (x, y) in input.iter().zip(output.iter_mut()) {
+        Size::<W>::rfft_full(x, y);
+    }
+    let mut columns = [[Complex64::default(); H]; COLUMNS];
+    Size::<H>::cfft_columns(output, &mut columns);
+    output
+}
+
+/// Perform two `N`-point RFFTs at the cost of one `N`-point CFFT.
+///
+/// The real inputs `a` and `b` are combined into the real and imaginary
+/// parts of a single complex input, whose CFFT is computed into `output`
+/// and then separated into the two spectra, using their Hermitian symmetry.
+/// The first half of `output` receives the packed RFFT of `a`, the second
+/// half that of `b`. Both are returned, and are equal to the results of
+/// [`rfft`], up to rounding.
+///
+/// It can be used with any `N` for which [`Size<N>`](Size) implements both
+/// [`CFftSize`] and [`RFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, real::rfft_pair};
+///
+/// let left = [1.; 16];
+/// let right = [0.; 16];
+/// let mut output = [Complex32::default(); 16];
+/// let (left, right) = rfft_pair(&left, &right, &mut output);
+/// assert_eq!(left[0], Complex32::new(16., 0.));
+/// assert_eq!(right[0], Complex32::new(0., 0.));
+/// ```
+#[inline]
+pub fn rfft_pair<'a, const N: usize>(
+    a: &[f32; N],
+    b: &[f32; N],
+    output: &'a mut [Complex32; N],
+) -> (
+    &'a mut <Size<N> as RFftSize>::Half<Complex32>,
+    &'a mut <Size<N> as RFftSize>::Half<Complex32>,
+)
+where
+    Size<N>: CFftSize + RFftSize,
+{
+    Size::<N>::rfft_pair(a, b, output)
+}
+
+/// Perform two `N`-point RFFTs on double-precision values at the cost of one
+/// `N`-point CFFT.
+///
+/// The real inputs `a` and `b` are combined into the real and imaginary
+/// parts of a single complex input, whose CFFT is computed into `output`
+/// and then separated into the two spectra, using their Hermitian symmetry.
+/// The first half of `output` receives the packed RFFT of `a`, the second
+/// half that of `b`. Both are returned, and are equal to the results of
+/// [`rfft_f64`], up to rounding.
+///
+/// It can be used with any `N` for which [`Size<N>`](Size) implements both
+/// [`CFftSize`] and [`RFftSize`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex64, real::rfft_pair_f64};
+///
+/// let left = [1.; 16];
+/// let right = [0.; 16];
+/// let mut output = [Complex64::default(); 16];
+/// let (left, right) = rfft_pair_f64(&left, &right, &mut output);
+/// assert_eq!(left[0], Complex64::new(16., 0.));
+/// assert_eq!(right[0], Complex64::new(0., 0.));
+/// ```
+#[inline]
+pub fn rfft_pair_f64<'a, const N: usize>(
+    a: &[f64; N],
+    b: &[f64; N],
+    output: &'a mut [Complex64; N],
+) -> (
+    &'a mut <Size<N> as RFftSize>::Half<Complex64>,
+    &'a mut <Size<N> as RFftSize>::Half<Complex64>,
+)
+where
+    Size<N>: CFftSize + RFftSize,
+{
+    Size::<N>::rfft_pair(a, b, output)
 }
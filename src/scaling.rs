@@ -0,0 +1,227 @@
+//! Calibrated spectrum scaling
+//!
+//! The raw bins of an `N`-point RFFT scale with `N` and with the window that
+//! was applied to the samples. To express them in physical units, they have
+//! to be corrected for these, and the one-sided spectrum has to be doubled
+//! for all bins except DC and Nyquist, whose energy is not split between
+//! positive and negative frequencies. [`WindowScaling`] does this, given the
+//! sums of the window coefficients:
+//!
+//! ```text
+//! S1 = sum_n w[n]
+//! S2 = sum_n w[n]^2
+//! ```
+//!
+//! It provides three calibrations, in units of the input `U` (e.g. volts):
+//!
+//!  * The power spectrum (`U^2`), whose bins hold the mean square, i.e. the
+//!    squared RMS value, of the tone at the bin frequency:
+//!    `P[k] = 2 * |X[k]|^2 / S1^2`.
+//!  * The amplitude spectrum (`U`), holding the RMS values of the tones:
+//!    `A[k] = sqrt(P[k])`. A sine of amplitude `a` results in `a / sqrt(2)`,
+//!    a constant value `c` in `c` at DC.
+//!  * The power spectral density (`U^2/Hz`), for broadband signals like
+//!    noise: `PSD[k] = 2 * |X[k]|^2 / (S2 * sample_rate)`. Summing it over all
+//!    bins and multiplying by the bin width `sample_rate / N` gives the mean
+//!    square of the signal.
+//!
+//! The DC and Nyquist bins, which are packed together into the first value of
+//! the RFFT output, are both unpacked and scaled without the doubling, so all
+//! methods yield `N/2 + 1` values.
+//!
+//! Tones are only measured accurately if they are centered on a bin, or if a
+//! window with a flat main lobe, like [`Window::FlatTop`], is used.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::real::rfft_64;
+//! use microfft::scaling::WindowScaling;
+//! use microfft::window::{self, Window};
+//!
+//! // a sine of amplitude 2 at bin 5, i.e. with an RMS value of sqrt(2)
+//! let mut samples: [f32; 64] =
+//!     core::array::from_fn(|i| 2. * (2. * core::f32::consts::PI * 5. * i as f32 / 64.).sin());
+//!
+//! let mut hann = [0.; 64];
+//! Window::Hann.fill(&mut hann);
+//! window::apply(&mut samples, &hann);
+//! let spectrum = rfft_64(&mut samples);
+//!
+//! let scaling = WindowScaling::new(Window::Hann, 64);
+//! let amplitudes: Vec<f32> = scaling.amplitude_spectrum(spectrum).collect();
+//! assert_eq!(amplitudes.len(), 33);
+//! assert!((amplitudes[5] - 2f32.sqrt()).abs() < 1e-5);
+//! ```
+//!
+//! [`Window::FlatTop`]: crate::window::Window::FlatTop
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::window::Window;
+
+/// Scale factors for the RFFT output of windowed blocks of samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowScaling {
+    len: usize,
+    sum: f64,
+    sum_sq: f64,
+}
+
+impl WindowScaling {
+    /// Compute the scale factors for the periodic `window` of length `len`,
+    /// as computed by [`Window::fill`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is zero, or if the window is [`Window::Kbd`] and `len`
+    /// is odd.
+    pub fn new(window: Window, len: usize) -> Self {
+        assert!(len > 0, "window length must not be zero");
+
+        let (mut sum, mut sum_sq) = (0., 0.);
+        window.for_each_coefficient(len, len, |_, w: f64| {
+            sum += w;
+            sum_sq += w * w;
+        });
+        Self { len, sum, sum_sq }
+    }
+
+    /// Compute the scale factors for arbitrary window `coefficients`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `coefficients` is empty or sums to zero.
+    pub fn from_coefficients<T: Float>(coefficients: &[T]) -> Self {
+        assert!(!coefficients.is_empty(), "window length must not be zero");
+
+        let (mut sum, mut sum_sq) = (0., 0.);
+        for w in coefficients {
+            let w = w.to_f64();
+            sum += w;
+            sum_sq += w * w;
+        }
+        assert!(sum != 0., "window coefficients must not sum to zero");
+        Self {
+            len: coefficients.len(),
+            sum,
+            sum_sq,
+        }
+    }
+
+    /// The window length, which is the size `N` of the RFFT.
+    #[inline]
+    pub fn fft_len(&self) -> usize {
+        self.len
+    }
+
+    /// The coherent gain `S1 / N` of the window, i.e. its mean value.
+    #[inline]
+    pub fn coherent_gain(&self) -> f64 {
+        self.sum / self.len as f64
+    }
+
+    /// The equivalent noise bandwidth `N * S2 / S1^2` of the window, in bins.
+    #[inline]
+    pub fn enbw(&self) -> f64 {
+        self.len as f64 * self.sum_sq / (self.sum * self.sum)
+    }
+
+    /// The factor converting the squared magnitude of a single-sided bin
+    /// into power spectral density, without the doubling.
+    #[inline]
+    pub(crate) fn density_scale(&self, sample_rate: f64) -> f64 {
+        1. / (self.sum_sq * sample_rate)
+    }
+
+    /// Iterate over the power spectrum of the packed RFFT output `spectrum`,
+    /// in squared units of the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `spectrum` is not the output of an RFFT of the window
+    /// length.
+    #[inline]
+    pub fn power_spectrum<'a, T: Float, const M: usize>(
+        &self,
+        spectrum: &'a [Complex<T>; M],
+    ) -> impl ExactSizeIterator<Item = T> + 'a {
+        self.scaled(spectrum, 1. / (self.sum * self.sum))
+    }
+
+    /// Iterate over the amplitude spectrum of the packed RFFT output
+    /// `spectrum`, holding RMS values in units of the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `spectrum` is not the output of an RFFT of the window
+    /// length.
+    #[inline]
+    pub fn amplitude_spectrum<'a, T: Float, const M: usize>(
+        &self,
+        spectrum: &'a [Complex<T>; M],
+    ) -> impl ExactSizeIterator<Item = T> + 'a {
+        self.power_spectrum(spectrum).map(T::sqrt)
+    }
+
+    /// Iterate over the power spectral density of the packed RFFT output
+    /// `spectrum`, in squared units of the input per Hz.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `spectrum` is not the output of an RFFT of the window
+    /// length.
+    #[inline]
+    pub fn power_spectral_density<'a, T: Float, const M: usize>(
+        &self,
+        spectrum: &'a [Complex<T>; M],
+        sample_rate: T,
+    ) -> impl ExactSizeIterator<Item = T> + 'a {
+        self.scaled(spectrum, self.density_scale(sample_rate.to_f64()))
+    }
+
+    /// Iterate over the squared magnitudes of the `N/2 + 1` bins of
+    /// `spectrum`, multiplied by `scale`, and doubled for all bins except DC
+    /// and Nyquist.
+    #[inline]
+    fn scaled<'a, T: Float, const M: usize>(
+        &self,
+        spectrum: &'a [Complex<T>; M],
+        scale: f64,
+    ) -> impl ExactSizeIterator<Item = T> + 'a {
+        assert_eq!(
+            M * 2,
+            self.len,
+            "spectrum length must be half the window length"
+        );
+
+        let scale = T::from_f64(scale);
+        power(spectrum)
+            .enumerate()
+            .map(move |(k, p)| p * one_sided_scale(k, M * 2, scale))
+    }
+}
+
+/// Iterate over the squared magnitudes of the `N/2 + 1` bins of the packed
+/// RFFT output `spectrum`, with the DC and Nyquist values unpacked.
+#[inline]
+pub(crate) fn power<T: Float>(spectrum: &[Complex<T>]) -> impl ExactSizeIterator<Item = T> + '_ {
+    let m = spectrum.len();
+    (0..m + 1).map(move |k| match k {
+        0 => spectrum[0].re * spectrum[0].re,
+        k if k == m => spectrum[0].im * spectrum[0].im,
+        k => spectrum[k].norm_sqr(),
+    })
+}
+
+/// The scale of bin `k` of the one-sided spectrum of an `n`-point RFFT:
+/// `scale`, doubled for all bins except DC and Nyquist.
+#[inline]
+pub(crate) fn one_sided_scale<T: Float>(k: usize, n: usize, scale: T) -> T {
+    if k == 0 || 2 * k == n {
+        scale
+    } else {
+        T::TWO * scale
+    }
+}
@@ -0,0 +1,410 @@
+//! Marker traits for supported FFT sizes
+//!
+//! Besides the functions for specific sizes, like `complex::cfft_16`, each
+//! module also provides a const-generic function, like `complex::cfft`,
+//! that can be used with any supported size `N`. Which sizes are supported
+//! depends on the enabled `size-*` features, and is expressed by the traits
+//! in this module being implemented for [`Size<N>`](Size).
+//!
+//! This allows writing code that is generic over the FFT size:
+//!
+//! ```
+//! use microfft::size::{CFftSize, Size};
+//! use microfft::{complex, Complex32};
+//!
+//! fn spectrum_peak<const N: usize>(input: &mut [Complex32; N]) -> usize
+//! where
+//!     Size<N>: CFftSize,
+//! {
+//!     let spectrum = complex::cfft(input);
+//!     let mut peak = 0;
+//!     for (i, c) in spectrum.iter().enumerate() {
+//!         if c.norm_sqr() > spectrum[peak].norm_sqr() {
+//!             peak = i;
+//!         }
+//!     }
+//!     peak
+//! }
+//!
+//! let mut input = [Complex32::default(); 16];
+//! input[0] = Complex32::new(1., 0.);
+//! assert_eq!(spectrum_peak(&mut input), 0);
+//! ```
+//!
+//! The traits are sealed, so they cannot be implemented outside of this
+//! crate.
+
+use core::convert::TryInto;
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::batch::{self, CFftBatch};
+use crate::impls::cfft::*;
+use crate::impls::fft2d::transform_columns;
+use crate::impls::ifft::*;
+use crate::impls::irfft::*;
+use crate::impls::pair;
+use crate::impls::rfft::*;
+
+/// A marker type for the FFT size `N`.
+pub struct Size<const N: usize>;
+
+/// Implemented for [`Size<N>`](Size) if `N`-point CFFTs and IFFTs are
+/// supported.
+pub trait CFftSize: sealed::CFftImpl {}
+
+/// Implemented for [`Size<N>`](Size) if `N`-point RFFTs and inverse RFFTs are
+/// supported.
+pub trait RFftSize: sealed::RFftImpl {
+    /// An array of `N / 2` elements of type `E`.
+    type Half<E>: AsRef<[E]>;
+
+    /// An array of `N / 2 + 1` elements of type `E`.
+    type Full<E>: AsRef<[E]> + AsMut<[E]>;
+}
+
+pub(crate) use sealed::{CFftImpl, RFftImpl};
+
+/// The implementations behind the public traits, which are not nameable
+/// outside of this crate.
+mod sealed {
+    use num_complex::Complex;
+
+    use super::RFftSize;
+    use crate::float::Float;
+
+    pub trait CFftImpl {
+        fn cfft<T: Float>(x: &mut [Complex<T>]);
+
+        fn ifft<T: Float>(x: &mut [Complex<T>]);
+
+        fn ifft_unnormalized<T: Float>(x: &mut [Complex<T>]);
+
+        fn cfft_into<T: Float>(x: &[Complex<T>], y: &mut [Complex<T>]);
+
+        fn ifft_into<T: Float>(x: &[Complex<T>], y: &mut [Complex<T>]);
+
+        /// Transform the columns of the `N` rows in `x`, in blocks of
+        /// adjacent columns through the buffers `columns` of `N` values each.
+        fn cfft_columns<T: Float, R: AsMut<[Complex<T>]>, C: AsMut<[Complex<T>]>>(
+            x: &mut [R],
+            columns: &mut [C],
+        );
+
+        /// Like `cfft_columns`, but for the normalized inverse transform.
+        fn ifft_columns<T: Float, R: AsMut<[Complex<T>]>, C: AsMut<[Complex<T>]>>(
+            x: &mut [R],
+            columns: &mut [C],
+        );
+
+        fn cfft_planar<T: Float>(x: &mut [Complex<T>]);
+
+        fn twiddle<T: Float>(k: usize) -> Complex<T>;
+
+        fn cfft_interleaved<T: Float>(x: &mut [Complex<T>]);
+    }
+
+    pub trait RFftImpl {
+        fn rfft<T: Float>(x: &mut [T]) -> &mut Self::Half<Complex<T>>
+        where
+            Self: RFftSize;
+
+        fn irfft<T: Float>(x: &mut Self::Half<Complex<T>>) -> &mut [T]
+        where
+            Self: RFftSize;
+
+        fn rfft_scaled<T: Float>(x: &mut [T], scale: Option<T>) -> &mut Self::Half<Complex<T>>
+        where
+            Self: RFftSize;
+
+        fn irfft_scaled<T: Float>(x: &mut Self::Half<Complex<T>>, scale: Option<T>) -> &mut [T]
+        where
+            Self: RFftSize;
+
+        fn rfft_into<T: Float>(x: &[T], y: &mut Self::Half<Complex<T>>)
+        where
+            Self: RFftSize;
+
+        fn irfft_into<T: Float>(x: &Self::Half<Complex<T>>, y: &mut [T])
+        where
+            Self: RFftSize;
+
+        fn rfft_full<T: Float>(x: &[T], y: &mut Self::Full<Complex<T>>)
+        where
+            Self: RFftSize;
+
+        fn irfft_full<T: Float>(x: &Self::Full<Complex<T>>, y: &mut [T])
+        where
+            Self: RFftSize;
+
+        fn rfft_planar<T: Float, const C: usize>(x: &mut [T]) -> &mut [Self::Half<Complex<T>>; C]
+        where
+            Self: RFftSize;
+
+        fn rfft_interleaved<T: Float, const C: usize>(
+            x: &mut [T],
+        ) -> &mut Self::Half<[Complex<T>; C]>
+        where
+            Self: RFftSize;
+
+        #[allow(clippy::type_complexity)]
+        fn rfft_pair<'a, T: Float>(
+            a: &[T],
+            b: &[T],
+            y: &'a mut [Complex<T>],
+        ) -> (
+            &'a mut Self::Half<Complex<T>>,
+            &'a mut Self::Half<Complex<T>>,
+        )
+        where
+            Self: RFftSize;
+
+        fn irfft_pair<T: Float>(x: &mut [Complex<T>], a: &mut [T], b: &mut [T]);
+    }
+}
+
+macro_rules! cfft_sizes {
+    ( $( $N:expr $(, $feature:expr)? ; )* ) => {
+        $(
+            $( #[cfg(feature = $feature)] )?
+            impl CFftSize for Size<$N> {}
+
+            $( #[cfg(feature = $feature)] )?
+            impl sealed::CFftImpl for Size<$N> {
+                #[inline]
+                fn cfft<T: Float>(x: &mut [Complex<T>]) {
+                    CFftN::<$N>::transform(x);
+                }
+
+                #[inline]
+                fn ifft<T: Float>(x: &mut [Complex<T>]) {
+                    IFftN::<$N>::transform(x);
+                }
+
+                #[inline]
+                fn ifft_unnormalized<T: Float>(x: &mut [Complex<T>]) {
+                    IFftN::<$N>::transform_unnormalized(x);
+                }
+
+                #[inline]
+                fn cfft_into<T: Float>(x: &[Complex<T>], y: &mut [Complex<T>]) {
+                    CFftN::<$N>::transform_into(x, y);
+                }
+
+                #[inline]
+                fn ifft_into<T: Float>(x: &[Complex<T>], y: &mut [Complex<T>]) {
+                    IFftN::<$N>::transform_into(x, y);
+                }
+
+                #[inline]
+                fn cfft_columns<T: Float, R: AsMut<[Complex<T>]>, C: AsMut<[Complex<T>]>>(
+                    x: &mut [R],
+                    columns: &mut [C],
+                ) {
+                    transform_columns::<T, CFftN<$N>, R, C>(x, columns, false);
+                }
+
+                #[inline]
+                fn ifft_columns<T: Float, R: AsMut<[Complex<T>]>, C: AsMut<[Complex<T>]>>(
+                    x: &mut [R],
+                    columns: &mut [C],
+                ) {
+                    transform_columns::<T, CFftN<$N>, R, C>(x, columns, true);
+                }
+
+                #[inline]
+                fn cfft_planar<T: Float>(x: &mut [Complex<T>]) {
+                    CFftN::<$N>::planar(x);
+                }
+
+                #[inline]
+                fn twiddle<T: Float>(k: usize) -> Complex<T> {
+                    <CFftN<$N> as CFft<T>>::twiddle(k)
+                }
+
+                #[inline]
+                fn cfft_interleaved<T: Float>(x: &mut [Complex<T>]) {
+                    CFftN::<$N>::interleaved(x);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! rfft_sizes {
+    ( $( $N:expr $(, $feature:expr)? ; )* ) => {
+        $(
+            $( #[cfg(feature = $feature)] )?
+            impl RFftSize for Size<$N> {
+                type Half<E> = [E; $N / 2];
+                type Full<E> = [E; $N / 2 + 1];
+            }
+
+            $( #[cfg(feature = $feature)] )?
+            impl sealed::RFftImpl for Size<$N> {
+                #[inline]
+                fn rfft<T: Float>(x: &mut [T]) -> &mut [Complex<T>; $N / 2] {
+                    RFftN::<$N>::transform(x).try_into().unwrap()
+                }
+
+                #[inline]
+                fn irfft<T: Float>(x: &mut [Complex<T>; $N / 2]) -> &mut [T] {
+                    IRFftN::<$N>::transform(x)
+                }
+
+                #[inline]
+                fn rfft_scaled<T: Float>(
+                    x: &mut [T],
+                    scale: Option<T>,
+                ) -> &mut [Complex<T>; $N / 2] {
+                    RFftN::<$N>::transform_scaled(x, scale).try_into().unwrap()
+                }
+
+                #[inline]
+                fn irfft_scaled<T: Float>(
+                    x: &mut [Complex<T>; $N / 2],
+                    scale: Option<T>,
+                ) -> &mut [T] {
+                    IRFftN::<$N>::transform_scaled(x, scale)
+                }
+
+                #[inline]
+                fn rfft_into<T: Float>(x: &[T], y: &mut [Complex<T>; $N / 2]) {
+                    RFftN::<$N>::transform_into(x, y);
+                }
+
+                #[inline]
+                fn irfft_into<T: Float>(x: &[Complex<T>; $N / 2], y: &mut [T]) {
+                    IRFftN::<$N>::transform_into(x, y);
+                }
+
+                #[inline]
+                fn rfft_full<T: Float>(x: &[T], y: &mut [Complex<T>; $N / 2 + 1]) {
+                    RFftN::<$N>::transform_full(x, y);
+                }
+
+                #[inline]
+                fn irfft_full<T: Float>(x: &[Complex<T>; $N / 2 + 1], y: &mut [T]) {
+                    IRFftN::<$N>::transform_full(x, y);
+                }
+
+                #[inline]
+                fn rfft_planar<T: Float, const C: usize>(
+                    x: &mut [T],
+                ) -> &mut [[Complex<T>; $N / 2]; C] {
+                    let y = batch::rfft_planar::<T, RFftN<$N>>(x);
+                    batch::unflatten(y)
+                }
+
+                #[inline]
+                fn rfft_interleaved<T: Float, const C: usize>(
+                    x: &mut [T],
+                ) -> &mut [[Complex<T>; C]; $N / 2] {
+                    let y = batch::rfft_interleaved::<T, RFftN<$N>>(x);
+                    batch::unflatten(y)
+                }
+
+                #[inline]
+                fn rfft_pair<'a, T: Float>(
+                    a: &[T],
+                    b: &[T],
+                    y: &'a mut [Complex<T>],
+                ) -> (&'a mut [Complex<T>; $N / 2], &'a mut [Complex<T>; $N / 2]) {
+                    pair::forward::<T, CFftN<$N>>(a, b, y);
+                    let (y_a, y_b) = y.split_at_mut($N / 2);
+                    (y_a.try_into().unwrap(), y_b.try_into().unwrap())
+                }
+
+                #[inline]
+                fn irfft_pair<T: Float>(x: &mut [Complex<T>], a: &mut [T], b: &mut [T]) {
+                    pair::inverse::<T, CFftN<$N>>(x, a, b);
+                }
+            }
+        )*
+    };
+}
+
+cfft_sizes! {
+    2;
+    4, "size-4";
+    8, "size-8";
+    16, "size-16";
+    32, "size-32";
+    64, "size-64";
+    128, "size-128";
+    256, "size-256";
+    512, "size-512";
+    1024, "size-1024";
+    2048, "size-2048";
+    4096, "size-4096";
+    8192, "size-8192";
+    16384, "size-16384";
+    32768, "size-32768";
+}
+
+rfft_sizes! {
+    2;
+    4;
+    8, "size-4";
+    16, "size-8";
+    32, "size-16";
+    64, "size-32";
+    128, "size-64";
+    256, "size-128";
+    512, "size-256";
+    1024, "size-512";
+    2048, "size-1024";
+    4096, "size-2048";
+    8192, "size-4096";
+    16384, "size-8192";
+    32768, "size-16384";
+}
+
+cfft_sizes! {
+    12, "size-12";
+    24, "size-24";
+    48, "size-48";
+    96, "size-96";
+    192, "size-192";
+    384, "size-384";
+    768, "size-768";
+    20, "size-20";
+    40, "size-40";
+    80, "size-80";
+    160, "size-160";
+    320, "size-320";
+    640, "size-640";
+    1280, "size-1280";
+    60, "size-60";
+    120, "size-120";
+    240, "size-240";
+    480, "size-480";
+    960, "size-960";
+    1920, "size-1920";
+    3840, "size-3840";
+}
+
+rfft_sizes! {
+    12, "size-12";
+    24, "size-24";
+    48, "size-48";
+    96, "size-96";
+    192, "size-192";
+    384, "size-384";
+    768, "size-768";
+    20, "size-20";
+    40, "size-40";
+    80, "size-80";
+    160, "size-160";
+    320, "size-320";
+    640, "size-640";
+    1280, "size-1280";
+    60, "size-60";
+    120, "size-120";
+    240, "size-240";
+    480, "size-480";
+    960, "size-960";
+    1920, "size-1920";
+    3840, "size-3840";
+}
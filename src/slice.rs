@@ -0,0 +1,128 @@
+//! FFTs on runtime-sized inputs
+//!
+//! The functions in this module accept slices instead of arrays and dispatch
+//! to the FFT implementation matching the slice length at runtime. They can
+//! be used when the FFT size is only known at runtime, at the cost of
+//! including the code for all enabled FFT sizes in the final binary.
+//!
+//! If no FFT is available for the length of the input, an [`Error`] is
+//! returned instead and the input is left unmodified. Supported lengths are
+//! powers of two, and `3 * 2^k`, `5 * 2^k` and `15 * 2^k` with `k` from 2 to
+//! 8, each subject to its `size-*` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{slice, Complex32, Error};
+//!
+//! let mut input = vec![Complex32::default(); 16];
+//! let result = slice::cfft(&mut input).unwrap();
+//!
+//! let mut input = vec![Complex32::default(); 17];
+//! assert_eq!(slice::cfft(&mut input), Err(Error::UnsupportedSize));
+//! ```
+
+use crate::impls::dispatch;
+use crate::{Complex32, Complex64, Error};
+
+/// Perform an in-place CFFT.
+///
+/// # Errors
+///
+/// Returns an error if no CFFT is available for the length of `input`.
+#[inline]
+pub fn cfft(input: &mut [Complex32]) -> Result<&mut [Complex32], Error> {
+    dispatch::cfft(input)?;
+    Ok(input)
+}
+
+/// Perform an in-place CFFT on double-precision values.
+///
+/// # Errors
+///
+/// Returns an error if no CFFT is available for the length of `input`.
+#[inline]
+pub fn cfft_f64(input: &mut [Complex64]) -> Result<&mut [Complex64], Error> {
+    dispatch::cfft(input)?;
+    Ok(input)
+}
+
+/// Perform an in-place IFFT.
+///
+/// # Errors
+///
+/// Returns an error if no IFFT is available for the length of `input`.
+#[inline]
+pub fn ifft(input: &mut [Complex32]) -> Result<&mut [Complex32], Error> {
+    dispatch::ifft(input)?;
+    Ok(input)
+}
+
+/// Perform an in-place IFFT on double-precision values.
+///
+/// # Errors
+///
+/// Returns an error if no IFFT is available for the length of `input`.
+#[inline]
+pub fn ifft_f64(input: &mut [Complex64]) -> Result<&mut [Complex64], Error> {
+    dispatch::ifft(input)?;
+    Ok(input)
+}
+
+/// Perform an in-place RFFT.
+///
+/// For `N` input values, the `N/2` resulting complex values are returned,
+/// with the Nyquist frequency coefficient packed into the imaginary part of
+/// the DC bin, like for the functions in the [`real`](crate::real) module.
+///
+/// # Errors
+///
+/// Returns an error if no RFFT is available for the length of `input`.
+#[inline]
+pub fn rfft(input: &mut [f32]) -> Result<&mut [Complex32], Error> {
+    dispatch::rfft(input)
+}
+
+/// Perform an in-place RFFT on double-precision values.
+///
+/// For `N` input values, the `N/2` resulting complex values are returned,
+/// with the Nyquist frequency coefficient packed into the imaginary part of
+/// the DC bin, like for the functions in the [`real`](crate::real) module.
+///
+/// # Errors
+///
+/// Returns an error if no RFFT is available for the length of `input`.
+#[inline]
+pub fn rfft_f64(input: &mut [f64]) -> Result<&mut [Complex64], Error> {
+    dispatch::rfft(input)
+}
+
+/// Perform an in-place inverse RFFT.
+///
+/// Takes `N/2` complex frequency-domain samples and produces `N` real
+/// time-domain samples. The Nyquist frequency coefficient should be stored
+/// in the imaginary part of the DC bin.
+///
+/// # Errors
+///
+/// Returns an error if no inverse RFFT is available for twice the length of
+/// `input`.
+#[inline]
+pub fn irfft(input: &mut [Complex32]) -> Result<&mut [f32], Error> {
+    dispatch::irfft(input)
+}
+
+/// Perform an in-place inverse RFFT on double-precision values.
+///
+/// Takes `N/2` complex frequency-domain samples and produces `N` real
+/// time-domain samples. The Nyquist frequency coefficient should be stored
+/// in the imaginary part of the DC bin.
+///
+/// # Errors
+///
+/// Returns an error if no inverse RFFT is available for twice the length of
+/// `input`.
+#[inline]
+pub fn irfft_f64(input: &mut [Complex64]) -> Result<&mut [f64], Error> {
+    dispatch::irfft(input)
+}
@@ -0,0 +1,157 @@
+//! Sliding DFT
+//!
+//! A [`SlidingDft`] maintains the `N`-point DFT of the last `N` samples of
+//! a signal, and updates all `N` bins in `O(N)` operations per sample,
+//! instead of the `O(N log N)` operations needed to recompute the CFFT.
+//!
+//! The classic sliding DFT multiplies each bin by a twiddle factor on every
+//! update. Since the twiddle factors are not exact, the rounding errors of
+//! these multiplications accumulate, and the bins drift or even become
+//! unstable. This implementation uses the modulated sliding DFT instead,
+//! which keeps the bins in a modulated form. New samples are multiplied by a
+//! twiddle factor before being added, but the stored bins are never
+//! multiplied, so there is nothing that could accumulate multiplicatively.
+//! The modulation is removed when the bins are read.
+//!
+//! All twiddle factors are looked up from the same sine tables as used by
+//! the FFTs, so `N` can be any size for which [`Size<N>`](Size) implements
+//! [`CFftSize`].
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{complex, sliding::SlidingDft, Complex32};
+//!
+//! let mut window = [Complex32::default(); 16];
+//! let mut spectrum = window;
+//! let _ = complex::cfft(&mut spectrum);
+//! let mut sdft = SlidingDft::from_spectrum(&window, &spectrum);
+//!
+//! for i in 0..100 {
+//!     let sample = (i as f32 * 0.4).sin();
+//!     sdft.push(sample);
+//! }
+//!
+//! let dc = sdft.bin(0);
+//! ```
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::size::{CFftImpl, CFftSize, Size};
+
+/// The DFT of the last `N` samples, updated per sample.
+///
+/// Samples can be real or complex, real samples are converted to complex
+/// ones with a zero imaginary part.
+///
+/// Over very long runs, the additions of new and removals of old samples
+/// still accumulate rounding errors, although only additively.
+/// [`refresh`](Self::refresh) removes these by recomputing the bins with a
+/// CFFT.
+#[derive(Clone, Debug)]
+pub struct SlidingDft<T, const N: usize> {
+    samples: [Complex<T>; N],
+    bins: [Complex<T>; N],
+    next: usize,
+}
+
+impl<T: Float, const N: usize> SlidingDft<T, N>
+where
+    Size<N>: CFftSize,
+{
+    /// Create a sliding DFT of a window of `N` zero samples.
+    pub fn new() -> Self {
+        let zero = Complex::new(T::zero(), T::zero());
+        Self {
+            samples: [zero; N],
+            bins: [zero; N],
+            next: 0,
+        }
+    }
+
+    /// Create a sliding DFT of the samples in `window`, with their CFFT
+    /// already computed into `spectrum`, e.g. by `complex::cfft_N`.
+    ///
+    /// `window` holds the oldest sample first.
+    pub fn from_spectrum(window: &[Complex<T>; N], spectrum: &[Complex<T>; N]) -> Self {
+        Self {
+            samples: *window,
+            bins: *spectrum,
+            next: 0,
+        }
+    }
+
+    /// Create a sliding DFT of the samples in `window`, computing their CFFT.
+    ///
+    /// `window` holds the oldest sample first.
+    pub fn from_window(window: &[Complex<T>; N]) -> Self {
+        let mut bins = *window;
+        Size::<N>::cfft(&mut bins);
+        Self::from_spectrum(window, &bins)
+    }
+
+    /// Add `sample` to the window, dropping the oldest sample, and update all
+    /// bins.
+    #[inline]
+    pub fn push(&mut self, sample: impl Into<Complex<T>>) {
+        let sample = sample.into();
+        let m = self.next;
+        let delta = sample - self.samples[m];
+        self.samples[m] = sample;
+
+        // The modulated bins are the DFTs relative to the start of the
+        // stream, so the sample at stream position `m (mod N)` is
+        // multiplied by `exp(-2*PI*i*k*m/N)`.
+        for (k, bin) in self.bins.iter_mut().enumerate() {
+            *bin += delta * Size::<N>::twiddle::<T>(k * m % N);
+        }
+
+        self.next = (m + 1) % N;
+    }
+
+    /// The DFT bin `k` of the current window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not less than `N`.
+    #[inline]
+    pub fn bin(&self, k: usize) -> Complex<T> {
+        // Shift the DFT back to the start of the window, which is at stream
+        // position `next (mod N)`.
+        self.bins[k] * Size::<N>::twiddle::<T>(k * self.next % N).conj()
+    }
+
+    /// Write all `N` DFT bins of the current window into `output`.
+    #[inline]
+    pub fn spectrum<'a>(&self, output: &'a mut [Complex<T>; N]) -> &'a mut [Complex<T>; N] {
+        for (k, y) in output.iter_mut().enumerate() {
+            *y = self.bin(k);
+        }
+        output
+    }
+
+    /// The samples of the current window, oldest first.
+    #[inline]
+    pub fn samples(&self) -> impl ExactSizeIterator<Item = Complex<T>> + '_ {
+        (0..N).map(move |i| self.samples[(self.next + i) % N])
+    }
+
+    /// Recompute the bins from the samples of the current window with a
+    /// CFFT, removing accumulated rounding errors.
+    pub fn refresh(&mut self) {
+        self.samples.rotate_left(self.next);
+        self.next = 0;
+        self.bins = self.samples;
+        Size::<N>::cfft(&mut self.bins);
+    }
+}
+
+impl<T: Float, const N: usize> Default for SlidingDft<T, N>
+where
+    Size<N>: CFftSize,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
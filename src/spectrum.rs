@@ -0,0 +1,128 @@
+use num_complex::Complex;
+
+use crate::float::Float;
+
+/// A typed view of the packed output of an `N`-point RFFT.
+///
+/// The RFFT functions return `M = N/2` complex values, with the real-valued
+/// coefficient at the Nyquist frequency packed into the imaginary part of
+/// the DC bin. `RealSpectrum` wraps these values and provides access to the
+/// `N/2 + 1` logical bins, taking care of the packing.
+///
+/// Wrapping and unwrapping is free, and [`into_packed`](Self::into_packed)
+/// returns the packed values unchanged, so they can be passed on to the
+/// inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{inverse_real, real, RealSpectrum};
+///
+/// let mut input = [1.; 16];
+/// let spectrum = RealSpectrum::new(real::rfft_16(&mut input));
+/// assert_eq!(spectrum.dc(), 16.);
+/// assert_eq!(spectrum.nyquist(), 0.);
+/// assert_eq!(spectrum.bin_frequency(2, 8000.), 1000.);
+///
+/// let recovered = inverse_real::irfft_16(spectrum.into_packed());
+/// assert_eq!(recovered, &[1.; 16]);
+/// ```
+#[derive(Debug, PartialEq)]
+#[repr(transparent)]
+pub struct RealSpectrum<'a, T, const M: usize>(&'a mut [Complex<T>; M]);
+
+impl<'a, T: Float, const M: usize> RealSpectrum<'a, T, M> {
+    /// Wrap the packed output of an RFFT.
+    #[inline]
+    pub fn new(packed: &'a mut [Complex<T>; M]) -> Self {
+        Self(packed)
+    }
+
+    /// Return the packed values, in the format expected by the inverse RFFT.
+    #[inline]
+    pub fn into_packed(self) -> &'a mut [Complex<T>; M] {
+        self.0
+    }
+
+    /// Return a reference to the packed values.
+    #[inline]
+    pub fn as_packed(&self) -> &[Complex<T>; M] {
+        self.0
+    }
+
+    /// The size `N` of the RFFT that produced this spectrum.
+    #[inline]
+    pub fn fft_len(&self) -> usize {
+        M * 2
+    }
+
+    /// The number of logical bins, `N/2 + 1`.
+    #[inline]
+    pub fn bin_count(&self) -> usize {
+        M + 1
+    }
+
+    /// The real-valued coefficient at frequency zero.
+    #[inline]
+    pub fn dc(&self) -> T {
+        self.0[0].re
+    }
+
+    /// The real-valued coefficient at the Nyquist frequency.
+    #[inline]
+    pub fn nyquist(&self) -> T {
+        self.0[0].im
+    }
+
+    /// The coefficient of bin `k`, for `k` in `0..=N/2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than `N/2`.
+    #[inline]
+    pub fn bin(&self, k: usize) -> Complex<T> {
+        match k {
+            0 => Complex::new(self.dc(), T::zero()),
+            k if k == M => Complex::new(self.nyquist(), T::zero()),
+            k => self.0[k],
+        }
+    }
+
+    /// Iterate over the coefficients of all `N/2 + 1` bins.
+    #[inline]
+    pub fn bins(&self) -> impl ExactSizeIterator<Item = Complex<T>> + '_ {
+        (0..M + 1).map(move |k| self.bin(k))
+    }
+
+    /// Iterate over the magnitudes of all `N/2 + 1` bins.
+    #[inline]
+    pub fn magnitudes(&self) -> impl ExactSizeIterator<Item = T> + '_ {
+        self.powers().map(T::sqrt)
+    }
+
+    /// Iterate over the squared magnitudes of all `N/2 + 1` bins.
+    #[inline]
+    pub fn powers(&self) -> impl ExactSizeIterator<Item = T> + '_ {
+        self.bins().map(|c| c.norm_sqr())
+    }
+
+    /// Iterate over the phases (in radians, in `[-PI, PI]`) of all `N/2 + 1`
+    /// bins.
+    #[inline]
+    pub fn phases(&self) -> impl ExactSizeIterator<Item = T> + '_ {
+        self.bins().map(|c| c.im.atan2(c.re))
+    }
+
+    /// The center frequency of bin `k`, for the given sample rate.
+    #[inline]
+    pub fn bin_frequency(&self, k: usize, sample_rate: T) -> T {
+        T::from_usize(k) * sample_rate / T::from_usize(self.fft_len())
+    }
+}
+
+impl<'a, T: Float, const M: usize> From<&'a mut [Complex<T>; M]> for RealSpectrum<'a, T, M> {
+    #[inline]
+    fn from(packed: &'a mut [Complex<T>; M]) -> Self {
+        Self::new(packed)
+    }
+}
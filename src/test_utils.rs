@@ -5,7 +5,7 @@
 
 /// Approximate floating point comparison utilities
 pub mod approx {
-    use crate::Complex32;
+    use crate::{Complex32, Complex64};
 
     /// Check if two complex numbers are approximately equal with given tolerance
     pub fn complex_eq(a: Complex32, b: Complex32, tolerance: f32) -> bool {
@@ -17,6 +17,16 @@ pub mod approx {
         (a - b).abs() < tolerance
     }
 
+    /// Check if two double-precision complex numbers are approximately equal with given tolerance
+    pub fn complex64_eq(a: Complex64, b: Complex64, tolerance: f64) -> bool {
+        f64_eq(a.re, b.re, tolerance) && f64_eq(a.im, b.im, tolerance)
+    }
+
+    /// Check if two f64 values are approximately equal with given tolerance
+    pub fn f64_eq(a: f64, b: f64, tolerance: f64) -> bool {
+        (a - b).abs() < tolerance
+    }
+
     /// Assert that two slices of complex numbers are approximately equal
     pub fn assert_complex_eq(xa: &[Complex32], xb: &[Complex32]) {
         assert_eq!(xa.len(), xb.len());
@@ -32,6 +42,22 @@ pub mod approx {
             assert!(f32_eq(*a, *b, 0.005), "{a} !~ {b}");
         }
     }
+
+    /// Assert that two slices of double-precision complex numbers are approximately equal
+    pub fn assert_complex64_eq(xa: &[Complex64], xb: &[Complex64]) {
+        assert_eq!(xa.len(), xb.len());
+        for (a, b) in xa.iter().zip(xb) {
+            assert!(complex64_eq(*a, *b, 1e-9), "{a} !~ {b}");
+        }
+    }
+
+    /// Assert that two slices of f64 values are approximately equal
+    pub fn assert_f64_eq(xa: &[f64], xb: &[f64]) {
+        assert_eq!(xa.len(), xb.len());
+        for (a, b) in xa.iter().zip(xb) {
+            assert!(f64_eq(*a, *b, 1e-9), "{a} !~ {b}");
+        }
+    }
 }
 
 /// Reference implementations for comparison testing
@@ -39,12 +65,11 @@ pub mod references {
     extern crate std;
     use std::vec::Vec;
 
-    use crate::Complex32;
+    use crate::{Complex32, Complex64};
 
     /// Reference FFT implementation using rustfft for comparison testing
     pub fn rust_fft(input: &[Complex32]) -> Vec<Complex32> {
-        use rustfft::algorithm::Radix4;
-        use rustfft::{Fft, FftDirection};
+        use rustfft::FftPlanner;
 
         // Convert to rustfft's `num_complex` types, to prevent issues with
         // incompatible versions.
@@ -53,12 +78,28 @@ pub mod references {
             .map(|c| rustfft::num_complex::Complex32::new(c.re, c.im))
             .collect();
 
-        let fft = Radix4::new(buf.len(), FftDirection::Forward);
+        let fft = FftPlanner::new().plan_fft_forward(buf.len());
         fft.process(&mut buf);
 
         buf.iter().map(|c| Complex32::new(c.re, c.im)).collect()
     }
 
+    /// Reference 2D FFT implementation on `width`-element rows, using rustfft
+    /// for the rows and columns
+    pub fn rust_fft_2d(input: &[Complex32], width: usize) -> Vec<Complex32> {
+        let height = input.len() / width;
+        let rows: Vec<_> = input.chunks(width).flat_map(rust_fft).collect();
+
+        let mut output = rows.clone();
+        for c in 0..width {
+            let column: Vec<_> = (0..height).map(|r| rows[r * width + c]).collect();
+            for (r, value) in rust_fft(&column).into_iter().enumerate() {
+                output[r * width + c] = value;
+            }
+        }
+        output
+    }
+
     /// Reference real FFT implementation using realfft for comparison testing
     pub fn real_fft(input: &[f32]) -> Vec<Complex32> {
         use realfft::RealFftPlanner;
@@ -72,6 +113,150 @@ pub mod references {
 
         output.iter().map(|c| Complex32::new(c.re, c.im)).collect()
     }
+
+    /// Double-precision reference FFT implementation using rustfft for comparison testing
+    pub fn rust_fft_f64(input: &[Complex64]) -> Vec<Complex64> {
+        use rustfft::FftPlanner;
+
+        let mut buf: Vec<_> = input
+            .iter()
+            .map(|c| rustfft::num_complex::Complex64::new(c.re, c.im))
+            .collect();
+
+        let fft = FftPlanner::new().plan_fft_forward(buf.len());
+        fft.process(&mut buf);
+
+        buf.iter().map(|c| Complex64::new(c.re, c.im)).collect()
+    }
+
+    /// Double-precision reference real FFT implementation using realfft for comparison testing
+    pub fn real_fft_f64(input: &[f64]) -> Vec<Complex64> {
+        use realfft::RealFftPlanner;
+
+        let mut planner = RealFftPlanner::<f64>::new();
+        let r2c = planner.plan_fft_forward(input.len());
+        let mut input_copy = input.to_vec();
+        let mut output = r2c.make_output_vec();
+
+        r2c.process(&mut input_copy, &mut output).unwrap();
+
+        output.iter().map(|c| Complex64::new(c.re, c.im)).collect()
+    }
+
+    /// Reference radix-2 decimation-in-time FFT, with twiddle factors
+    /// computed directly, for comparison with the other butterfly kernels
+    pub fn radix_2_fft_f64(input: &[Complex64]) -> Vec<Complex64> {
+        use std::f64::consts::PI;
+
+        let n = input.len();
+        if n == 1 {
+            return input.to_vec();
+        }
+
+        let even: Vec<_> = input.iter().copied().step_by(2).collect();
+        let odd: Vec<_> = input.iter().copied().skip(1).step_by(2).collect();
+        let (even, odd) = (radix_2_fft_f64(&even), radix_2_fft_f64(&odd));
+
+        let mut output = std::vec![Complex64::default(); n];
+        for k in 0..n / 2 {
+            let twiddle = Complex64::from_polar(1., -2. * PI * k as f64 / n as f64);
+            let y = twiddle * odd[k];
+            output[k] = even[k] + y;
+            output[k + n / 2] = even[k] - y;
+        }
+        output
+    }
+
+    /// Reference DCT-II, computed directly from its definition
+    pub fn dct2_f64(input: &[f64]) -> Vec<f64> {
+        use std::f64::consts::PI;
+
+        let n = input.len();
+        (0..n)
+            .map(|k| {
+                input
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| x * (PI * (k * (2 * i + 1)) as f64 / (2 * n) as f64).cos())
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Reference DHT, computed directly from its definition
+    pub fn dht_f64(input: &[f64]) -> Vec<f64> {
+        use std::f64::consts::PI;
+
+        let n = input.len();
+        (0..n)
+            .map(|k| {
+                input
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| {
+                        // reduce `k * i` first to keep the argument small
+                        let t = 2. * PI * ((k * i) % n) as f64 / n as f64;
+                        x * (t.cos() + t.sin())
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Reference MDCT, computed directly from its definition
+    pub fn mdct_f64(input: &[f64]) -> Vec<f64> {
+        use std::f64::consts::PI;
+
+        let n = input.len();
+        (0..n / 2)
+            .map(|k| {
+                input
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| {
+                        let t = (i as f64 + 0.5 + n as f64 / 4.) * (k as f64 + 0.5);
+                        x * (2. * PI / n as f64 * t).cos()
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Reference IMDCT, computed directly from its definition
+    pub fn imdct_f64(input: &[f64]) -> Vec<f64> {
+        use std::f64::consts::PI;
+
+        let n = input.len() * 2;
+        (0..n)
+            .map(|i| {
+                let sum: f64 = input
+                    .iter()
+                    .enumerate()
+                    .map(|(k, x)| {
+                        let t = (i as f64 + 0.5 + n as f64 / 4.) * (k as f64 + 0.5);
+                        x * (2. * PI / n as f64 * t).cos()
+                    })
+                    .sum();
+                sum * 4. / n as f64
+            })
+            .collect()
+    }
+
+    /// Reference DST-II, computed directly from its definition
+    pub fn dst2_f64(input: &[f64]) -> Vec<f64> {
+        use std::f64::consts::PI;
+
+        let n = input.len();
+        (0..n)
+            .map(|k| {
+                input
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| x * (PI * ((k + 1) * (2 * i + 1)) as f64 / (2 * n) as f64).sin())
+                    .sum()
+            })
+            .collect()
+    }
 }
 
 /// Signal generators for testing various FFT scenarios
@@ -81,7 +266,7 @@ pub mod signal_generators {
     use std::f32::consts::PI;
     use std::vec::Vec;
 
-    use crate::Complex32;
+    use crate::{Complex32, Complex64};
 
     /// Generate a random float in the range [-1.0, 1.0) using a simple LCG
     fn random_float(rng: &mut u32) -> f32 {
@@ -167,4 +352,102 @@ pub mod signal_generators {
             .map(|c| c.re)
             .collect()
     }
+
+    /// Widen a complex signal to double precision
+    pub fn to_complex64(signal: &[Complex32]) -> Vec<Complex64> {
+        signal
+            .iter()
+            .map(|c| Complex64::new(c.re.into(), c.im.into()))
+            .collect()
+    }
+
+    /// Widen a real signal to double precision
+    pub fn to_f64(signal: &[f32]) -> Vec<f64> {
+        signal.iter().map(|&x| x.into()).collect()
+    }
+}
+
+/// Conversions between floating-point and fixed-point values
+pub mod fixed_point {
+    extern crate std;
+    use std::vec::Vec;
+
+    use crate::{Complex32, ComplexQ15, ComplexQ31};
+
+    const Q15_ONE: f32 = 32768.0;
+    const Q31_ONE: f32 = 2147483648.0;
+
+    /// Convert real values in `[-1, 1)` to Q15
+    pub fn to_q15(signal: &[f32]) -> Vec<i16> {
+        signal.iter().map(|&x| (x * Q15_ONE) as i16).collect()
+    }
+
+    /// Convert real values in `[-1, 1)` to Q31
+    pub fn to_q31(signal: &[f32]) -> Vec<i32> {
+        signal.iter().map(|&x| (x * Q31_ONE) as i32).collect()
+    }
+
+    /// Convert complex values with components in `[-1, 1)` to Q15
+    pub fn to_complex_q15(signal: &[Complex32]) -> Vec<ComplexQ15> {
+        signal
+            .iter()
+            .map(|c| ComplexQ15::new((c.re * Q15_ONE) as i16, (c.im * Q15_ONE) as i16))
+            .collect()
+    }
+
+    /// Convert complex values with components in `[-1, 1)` to Q31
+    pub fn to_complex_q31(signal: &[Complex32]) -> Vec<ComplexQ31> {
+        signal
+            .iter()
+            .map(|c| ComplexQ31::new((c.re * Q31_ONE) as i32, (c.im * Q31_ONE) as i32))
+            .collect()
+    }
+
+    /// Convert Q15 values with the given block exponent to floating point
+    pub fn from_q15(x: &[i16], exponent: i32) -> Vec<f32> {
+        let scale = 2f32.powi(exponent) / Q15_ONE;
+        x.iter().map(|&v| v as f32 * scale).collect()
+    }
+
+    /// Convert Q31 values with the given block exponent to floating point
+    pub fn from_q31(x: &[i32], exponent: i32) -> Vec<f32> {
+        let scale = 2f32.powi(exponent) / Q31_ONE;
+        x.iter().map(|&v| v as f32 * scale).collect()
+    }
+
+    /// Convert complex Q15 values with the given block exponent to floating point
+    pub fn from_complex_q15(x: &[ComplexQ15], exponent: i32) -> Vec<Complex32> {
+        let scale = 2f32.powi(exponent) / Q15_ONE;
+        x.iter()
+            .map(|c| Complex32::new(c.re as f32 * scale, c.im as f32 * scale))
+            .collect()
+    }
+
+    /// Convert complex Q31 values with the given block exponent to floating point
+    pub fn from_complex_q31(x: &[ComplexQ31], exponent: i32) -> Vec<Complex32> {
+        let scale = 2f32.powi(exponent) / Q31_ONE;
+        x.iter()
+            .map(|c| Complex32::new(c.re as f32 * scale, c.im as f32 * scale))
+            .collect()
+    }
+
+    /// Assert that two slices of complex numbers agree within the given
+    /// fraction of the largest magnitude in `expected`
+    pub fn assert_complex_close(result: &[Complex32], expected: &[Complex32], rel: f32) {
+        assert_eq!(result.len(), expected.len());
+        let max = expected.iter().map(|c| c.norm()).fold(1e-6, f32::max);
+        for (a, b) in result.iter().zip(expected) {
+            assert!((a - b).norm() <= rel * max, "{a} !~ {b} (max {max})");
+        }
+    }
+
+    /// Assert that two slices of real numbers agree within the given
+    /// fraction of the largest magnitude in `expected`
+    pub fn assert_f32_close(result: &[f32], expected: &[f32], rel: f32) {
+        assert_eq!(result.len(), expected.len());
+        let max = expected.iter().map(|x| x.abs()).fold(1e-6, f32::max);
+        for (a, b) in result.iter().zip(expected) {
+            assert!((a - b).abs() <= rel * max, "{a} !~ {b} (max {max})");
+        }
+    }
 }
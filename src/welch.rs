@@ -0,0 +1,235 @@
+//! Welch's method for power spectral density estimation
+//!
+//! The periodogram of a single block of samples is a very noisy estimate of
+//! the power spectral density (PSD) of a signal. Welch's method reduces the
+//! variance by splitting a long signal into overlapping segments of `N`
+//! samples, applying a window to each and averaging their periodograms.
+//!
+//! A [`Welch`] estimator takes the signal sample by sample or in slices of
+//! any length, so it can be fed from a stream. Each complete segment is
+//! transformed with an `N`-point RFFT right away, and its periodogram is
+//! added to a caller-provided accumulator of `N/2 + 1` values, so the
+//! estimator does not allocate. [`welch`] estimates the PSD of a whole
+//! signal in one call. Since `N` cannot be inferred from the accumulator, it
+//! has to be specified explicitly.
+//!
+//! The resulting one-sided PSD is scaled as described in the
+//! [`scaling`](crate::scaling) module, in squared units of the input per Hz.
+//! Samples that do not fill a complete segment at the end of the signal are
+//! not included in the estimate.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{welch, window::Window};
+//!
+//! // 10 s of a 1 kHz tone with an amplitude of 1 V, sampled at 8 kHz
+//! let signal: Vec<f32> = (0..80000)
+//!     .map(|i| (2. * core::f32::consts::PI * 1000. * i as f32 / 8000.).sin())
+//!     .collect();
+//!
+//! // 256-point segments, overlapping by half
+//! let mut psd = [0f32; 129];
+//! welch::welch::<_, 256>(&signal, Window::Hann, 128, 8000., &mut psd);
+//!
+//! // the tone is at bin 32, and its power of 0.5 V^2 is spread over the
+//! // equivalent noise bandwidth of 1.5 bins of 31.25 Hz
+//! assert!((psd[32] - 0.5 / (1.5 * 31.25)).abs() < 1e-4);
+//! ```
+
+use crate::float::Float;
+use crate::scaling::{self, WindowScaling};
+use crate::size::{RFftImpl, RFftSize, Size};
+use crate::window::Window;
+
+/// A streaming Welch PSD estimator with segments of `N` samples.
+///
+/// The sum of the periodograms is kept in a caller-provided accumulator of
+/// `N/2 + 1` values, while the samples of the current segment are buffered
+/// inside the estimator.
+pub struct Welch<'a, T, const N: usize>
+where
+    Size<N>: RFftSize,
+{
+    window: [T; N],
+    scaling: WindowScaling,
+    hop: usize,
+    buffer: [T; N],
+    filled: usize,
+    segments: usize,
+    accumulator: &'a mut <Size<N> as RFftSize>::Full<T>,
+}
+
+impl<'a, T: Float, const N: usize> Welch<'a, T, N>
+where
+    Size<N>: RFftSize,
+{
+    /// Create an estimator using the periodic `window` of length `N`, with
+    /// consecutive segments overlapping by `overlap` samples.
+    ///
+    /// The `accumulator` is cleared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `overlap` is not less than `N`.
+    pub fn new(
+        window: Window,
+        overlap: usize,
+        accumulator: &'a mut <Size<N> as RFftSize>::Full<T>,
+    ) -> Self {
+        let mut coefficients = [T::zero(); N];
+        window.fill(&mut coefficients);
+        Self::from_coefficients(&coefficients, overlap, accumulator)
+    }
+
+    /// Create an estimator using the window `coefficients`, with consecutive
+    /// segments overlapping by `overlap` samples.
+    ///
+    /// The `accumulator` is cleared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `overlap` is not less than `N`, or if the `coefficients`
+    /// sum to zero.
+    pub fn from_coefficients(
+        coefficients: &[T; N],
+        overlap: usize,
+        accumulator: &'a mut <Size<N> as RFftSize>::Full<T>,
+    ) -> Self {
+        assert!(overlap < N, "overlap must be less than the segment length");
+
+        accumulator.as_mut().fill(T::zero());
+        Self {
+            window: *coefficients,
+            scaling: WindowScaling::from_coefficients(coefficients),
+            hop: N - overlap,
+            buffer: [T::zero(); N],
+            filled: 0,
+            segments: 0,
+            accumulator,
+        }
+    }
+
+    /// The number of segments averaged so far.
+    #[inline]
+    pub fn segments(&self) -> usize {
+        self.segments
+    }
+
+    /// Feed a sample into the estimator.
+    #[inline]
+    pub fn push(&mut self, sample: T) {
+        self.buffer[self.filled] = sample;
+        self.filled += 1;
+        if self.filled == N {
+            self.process();
+        }
+    }
+
+    /// Feed the `samples` into the estimator.
+    pub fn push_slice(&mut self, samples: &[T]) {
+        let mut samples = samples;
+        while !samples.is_empty() {
+            let count = samples.len().min(N - self.filled);
+            let (head, tail) = samples.split_at(count);
+            self.buffer[self.filled..self.filled + count].copy_from_slice(head);
+            self.filled += count;
+            samples = tail;
+
+            if self.filled == N {
+                self.process();
+            }
+        }
+    }
+
+    /// Write the PSD estimate of the segments averaged so far into `output`.
+    ///
+    /// If no segment is complete yet, the estimate is zero.
+    pub fn psd<'b>(
+        &self,
+        sample_rate: T,
+        output: &'b mut <Size<N> as RFftSize>::Full<T>,
+    ) -> &'b mut <Size<N> as RFftSize>::Full<T> {
+        output.as_mut().copy_from_slice(self.accumulator.as_ref());
+        scale::<T, N>(output.as_mut(), self.density_scale(sample_rate));
+        output
+    }
+
+    /// Turn the accumulator into the PSD estimate of the segments averaged so
+    /// far, and return it.
+    ///
+    /// If no segment is complete yet, the estimate is zero.
+    pub fn finish(self, sample_rate: T) -> &'a mut <Size<N> as RFftSize>::Full<T> {
+        let factor = self.density_scale(sample_rate);
+        scale::<T, N>(self.accumulator.as_mut(), factor);
+        self.accumulator
+    }
+
+    /// Discard all segments and buffered samples.
+    pub fn reset(&mut self) {
+        self.accumulator.as_mut().fill(T::zero());
+        self.filled = 0;
+        self.segments = 0;
+    }
+
+    /// Add the periodogram of the full buffer to the accumulator, and keep
+    /// the overlapping samples for the next segment.
+    fn process(&mut self) {
+        let mut segment: [T; N] = core::array::from_fn(|n| self.buffer[n] * self.window[n]);
+        let spectrum = Size::<N>::rfft(&mut segment);
+        let spectrum = spectrum.as_ref();
+        let accumulator = self.accumulator.as_mut();
+
+        for (a, p) in accumulator.iter_mut().zip(scaling::power(spectrum)) {
+            *a += p;
+        }
+        self.segments += 1;
+
+        self.buffer.copy_within(self.hop.., 0);
+        self.filled = N - self.hop;
+    }
+
+    /// The factor converting the summed periodograms to a PSD, without the
+    /// doubling of the one-sided spectrum.
+    #[inline]
+    fn density_scale(&self, sample_rate: T) -> f64 {
+        if self.segments == 0 {
+            return 0.;
+        }
+        self.scaling.density_scale(sample_rate.to_f64()) / self.segments as f64
+    }
+}
+
+/// Multiply the `N/2 + 1` `values` by `factor`, and all except the DC and
+/// Nyquist values by another factor of two.
+fn scale<T: Float, const N: usize>(values: &mut [T], factor: f64) {
+    let factor = T::from_f64(factor);
+    for (k, value) in values.iter_mut().enumerate() {
+        *value *= scaling::one_sided_scale(k, N, factor);
+    }
+}
+
+/// Estimate the one-sided PSD of `signal` using Welch's method, with
+/// segments of `N` samples that overlap by `overlap` samples.
+///
+/// Each segment is multiplied by the periodic `window` before being
+/// transformed. The estimate is written into `output`, in squared units of
+/// the input per Hz.
+///
+/// # Panics
+///
+/// Panics if `overlap` is not less than `N`.
+pub fn welch<'a, T: Float, const N: usize>(
+    signal: &[T],
+    window: Window,
+    overlap: usize,
+    sample_rate: T,
+    output: &'a mut <Size<N> as RFftSize>::Full<T>,
+) -> &'a mut <Size<N> as RFftSize>::Full<T>
+where
+    Size<N>: RFftSize,
+{
+    let mut estimator = Welch::new(window, overlap, output);
+    estimator.push_slice(signal);
+    estimator.finish(sample_rate)
+}
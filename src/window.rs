@@ -0,0 +1,314 @@
+//! Window functions
+//!
+//! Before computing the spectrum of a block of samples, it is usually
+//! multiplied by a window, which tapers the block towards its edges to reduce
+//! spectral leakage. [`Window`] provides the common windows. Their
+//! coefficients are written into an array once with [`Window::fill`], which
+//! [`apply`] then multiplies each block by, so they do not have to be
+//! recomputed for every block.
+//!
+//! By default, windows are periodic (DFT-even), which is what is needed for
+//! spectral analysis: A window of length `N` is the first `N` values of a
+//! symmetric window of length `N + 1`. Symmetric windows, e.g. for filter
+//! design, are available through [`Window::fill_symmetric`]. The
+//! [`Window::Sine`] and [`Window::Kbd`] windows of the [MDCT](crate::mdct)
+//! are always symmetric.
+//!
+//! All coefficients are computed without `std`. The cosines of the cosine-sum
+//! windows are looked up from the same sine tables as used by the FFTs where
+//! the window length allows, so e.g. the periodic Hann window of any enabled
+//! FFT size is built from exactly the twiddle factors of that FFT.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{real::rfft_16, window::{self, Window}};
+//!
+//! let mut hann = [0.; 16];
+//! Window::Hann.fill(&mut hann);
+//!
+//! let mut samples = [1.; 16];
+//! window::apply(&mut samples, &hann);
+//! let spectrum = rfft_16(&mut samples);
+//!
+//! // a constant signal leaks into the first bin only
+//! assert!((spectrum[0].re - 8.).abs() < 1e-5);
+//! assert!((spectrum[1].re + 4.).abs() < 1e-5);
+//! assert!(spectrum[2].norm() < 1e-5);
+//! ```
+
+use core::f64::consts::PI;
+
+use num_complex::Complex;
+
+use crate::float::Float;
+use crate::impls::twiddle;
+use crate::math;
+
+/// A window function.
+///
+/// The formulas of the windows are given for the symmetric window of length
+/// `M + 1`, with `n` in `[0, M]`. The periodic window of length `N` uses
+/// `M = N`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Window {
+    /// The rectangular window, which leaves the samples unchanged.
+    Rectangular,
+    /// The Hann window.
+    ///
+    /// ```text
+    /// w[n] = 0.5 - 0.5 * cos(2*PI*n/M)
+    /// ```
+    Hann,
+    /// The Hamming window.
+    ///
+    /// ```text
+    /// w[n] = 0.54 - 0.46 * cos(2*PI*n/M)
+    /// ```
+    Hamming,
+    /// The Blackman window.
+    ///
+    /// ```text
+    /// w[n] = 0.42 - 0.5 * cos(2*PI*n/M) + 0.08 * cos(4*PI*n/M)
+    /// ```
+    Blackman,
+    /// The 4-term Blackman-Harris window, with side lobes below -92 dB.
+    ///
+    /// ```text
+    /// w[n] = 0.35875 - 0.48829 * cos(2*PI*n/M) + 0.14128 * cos(4*PI*n/M)
+    ///      - 0.01168 * cos(6*PI*n/M)
+    /// ```
+    BlackmanHarris,
+    /// The 5-term flat-top window, whose flat main lobe allows for accurate
+    /// amplitude measurements of tones between bins.
+    ///
+    /// ```text
+    /// w[n] = 0.21557895 - 0.41663158 * cos(2*PI*n/M) + 0.277263158 * cos(4*PI*n/M)
+    ///      - 0.083578947 * cos(6*PI*n/M) + 0.006947368 * cos(8*PI*n/M)
+    /// ```
+    FlatTop,
+    /// The Kaiser window with shape parameter `beta`.
+    ///
+    /// Larger values of `beta` result in a wider main lobe and lower side
+    /// lobes. A `beta` of zero results in the rectangular window.
+    ///
+    /// ```text
+    /// w[n] = I0(beta * sqrt(1 - (2*n/M - 1)^2)) / I0(beta)
+    /// ```
+    Kaiser(f64),
+    /// The Tukey (tapered cosine) window with the fraction `alpha` of the
+    /// window inside the cosine tapers.
+    ///
+    /// An `alpha` of zero results in the rectangular window, an `alpha` of
+    /// one in the Hann window.
+    ///
+    /// ```text
+    /// w[n] = 0.5 - 0.5 * cos(2*PI*n/(alpha*M))   for n < alpha*M/2
+    ///      = 1                                   for alpha*M/2 <= n <= M/2
+    ///      = w[M - n]                            for n > M/2
+    /// ```
+    Tukey(f64),
+    /// The Gaussian window with the standard deviation `sigma`, relative to
+    /// half the window length, which must be positive.
+    ///
+    /// ```text
+    /// w[n] = exp(-0.5 * ((2*n/M - 1) / sigma)^2)
+    /// ```
+    Gaussian(f64),
+    /// The sine window, which satisfies the Princen-Bradley condition of the
+    /// [MDCT](crate::mdct).
+    ///
+    /// Unlike the windows above, it is defined for the window length `N`
+    /// instead of `M` and is symmetric, so [`fill`](Self::fill) and
+    /// [`fill_symmetric`](Self::fill_symmetric) produce the same
+    /// coefficients.
+    ///
+    /// ```text
+    /// w[n] = sin(PI * (n + 1/2) / N)
+    /// ```
+    Sine,
+    /// The Kaiser-Bessel-derived window with shape parameter `alpha`, which
+    /// satisfies the Princen-Bradley condition of the [MDCT](crate::mdct).
+    ///
+    /// It is derived from the symmetric Kaiser window `v` of length
+    /// `N/2 + 1` with `beta = PI * alpha`, so larger values of `alpha` result
+    /// in a wider main lobe and lower side lobes. Like [`Sine`](Self::Sine),
+    /// it is defined for the window length `N`, which must be even, and is
+    /// symmetric.
+    ///
+    /// ```text
+    /// w[n] = sqrt(sum_{j <= n} v[j] / sum_{j <= N/2} v[j])   for n < N/2
+    ///      = w[N - 1 - n]                                     for n >= N/2
+    /// ```
+    Kbd(f64),
+}
+
+/// Flat-top window coefficients.
+const FLAT_TOP: [f64; 5] = [
+    0.215_578_95,
+    0.416_631_58,
+    0.277_263_158,
+    0.083_578_947,
+    0.006_947_368,
+];
+
+impl Window {
+    /// Fill `window` with the coefficients of the periodic window of its
+    /// length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window is [`Kbd`](Self::Kbd) and the length of `window`
+    /// is odd.
+    pub fn fill<T: Float>(&self, window: &mut [T]) {
+        let m = window.len();
+        self.for_each_coefficient(window.len(), m, |n, w| window[n] = w);
+    }
+
+    /// Fill `window` with the coefficients of the symmetric window of its
+    /// length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window is [`Kbd`](Self::Kbd) and the length of `window`
+    /// is odd.
+    pub fn fill_symmetric<T: Float>(&self, window: &mut [T]) {
+        let m = window.len().saturating_sub(1);
+        self.for_each_coefficient(window.len(), m, |n, w| window[n] = w);
+    }
+
+    /// Call `f` with each index `n` and coefficient `w[n]` of the window of
+    /// length `len` with parameter `M = m`, in no particular order.
+    ///
+    /// The values shared by all coefficients are only computed once.
+    pub(crate) fn for_each_coefficient<T: Float>(
+        &self,
+        len: usize,
+        m: usize,
+        mut f: impl FnMut(usize, T),
+    ) {
+        match *self {
+            Self::Sine => {
+                for n in 0..len {
+                    let (sin, _) = math::sin_cos(PI * (n as f64 + 0.5) / len as f64);
+                    f(n, T::from_f64(sin));
+                }
+            }
+            Self::Kbd(alpha) => kbd(alpha, len, f),
+            _ if m == 0 => (0..len).for_each(|n| f(n, T::one())),
+            Self::Rectangular => (0..len).for_each(|n| f(n, T::one())),
+            Self::Hann => cosine_sum(&[0.5, 0.5], len, m, f),
+            Self::Hamming => cosine_sum(&[0.54, 0.46], len, m, f),
+            Self::Blackman => cosine_sum(&[0.42, 0.5, 0.08], len, m, f),
+            Self::BlackmanHarris => {
+                cosine_sum(&[0.35875, 0.48829, 0.14128, 0.01168], len, m, f);
+            }
+            Self::FlatTop => cosine_sum(&FLAT_TOP, len, m, f),
+            Self::Kaiser(beta) => {
+                let scale = 1. / math::bessel_i0(beta);
+                for n in 0..len {
+                    let t = 2. * n as f64 / m as f64 - 1.;
+                    let w = math::bessel_i0(beta * math::sqrt(1. - t * t)) * scale;
+                    f(n, T::from_f64(w));
+                }
+            }
+            Self::Tukey(alpha) => {
+                for n in 0..len {
+                    // distance from the closer edge, as a fraction of the window
+                    let x = n.min(m - n) as f64 / m as f64;
+                    if x < alpha / 2. {
+                        let (_, cos) = math::sin_cos(2. * PI * x / alpha);
+                        f(n, T::from_f64(0.5 - 0.5 * cos));
+                    } else {
+                        f(n, T::one());
+                    }
+                }
+            }
+            Self::Gaussian(sigma) => {
+                for n in 0..len {
+                    let t = (2. * n as f64 / m as f64 - 1.) / sigma;
+                    f(n, T::from_f64(math::exp(-0.5 * t * t)));
+                }
+            }
+        }
+    }
+}
+
+/// Multiply the `N` real values in `x` by the window `coefficients`, as
+/// computed by [`Window::fill`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::window::{self, Window};
+///
+/// let mut hann = [0.; 16];
+/// Window::Hann.fill(&mut hann);
+///
+/// let mut samples = [1.; 16];
+/// let result = window::apply(&mut samples, &hann);
+/// assert_eq!(result, &hann);
+/// ```
+#[inline]
+pub fn apply<'a, T: Float, const N: usize>(
+    x: &'a mut [T; N],
+    coefficients: &[T; N],
+) -> &'a mut [T; N] {
+    for (x_n, w) in x.iter_mut().zip(coefficients) {
+        *x_n *= *w;
+    }
+    x
+}
+
+/// Multiply the `N` complex values in `x` by the window `coefficients`, as
+/// computed by [`Window::fill`].
+#[inline]
+pub fn apply_complex<'a, T: Float, const N: usize>(
+    x: &'a mut [Complex<T>; N],
+    coefficients: &[T; N],
+) -> &'a mut [Complex<T>; N] {
+    for (x_n, w) in x.iter_mut().zip(coefficients) {
+        *x_n = x_n.scale(*w);
+    }
+    x
+}
+
+/// Call `f` with the coefficients `w[n] = sum_k (-1)^k * a[k] * cos(2*PI*k*n/m)`
+/// of a cosine-sum window of length `len`.
+#[inline]
+fn cosine_sum<T: Float>(a: &[f64], len: usize, m: usize, mut f: impl FnMut(usize, T)) {
+    for n in 0..len {
+        let mut w = T::from_f64(a[0]);
+        for (k, a_k) in a.iter().enumerate().skip(1) {
+            let cos = twiddle::any::<T>(k * n, m).re;
+            if k % 2 == 1 {
+                w -= T::from_f64(*a_k) * cos;
+            } else {
+                w += T::from_f64(*a_k) * cos;
+            }
+        }
+        f(n, w);
+    }
+}
+
+/// Call `f` with the coefficients of the Kaiser-Bessel-derived window of
+/// length `len` with parameter `alpha`.
+fn kbd<T: Float>(alpha: f64, len: usize, mut f: impl FnMut(usize, T)) {
+    assert_eq!(len % 2, 0, "window length must be even");
+
+    let half = len / 2;
+    let beta = PI * alpha;
+    let kaiser = |j: usize| {
+        let t = 2. * j as f64 / half as f64 - 1.;
+        math::bessel_i0(beta * math::sqrt(1. - t * t))
+    };
+
+    let total: f64 = (0..=half).map(kaiser).sum();
+    let mut sum = 0.;
+    for j in 0..half {
+        sum += kaiser(j);
+        let w = T::from_f64(math::sqrt(sum / total));
+        f(j, w);
+        f(len - 1 - j, w);
+    }
+}
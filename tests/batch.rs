@@ -0,0 +1,180 @@
+use microfft::test_utils::*;
+use microfft::{batch, complex, real, Complex32, Complex64};
+
+fn transpose<T: Copy + Default, const A: usize, const B: usize>(x: &[[T; A]; B]) -> [[T; B]; A] {
+    let mut y = [[T::default(); B]; A];
+    for (i, row) in x.iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            y[j][i] = *value;
+        }
+    }
+    y
+}
+
+fn flatten<T: Copy, const W: usize>(rows: &[[T; W]]) -> Vec<T> {
+    rows.iter().flatten().copied().collect()
+}
+
+macro_rules! batch_tests {
+    ( $( $name:ident: $N:expr, $C:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                fn complex_channels() -> [[Complex32; $N]; $C] {
+                    let mut channels = [[Complex32::default(); $N]; $C];
+                    for (c, channel) in channels.iter_mut().enumerate() {
+                        let signal = signal_generators::noise_complex($N, 12345 + c as u32);
+                        channel.copy_from_slice(&signal);
+                    }
+                    channels
+                }
+
+                fn real_channels() -> [[f32; $N]; $C] {
+                    let mut channels = [[0.; $N]; $C];
+                    for (c, channel) in channels.iter_mut().enumerate() {
+                        let signal = signal_generators::noise_real($N, 12345 + c as u32);
+                        channel.copy_from_slice(&signal);
+                    }
+                    channels
+                }
+
+                let input = complex_channels();
+                let mut expected = input;
+                for channel in expected.iter_mut() {
+                    let _ = complex::cfft(channel);
+                }
+
+                let mut planar = input;
+                let result = batch::cfft_planar(&mut planar);
+                approx::assert_complex_eq(&flatten(result), &flatten(&expected));
+
+                let mut interleaved = transpose(&input);
+                let result = batch::cfft_interleaved(&mut interleaved);
+                approx::assert_complex_eq(&flatten(&transpose(result)), &flatten(&expected));
+
+                let mut interleaved = flatten(&transpose(&input));
+                let result = batch::cfft_interleaved_flat::<$N, $C>(&mut interleaved);
+                approx::assert_complex_eq(&flatten(&transpose(result)), &flatten(&expected));
+
+                let input = real_channels();
+                let mut expected = Vec::new();
+                for channel in input {
+                    let mut channel = channel;
+                    expected.extend_from_slice(real::rfft(&mut channel));
+                }
+
+                let mut planar = input;
+                let result = batch::rfft_planar(&mut planar);
+                approx::assert_complex_eq(&flatten(result), &expected);
+
+                let mut interleaved = transpose(&input);
+                let result = batch::rfft_interleaved(&mut interleaved);
+                approx::assert_complex_eq(&flatten(&transpose(result)), &expected);
+
+                let mut interleaved = flatten(&transpose(&input));
+                let result = batch::rfft_interleaved_flat::<$N, $C>(&mut interleaved);
+                approx::assert_complex_eq(&flatten(&transpose(result)), &expected);
+
+                let input = complex_channels()
+                    .map(|channel| channel.map(|c| Complex64::new(c.re.into(), c.im.into())));
+                let mut expected = input;
+                for channel in expected.iter_mut() {
+                    let _ = complex::cfft_f64(channel);
+                }
+
+                let mut planar = input;
+                let result = batch::cfft_planar_f64(&mut planar);
+                approx::assert_complex64_eq(&flatten(result), &flatten(&expected));
+
+                let mut interleaved = transpose(&input);
+                let result = batch::cfft_interleaved_f64(&mut interleaved);
+                approx::assert_complex64_eq(&flatten(&transpose(result)), &flatten(&expected));
+
+                let mut interleaved = flatten(&transpose(&input));
+                let result = batch::cfft_interleaved_flat_f64::<$N, $C>(&mut interleaved);
+                approx::assert_complex64_eq(&flatten(&transpose(result)), &flatten(&expected));
+
+                let input = real_channels().map(|channel| channel.map(f64::from));
+                let mut expected = Vec::new();
+                for channel in input {
+                    let mut channel = channel;
+                    expected.extend_from_slice(real::rfft_f64(&mut channel));
+                }
+
+                let mut planar = input;
+                let result = batch::rfft_planar_f64(&mut planar);
+                approx::assert_complex64_eq(&flatten(result), &expected);
+
+                let mut interleaved = transpose(&input);
+                let result = batch::rfft_interleaved_f64(&mut interleaved);
+                approx::assert_complex64_eq(&flatten(&transpose(result)), &expected);
+
+                let mut interleaved = flatten(&transpose(&input));
+                let result = batch::rfft_interleaved_flat_f64::<$N, $C>(&mut interleaved);
+                approx::assert_complex64_eq(&flatten(&transpose(result)), &expected);
+            }
+        )*
+    };
+}
+
+batch_tests! {
+    size_2x1: 2, 1,
+    size_4x3: 4, 3,
+    size_16x8: 16, 8,
+    size_256x3: 256, 3,
+    size_1024x6: 1024, 6,
+    size_12x3: 12, 3,
+    size_240x5: 240, 5,
+}
+
+#[test]
+fn sine_per_channel() {
+    // Each channel holds a sine at a different bin.
+    let mut frames = [[0.; 4]; 64];
+    for (i, frame) in frames.iter_mut().enumerate() {
+        for (c, value) in frame.iter_mut().enumerate() {
+            let bin = (c + 1) * 3;
+            *value = (2. * std::f32::consts::PI * (bin * i) as f32 / 64.).sin();
+        }
+    }
+
+    let spectra = batch::rfft_interleaved(&mut frames);
+    for (k, frame) in spectra.iter().enumerate() {
+        for (c, value) in frame.iter().enumerate() {
+            let expected = if k == (c + 1) * 3 { -32. } else { 0. };
+            assert!(
+                approx::complex_eq(*value, Complex32::new(0., expected), 1e-4),
+                "{value}"
+            );
+        }
+    }
+}
+
+#[test]
+fn zero_channels() {
+    let mut planar: [[Complex32; 16]; 0] = [];
+    let _ = batch::cfft_planar(&mut planar);
+    let mut interleaved: [[Complex32; 0]; 16] = [[]; 16];
+    let _ = batch::cfft_interleaved(&mut interleaved);
+
+    let mut planar: [[f32; 16]; 0] = [];
+    assert!(batch::rfft_planar(&mut planar).is_empty());
+    let mut interleaved = [[0.; 0]; 16];
+    assert_eq!(batch::rfft_interleaved(&mut interleaved).len(), 8);
+    let mut interleaved = [[0.; 0]; 24];
+    assert_eq!(batch::rfft_interleaved(&mut interleaved).len(), 12);
+}
+
+#[test]
+#[should_panic(expected = "input length must be N * C")]
+fn cfft_flat_wrong_length() {
+    let mut input = [Complex32::default(); 16 * 3 + 1];
+    let _ = batch::cfft_interleaved_flat::<16, 3>(&mut input);
+}
+
+#[test]
+#[should_panic(expected = "input length must be N * C")]
+fn rfft_flat_wrong_length() {
+    let mut input = [0.; 16 * 2];
+    let _ = batch::rfft_interleaved_flat::<16, 3>(&mut input);
+}
@@ -0,0 +1,66 @@
+use microfft::test_utils::*;
+use microfft::{bluestein, Complex32, Error};
+use std::convert::TryInto;
+
+macro_rules! dft_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::ramp_complex($N, 1.7, $N as f32);
+                let mut scratch = vec![Default::default(); bluestein::scratch_len($N)];
+
+                let expected = references::rust_fft(&input);
+                let mut data: [_; $N] = input.clone().try_into().unwrap();
+                let result = bluestein::dft(&mut data, &mut scratch).unwrap();
+                approx::assert_complex_eq(result, &expected);
+
+                let recovered = bluestein::idft(&mut data, &mut scratch).unwrap();
+                approx::assert_complex_eq(recovered, &input);
+
+                let input = signal_generators::to_complex64(&input);
+                let mut scratch = vec![Default::default(); bluestein::scratch_len($N)];
+
+                let expected = references::rust_fft_f64(&input);
+                let mut data: [_; $N] = input.clone().try_into().unwrap();
+                let result = bluestein::dft_f64(&mut data, &mut scratch).unwrap();
+                approx::assert_complex64_eq(result, &expected);
+
+                let recovered = bluestein::idft_f64(&mut data, &mut scratch).unwrap();
+                approx::assert_complex64_eq(recovered, &input);
+            }
+        )*
+    };
+}
+
+dft_tests! {
+    dft_1: 1,
+    dft_2: 2,
+    dft_3: 3,
+    dft_7: 7,
+    dft_16: 16,
+    dft_100: 100,
+    dft_127: 127,
+    dft_251: 251,
+    dft_1000: 1000,
+    dft_4099: 4099,
+}
+
+#[test]
+fn size_not_available() {
+    // a 16385-point DFT requires a 65536-point CFFT
+    let input = signal_generators::noise_complex(16385, 12345);
+    let mut data: Box<[_; 16385]> = input.clone().into_boxed_slice().try_into().unwrap();
+    let mut scratch = vec![Complex32::default(); bluestein::scratch_len(16385)];
+
+    assert_eq!(
+        bluestein::dft(&mut data, &mut scratch),
+        Err(Error::TooLarge)
+    );
+    assert_eq!(&data[..], &input[..]);
+    assert_eq!(
+        bluestein::idft(&mut data, &mut scratch),
+        Err(Error::TooLarge)
+    );
+    assert_eq!(&data[..], &input[..]);
+}
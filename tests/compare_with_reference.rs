@@ -39,6 +39,30 @@ cfft_tests! {
     cfft_32768: 32768,
 }
 
+cfft_tests! {
+    cfft_12: 12,
+    cfft_24: 24,
+    cfft_48: 48,
+    cfft_96: 96,
+    cfft_192: 192,
+    cfft_384: 384,
+    cfft_768: 768,
+    cfft_20: 20,
+    cfft_40: 40,
+    cfft_80: 80,
+    cfft_160: 160,
+    cfft_320: 320,
+    cfft_640: 640,
+    cfft_1280: 1280,
+    cfft_60: 60,
+    cfft_120: 120,
+    cfft_240: 240,
+    cfft_480: 480,
+    cfft_960: 960,
+    cfft_1920: 1920,
+    cfft_3840: 3840,
+}
+
 macro_rules! rfft_tests {
     ( $( $name:ident: $N:expr, )* ) => {
         $(
@@ -81,3 +105,182 @@ rfft_tests! {
     rfft_16384: 16384,
     rfft_32768: 32768,
 }
+
+macro_rules! mixed_rfft_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let frequency = 1.7;
+                let sample_rate = $N as f32;
+                let input = signal_generators::ramp_real($N, frequency, sample_rate);
+
+                let expected = references::real_fft(&input);
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let result = microfft::real::$name(&mut input);
+
+                // The reference uses a different factorization for
+                // non-power-of-two sizes, so the Nyquist coefficient is not
+                // bit-identical.
+                let coeff_at_nyquist = result[0].im;
+                assert!(approx::f32_eq(coeff_at_nyquist, expected[$N / 2].re, 0.005));
+                result[0].im = 0.0;
+                approx::assert_complex_eq(result, &expected[..($N / 2)]);
+            }
+        )*
+    };
+}
+
+mixed_rfft_tests! {
+    rfft_12: 12,
+    rfft_24: 24,
+    rfft_48: 48,
+    rfft_96: 96,
+    rfft_192: 192,
+    rfft_384: 384,
+    rfft_768: 768,
+    rfft_20: 20,
+    rfft_40: 40,
+    rfft_80: 80,
+    rfft_160: 160,
+    rfft_320: 320,
+    rfft_640: 640,
+    rfft_1280: 1280,
+    rfft_60: 60,
+    rfft_120: 120,
+    rfft_240: 240,
+    rfft_480: 480,
+    rfft_960: 960,
+    rfft_1920: 1920,
+    rfft_3840: 3840,
+}
+
+macro_rules! cfft_f64_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let frequency = 1.7;
+                let sample_rate = $N as f32;
+                let input = signal_generators::ramp_complex($N, frequency, sample_rate);
+                let input = signal_generators::to_complex64(&input);
+
+                let expected = references::rust_fft_f64(&input);
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let result = microfft::complex::$name(&mut input);
+
+                approx::assert_complex64_eq(result, &expected);
+            }
+        )*
+    };
+}
+
+cfft_f64_tests! {
+    cfft_2_f64: 2,
+    cfft_4_f64: 4,
+    cfft_8_f64: 8,
+    cfft_16_f64: 16,
+    cfft_32_f64: 32,
+    cfft_64_f64: 64,
+    cfft_128_f64: 128,
+    cfft_256_f64: 256,
+    cfft_512_f64: 512,
+    cfft_1024_f64: 1024,
+    cfft_2048_f64: 2048,
+    cfft_4096_f64: 4096,
+    cfft_8192_f64: 8192,
+    cfft_16384_f64: 16384,
+    cfft_32768_f64: 32768,
+}
+
+cfft_f64_tests! {
+    cfft_12_f64: 12,
+    cfft_24_f64: 24,
+    cfft_48_f64: 48,
+    cfft_96_f64: 96,
+    cfft_192_f64: 192,
+    cfft_384_f64: 384,
+    cfft_768_f64: 768,
+    cfft_20_f64: 20,
+    cfft_40_f64: 40,
+    cfft_80_f64: 80,
+    cfft_160_f64: 160,
+    cfft_320_f64: 320,
+    cfft_640_f64: 640,
+    cfft_1280_f64: 1280,
+    cfft_60_f64: 60,
+    cfft_120_f64: 120,
+    cfft_240_f64: 240,
+    cfft_480_f64: 480,
+    cfft_960_f64: 960,
+    cfft_1920_f64: 1920,
+    cfft_3840_f64: 3840,
+}
+
+macro_rules! rfft_f64_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let frequency = 1.7;
+                let sample_rate = $N as f32;
+                let input = signal_generators::ramp_real($N, frequency, sample_rate);
+                let input = signal_generators::to_f64(&input);
+
+                let expected = references::real_fft_f64(&input);
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let result = microfft::real::$name(&mut input);
+
+                // The real-valued coefficient at the Nyquist frequency
+                // is packed into the imaginary part of the DC bin.
+                let coeff_at_nyquist = result[0].im;
+                assert!(approx::f64_eq(coeff_at_nyquist, expected[$N / 2].re, 1e-9));
+                // Clear this value before checking the results.
+                result[0].im = 0.0;
+                approx::assert_complex64_eq(result, &expected[..($N / 2)]);
+            }
+        )*
+    };
+}
+
+rfft_f64_tests! {
+    rfft_2_f64: 2,
+    rfft_4_f64: 4,
+    rfft_8_f64: 8,
+    rfft_16_f64: 16,
+    rfft_32_f64: 32,
+    rfft_64_f64: 64,
+    rfft_128_f64: 128,
+    rfft_256_f64: 256,
+    rfft_512_f64: 512,
+    rfft_1024_f64: 1024,
+    rfft_2048_f64: 2048,
+    rfft_4096_f64: 4096,
+    rfft_8192_f64: 8192,
+    rfft_16384_f64: 16384,
+    rfft_32768_f64: 32768,
+}
+
+rfft_f64_tests! {
+    rfft_12_f64: 12,
+    rfft_24_f64: 24,
+    rfft_48_f64: 48,
+    rfft_96_f64: 96,
+    rfft_192_f64: 192,
+    rfft_384_f64: 384,
+    rfft_768_f64: 768,
+    rfft_20_f64: 20,
+    rfft_40_f64: 40,
+    rfft_80_f64: 80,
+    rfft_160_f64: 160,
+    rfft_320_f64: 320,
+    rfft_640_f64: 640,
+    rfft_1280_f64: 1280,
+    rfft_60_f64: 60,
+    rfft_120_f64: 120,
+    rfft_240_f64: 240,
+    rfft_480_f64: 480,
+    rfft_960_f64: 960,
+    rfft_1920_f64: 1920,
+    rfft_3840_f64: 3840,
+}
@@ -0,0 +1,123 @@
+use microfft::czt::{self, Contour};
+use microfft::test_utils::*;
+use microfft::{Complex32, Complex64, Error};
+use std::f64::consts::PI;
+
+/// The z-transform of `input` at `z`.
+fn reference(input: &[Complex64], z: Complex64) -> Complex64 {
+    input
+        .iter()
+        .enumerate()
+        .map(|(n, x)| x * z.powi(-(n as i32)))
+        .sum()
+}
+
+/// Assert that `value` agrees with `expected` to a relative precision.
+fn assert_close(k: usize, value: Complex64, expected: Complex64) {
+    let tolerance = 1e-9 * expected.norm().max(1.);
+    assert!(
+        (value - expected).norm() < tolerance,
+        "{k}: {value} !~ {expected}"
+    );
+}
+
+macro_rules! czt_tests {
+    ( $( $name:ident: $N:expr, $M:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                // `N` points around the unit circle result in the DFT
+                let input = signal_generators::noise_complex($N, 12345);
+
+                let mut output = [Complex32::default(); $N];
+                let mut scratch = vec![Complex32::default(); czt::scratch_len($N, $N)];
+                let contour = Contour::arc(0., 2. * std::f32::consts::PI / $N as f32);
+                let result = czt::czt(&input, &mut output, contour, &mut scratch).unwrap();
+
+                approx::assert_complex_eq(result, &references::rust_fft(&input));
+
+                let input = signal_generators::to_complex64(&signal_generators::noise_complex($N, 12345));
+
+                let (r0, phi0, r, phi) = (0.99, 0.3, 1.00001, 0.05);
+                let mut output = [Complex64::default(); $M];
+                let mut scratch = vec![Complex64::default(); czt::scratch_len($N, $M)];
+                let contour = Contour::new(r0, phi0, r, phi);
+                let result = czt::czt_f64(&input, &mut output, contour, &mut scratch).unwrap();
+
+                for (k, value) in result.iter().enumerate() {
+                    let z = Complex64::from_polar(r0 * r.powi(k as i32), phi0 + k as f64 * phi);
+                    let expected = reference(&input, z);
+                    assert_close(k, *value, expected);
+                }
+
+                let input = signal_generators::to_f64(&signal_generators::noise_real($N, 12345));
+                let complex_input: Vec<_> = input.iter().map(|&x| Complex64::new(x, 0.)).collect();
+
+                let (start, stop, sample_rate) = (48., 52., 1000.);
+                let mut output = [Complex64::default(); $M];
+                let mut scratch = vec![Complex64::default(); czt::scratch_len($N, $M)];
+                let result = czt::zoom_fft_f64(&input, &mut output, start, stop, sample_rate, &mut scratch).unwrap();
+
+                for (k, value) in result.iter().enumerate() {
+                    let frequency = start + (stop - start) * k as f64 / ($M - 1) as f64;
+                    let z = Complex64::from_polar(1., 2. * PI * frequency / sample_rate);
+                    let expected = reference(&complex_input, z);
+                    assert_close(k, *value, expected);
+                }
+            }
+        )*
+    };
+}
+
+czt_tests! {
+    size_1x2: 1, 2,
+    size_2x2: 2, 2,
+    size_7x40: 7, 40,
+    size_64x16: 64, 16,
+    size_100x41: 100, 41,
+    size_1000x201: 1000, 201,
+}
+
+#[test]
+fn zoom_fft_matches_rfft_bins() {
+    // At multiples of `sample_rate / N`, the zoom FFT equals the FFT bins.
+    let input = signal_generators::noise_real(256, 12345);
+    let complex_input: Vec<_> = input.iter().map(|&x| Complex32::new(x, 0.)).collect();
+    let expected = references::rust_fft(&complex_input);
+
+    let mut output = [Complex32::default(); 11];
+    let mut scratch = vec![Complex32::default(); czt::scratch_len(256, 11)];
+    let result = czt::zoom_fft(&input, &mut output, 100., 200., 2560., &mut scratch).unwrap();
+
+    approx::assert_complex_eq(result, &expected[10..=20]);
+}
+
+#[test]
+fn empty() {
+    let mut output = [Complex32::new(1., 1.); 4];
+    let result = czt::czt(&[], &mut output, Contour::arc(0., 0.1), &mut []).unwrap();
+    assert_eq!(result, &[Complex32::default(); 4]);
+
+    let input = [Complex32::new(1., 0.); 4];
+    let result = czt::czt(&input, &mut [], Contour::arc(0., 0.1), &mut []).unwrap();
+    assert!(result.is_empty());
+}
+
+#[test]
+fn size_not_available() {
+    // 20000 input and 20000 output values require a 65536-point CFFT
+    let input = vec![Complex32::default(); 20000];
+    let mut output = vec![Complex32::new(1., 1.); 20000];
+    let mut scratch = vec![Complex32::default(); czt::scratch_len(20000, 20000)];
+    let result = czt::czt(&input, &mut output, Contour::arc(0., 0.1), &mut scratch);
+    assert_eq!(result, Err(Error::TooLarge));
+}
+
+#[test]
+#[should_panic(expected = "scratch buffer too small")]
+fn scratch_too_small() {
+    let input = [Complex32::default(); 10];
+    let mut output = [Complex32::default(); 10];
+    let mut scratch = [Complex32::default(); 16];
+    let _ = czt::czt(&input, &mut output, Contour::arc(0., 0.1), &mut scratch).unwrap();
+}
@@ -0,0 +1,89 @@
+use microfft::dct;
+use microfft::test_utils::*;
+use std::convert::TryInto;
+
+macro_rules! dct_tests {
+    ( $( $name:ident: $N:expr => ($dct2:ident, $dct3:ident, $dst2:ident, $dst3:ident, $dct2_f64:ident, $dct3_f64:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_real($N, 12345);
+
+                let expected = references::dct2_f64(&signal_generators::to_f64(&input));
+                let expected: Vec<_> = expected.iter().map(|&x| x as f32).collect();
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let result = dct::$dct2(&mut input);
+
+                approx::assert_f32_eq(result, &expected);
+
+                let input = signal_generators::noise_real($N, 12345);
+
+                let expected = references::dst2_f64(&signal_generators::to_f64(&input));
+                let expected: Vec<_> = expected.iter().map(|&x| x as f32).collect();
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let result = dct::$dst2(&mut input);
+
+                approx::assert_f32_eq(result, &expected);
+
+                let input: [_; $N] = signal_generators::noise_real($N, 12345)
+                    .try_into()
+                    .unwrap();
+
+                let mut data = input;
+                let recovered = dct::$dct3(dct::$dct2(&mut data));
+                approx::assert_f32_eq(recovered, &input);
+
+                let mut data = input;
+                let recovered = dct::$dst3(dct::$dst2(&mut data));
+                approx::assert_f32_eq(recovered, &input);
+
+                let input = signal_generators::noise_real($N, 12345);
+                let input = signal_generators::to_f64(&input);
+
+                let expected = references::dct2_f64(&input);
+                let input: [_; $N] = input.try_into().unwrap();
+                let mut data = input;
+                let result = dct::$dct2_f64(&mut data);
+                approx::assert_f64_eq(result, &expected);
+
+                let recovered = dct::$dct3_f64(result);
+                approx::assert_f64_eq(recovered, &input);
+            }
+        )*
+    };
+}
+
+dct_tests! {
+    size_2: 2 => (dct2_2, dct3_2, dst2_2, dst3_2, dct2_2_f64, dct3_2_f64),
+    size_4: 4 => (dct2_4, dct3_4, dst2_4, dst3_4, dct2_4_f64, dct3_4_f64),
+    size_8: 8 => (dct2_8, dct3_8, dst2_8, dst3_8, dct2_8_f64, dct3_8_f64),
+    size_64: 64 => (dct2_64, dct3_64, dst2_64, dst3_64, dct2_64_f64, dct3_64_f64),
+    size_1024: 1024 => (dct2_1024, dct3_1024, dst2_1024, dst3_1024, dct2_1024_f64, dct3_1024_f64),
+}
+
+#[test]
+fn largest_size() {
+    // The twiddle factors of the largest size are finer than the sine table,
+    // so check a few coefficients against their definition.
+    use std::f64::consts::PI;
+
+    let input = signal_generators::noise_real(32768, 12345);
+    let mut data: [_; 32768] = input.clone().try_into().unwrap();
+    let result = dct::dct2_32768(&mut data);
+
+    for k in [1, 3, 1000, 16383, 16384, 16385, 32767] {
+        let expected: f64 = input
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| x as f64 * (PI * (k * (2 * i + 1)) as f64 / 65536.).cos())
+            .sum();
+        assert!(
+            (result[k] as f64 - expected).abs() < 0.05,
+            "{k}: {} != {expected}",
+            result[k]
+        );
+    }
+
+    let recovered = dct::dct3_32768(result);
+    approx::assert_f32_eq(recovered, &input);
+}
@@ -0,0 +1,81 @@
+use microfft::test_utils::*;
+use microfft::{complex, inverse, Complex32, Complex64};
+
+fn to_rows<const W: usize, const H: usize>(input: &[Complex32]) -> [[Complex32; W]; H] {
+    let mut rows = [[Complex32::default(); W]; H];
+    for (row, chunk) in rows.iter_mut().zip(input.chunks(W)) {
+        row.copy_from_slice(chunk);
+    }
+    rows
+}
+
+fn flatten<T: Copy, const W: usize>(rows: &[[T; W]]) -> Vec<T> {
+    rows.iter().flatten().copied().collect()
+}
+
+macro_rules! fft2d_tests {
+    ( $( $name:ident: $W:expr, $H:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_complex($W * $H, 12345);
+
+                let expected = references::rust_fft_2d(&input, $W);
+                let mut input = to_rows::<$W, $H>(&input);
+                let result = complex::fft2d(&mut input);
+
+                approx::assert_complex_eq(&flatten(result), &expected);
+
+                let input = signal_generators::noise_complex($W * $H, 12345);
+                let input = to_rows::<$W, $H>(&input);
+
+                let mut data = input;
+                let recovered = inverse::ifft2d(complex::fft2d(&mut data));
+
+                approx::assert_complex_eq(&flatten(recovered), &flatten(&input));
+
+                let input = signal_generators::noise_complex($W * $H, 12345);
+                let input = to_rows::<$W, $H>(&input);
+                let mut input = input.map(|row| row.map(|c| Complex64::new(c.re.into(), c.im.into())));
+                let original = input;
+
+                let spectrum = complex::fft2d_f64(&mut input);
+                for (i, row) in spectrum.iter().enumerate() {
+                    for (j, value) in row.iter().enumerate() {
+                        let expected: Complex64 = original
+                            .iter()
+                            .enumerate()
+                            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, v)| (x, y, v)))
+                            .map(|(x, y, v)| {
+                                let phase = -2. * std::f64::consts::PI
+                                    * ((i * y) as f64 / $H as f64 + (j * x) as f64 / $W as f64);
+                                v * Complex64::new(phase.cos(), phase.sin())
+                            })
+                            .sum();
+                        assert!(approx::complex64_eq(*value, expected, 1e-9), "{value} !~ {expected}");
+                    }
+                }
+
+                let recovered = inverse::ifft2d_f64(spectrum);
+                approx::assert_complex64_eq(&flatten(recovered), &flatten(&original));
+            }
+        )*
+    };
+}
+
+fft2d_tests! {
+    size_2x2: 2, 2,
+    size_32x32: 32, 32,
+    size_64x16: 64, 16,
+    size_4x128: 4, 128,
+    size_12x20: 12, 20,
+}
+
+#[test]
+fn impulse() {
+    let mut input = [[Complex32::default(); 8]; 4];
+    input[0][0] = Complex32::new(1., 0.);
+
+    let result = complex::fft2d(&mut input);
+    assert!(flatten(result).iter().all(|&c| c == Complex32::new(1., 0.)));
+}
@@ -0,0 +1,154 @@
+use std::convert::TryInto;
+
+use microfft::test_utils::*;
+
+macro_rules! cfft_q15_tests {
+    ( $( $name:ident: ($N:expr, $cfft_name:ident, $ifft_name:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_complex($N, 12345);
+                let input = fixed_point::to_complex_q15(&input);
+                let reference = fixed_point::from_complex_q15(&input, 0);
+
+                let expected = references::rust_fft(&reference);
+                let mut buf: [_; $N] = input.clone().try_into().unwrap();
+                let (result, forward_exponent) = microfft::complex::$cfft_name(&mut buf);
+                let result = fixed_point::from_complex_q15(result, forward_exponent);
+                fixed_point::assert_complex_close(&result, &expected, 0.01);
+
+                // The inverse transform works on the fixed-point values as they
+                // are, so the block exponents of both transforms add up.
+                let (recovered, exponent) = microfft::inverse::$ifft_name(&mut buf);
+                let recovered = fixed_point::from_complex_q15(recovered, forward_exponent + exponent);
+                fixed_point::assert_complex_close(&recovered, &reference, 0.02);
+            }
+        )*
+    };
+}
+
+cfft_q15_tests! {
+    cfft_2_q15: (2, cfft_2_q15, ifft_2_q15),
+    cfft_4_q15: (4, cfft_4_q15, ifft_4_q15),
+    cfft_16_q15: (16, cfft_16_q15, ifft_16_q15),
+    cfft_128_q15: (128, cfft_128_q15, ifft_128_q15),
+    cfft_1024_q15: (1024, cfft_1024_q15, ifft_1024_q15),
+}
+
+macro_rules! cfft_q31_tests {
+    ( $( $name:ident: ($N:expr, $cfft_name:ident, $ifft_name:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_complex($N, 12345);
+                let input = fixed_point::to_complex_q31(&input);
+                let reference = fixed_point::from_complex_q31(&input, 0);
+
+                let expected = references::rust_fft(&reference);
+                let mut buf: [_; $N] = input.clone().try_into().unwrap();
+                let (result, forward_exponent) = microfft::complex::$cfft_name(&mut buf);
+                let result = fixed_point::from_complex_q31(result, forward_exponent);
+                fixed_point::assert_complex_close(&result, &expected, 0.0001);
+
+                // The inverse transform works on the fixed-point values as they
+                // are, so the block exponents of both transforms add up.
+                let (recovered, exponent) = microfft::inverse::$ifft_name(&mut buf);
+                let recovered = fixed_point::from_complex_q31(recovered, forward_exponent + exponent);
+                fixed_point::assert_complex_close(&recovered, &reference, 0.0001);
+            }
+        )*
+    };
+}
+
+cfft_q31_tests! {
+    cfft_2_q31: (2, cfft_2_q31, ifft_2_q31),
+    cfft_4_q31: (4, cfft_4_q31, ifft_4_q31),
+    cfft_16_q31: (16, cfft_16_q31, ifft_16_q31),
+    cfft_128_q31: (128, cfft_128_q31, ifft_128_q31),
+    cfft_1024_q31: (1024, cfft_1024_q31, ifft_1024_q31),
+}
+
+macro_rules! rfft_q15_tests {
+    ( $( $name:ident: ($N:expr, $rfft_name:ident, $irfft_name:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_real($N, 12345);
+                let input = fixed_point::to_q15(&input);
+                let reference = fixed_point::from_q15(&input, 0);
+
+                let expected = references::real_fft(&reference);
+                let mut buf: [_; $N] = input.clone().try_into().unwrap();
+                let (result, forward_exponent) = microfft::real::$rfft_name(&mut buf);
+                let spectrum = fixed_point::from_complex_q15(result, forward_exponent);
+
+                // The real-valued coefficient at the Nyquist frequency
+                // is packed into the imaginary part of the DC bin.
+                let mut unpacked = spectrum.clone();
+                unpacked[0].im = 0.0;
+                unpacked.push(spectrum[0].im.into());
+                fixed_point::assert_complex_close(&unpacked, &expected, 0.01);
+
+                let (recovered, exponent) = microfft::inverse_real::$irfft_name(result);
+                let recovered = fixed_point::from_q15(recovered, forward_exponent + exponent);
+                fixed_point::assert_f32_close(&recovered, &reference, 0.02);
+            }
+        )*
+    };
+}
+
+rfft_q15_tests! {
+    rfft_2_q15: (2, rfft_2_q15, irfft_2_q15),
+    rfft_4_q15: (4, rfft_4_q15, irfft_4_q15),
+    rfft_16_q15: (16, rfft_16_q15, irfft_16_q15),
+    rfft_128_q15: (128, rfft_128_q15, irfft_128_q15),
+    rfft_1024_q15: (1024, rfft_1024_q15, irfft_1024_q15),
+}
+
+macro_rules! rfft_q31_tests {
+    ( $( $name:ident: ($N:expr, $rfft_name:ident, $irfft_name:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_real($N, 12345);
+                let input = fixed_point::to_q31(&input);
+                let reference = fixed_point::from_q31(&input, 0);
+
+                let expected = references::real_fft(&reference);
+                let mut buf: [_; $N] = input.clone().try_into().unwrap();
+                let (result, forward_exponent) = microfft::real::$rfft_name(&mut buf);
+                let spectrum = fixed_point::from_complex_q31(result, forward_exponent);
+
+                let mut unpacked = spectrum.clone();
+                unpacked[0].im = 0.0;
+                unpacked.push(spectrum[0].im.into());
+                fixed_point::assert_complex_close(&unpacked, &expected, 0.0001);
+
+                let (recovered, exponent) = microfft::inverse_real::$irfft_name(result);
+                let recovered = fixed_point::from_q31(recovered, forward_exponent + exponent);
+                fixed_point::assert_f32_close(&recovered, &reference, 0.0001);
+            }
+        )*
+    };
+}
+
+rfft_q31_tests! {
+    rfft_2_q31: (2, rfft_2_q31, irfft_2_q31),
+    rfft_4_q31: (4, rfft_4_q31, irfft_4_q31),
+    rfft_16_q31: (16, rfft_16_q31, irfft_16_q31),
+    rfft_128_q31: (128, rfft_128_q31, irfft_128_q31),
+    rfft_1024_q31: (1024, rfft_1024_q31, irfft_1024_q31),
+}
+
+#[test]
+fn full_scale_input_does_not_overflow() {
+    let mut input = [microfft::ComplexQ15::new(i16::MIN, i16::MIN); 64];
+    let (result, exponent) = microfft::complex::cfft_64_q15(&mut input);
+    let result = fixed_point::from_complex_q15(result, exponent);
+
+    let expected = microfft::Complex32::new(-64., -64.);
+    assert!((result[0] - expected).norm() < 0.01, "{}", result[0]);
+    for c in &result[1..] {
+        assert!(c.norm() < 0.01, "{c}");
+    }
+}
@@ -0,0 +1,86 @@
+use microfft::test_utils::*;
+use microfft::{inverse_real, real, Complex32, Complex64};
+use std::convert::TryInto;
+
+macro_rules! full_spectrum_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::ramp_real($N, 1.7, $N as f32);
+
+                let expected = references::real_fft(&input);
+                let input: [_; $N] = input.try_into().unwrap();
+                let mut output = [Complex32::default(); $N / 2 + 1];
+                let result = real::rfft_full(&input, &mut output);
+
+                assert_eq!(result[0].im, 0.0);
+                assert_eq!(result[$N / 2].im, 0.0);
+                approx::assert_complex_eq(result, &expected);
+
+                let input: [_; $N] = signal_generators::noise_real($N, 12345)
+                    .try_into()
+                    .unwrap();
+
+                let mut output = [Complex32::default(); $N / 2 + 1];
+                let full = real::rfft_full(&input, &mut output);
+
+                let mut packed = input;
+                let packed = real::rfft(&mut packed);
+                assert_eq!(full[0].re, packed[0].re);
+                assert_eq!(full[$N / 2].re, packed[0].im);
+                assert_eq!(full[1..($N / 2)], packed[1..]);
+
+                let input: [_; $N] = signal_generators::noise_real($N, 12345)
+                    .try_into()
+                    .unwrap();
+
+                let mut spectrum = [Complex32::default(); $N / 2 + 1];
+                real::rfft_full(&input, &mut spectrum);
+                let original = spectrum;
+
+                let mut output = [0.; $N];
+                let result = inverse_real::irfft_full::<$N>(&spectrum, &mut output);
+
+                approx::assert_f32_eq(result, &input);
+                assert_eq!(spectrum, original);
+
+                let input = signal_generators::noise_real($N, 12345);
+                let input = signal_generators::to_f64(&input);
+
+                let expected = references::real_fft_f64(&input);
+                let input: [_; $N] = input.try_into().unwrap();
+                let mut spectrum = [Complex64::default(); $N / 2 + 1];
+                let result = real::rfft_full_f64(&input, &mut spectrum);
+                approx::assert_complex64_eq(result, &expected);
+
+                let mut output = [0.; $N];
+                let result = inverse_real::irfft_full_f64::<$N>(&spectrum, &mut output);
+                approx::assert_f64_eq(result, &input);
+            }
+        )*
+    };
+}
+
+full_spectrum_tests! {
+    size_2: 2,
+    size_4: 4,
+    size_16: 16,
+    size_1024: 1024,
+    size_480: 480,
+    size_3840: 3840,
+}
+
+#[test]
+fn ignores_imaginary_dc_and_nyquist() {
+    let input: [_; 64] = signal_generators::noise_real(64, 12345).try_into().unwrap();
+
+    let mut spectrum = [Complex32::default(); 33];
+    real::rfft_full(&input, &mut spectrum);
+    spectrum[0].im = 1.0;
+    spectrum[32].im = -1.0;
+
+    let mut output = [0.; 64];
+    let result = inverse_real::irfft_full::<64>(&spectrum, &mut output);
+    approx::assert_f32_eq(result, &input);
+}
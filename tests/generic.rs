@@ -0,0 +1,71 @@
+use microfft::size::{CFftSize, RFftSize, Size};
+use microfft::test_utils::*;
+use microfft::{complex, inverse, inverse_real, real, Complex32};
+use std::convert::TryInto;
+
+/// A CFFT/IFFT roundtrip that is generic over the FFT size.
+fn complex_roundtrip<const N: usize>(input: &mut [Complex32; N]) -> &mut [Complex32; N]
+where
+    Size<N>: CFftSize,
+{
+    let spectrum = complex::cfft(input);
+    inverse::ifft(spectrum)
+}
+
+/// An RFFT/IRFFT roundtrip that is generic over the FFT size.
+fn real_roundtrip<const N: usize>(input: &mut [f32; N]) -> &mut [f32; N]
+where
+    Size<N>: RFftSize,
+{
+    let spectrum = real::rfft(input);
+    inverse_real::irfft::<N>(spectrum)
+}
+
+macro_rules! generic_tests {
+    ( $( $name:ident: ($N:expr, $cfft_N:ident, $rfft_N:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::ramp_complex($N, 1.7, $N as f32);
+                let mut expected: [_; $N] = input.clone().try_into().unwrap();
+                let expected = complex::$cfft_N(&mut expected);
+                let mut input: [_; $N] = input.try_into().unwrap();
+                assert_eq!(complex::cfft(&mut input), expected);
+
+                let input = signal_generators::ramp_real($N, 1.7, $N as f32);
+                let mut expected: [_; $N] = input.clone().try_into().unwrap();
+                let expected = real::$rfft_N(&mut expected);
+                let mut input: [_; $N] = input.try_into().unwrap();
+                assert_eq!(real::rfft(&mut input), expected);
+
+                let input = signal_generators::noise_complex($N, 12345);
+                let mut data: [_; $N] = input.clone().try_into().unwrap();
+                approx::assert_complex_eq(complex_roundtrip(&mut data), &input);
+
+                let input = signal_generators::noise_real($N, 12345);
+                let mut data: [_; $N] = input.clone().try_into().unwrap();
+                approx::assert_f32_eq(real_roundtrip(&mut data), &input);
+            }
+        )*
+    };
+}
+
+generic_tests! {
+    size_2: (2, cfft_2, rfft_2),
+    size_16: (16, cfft_16, rfft_16),
+    size_256: (256, cfft_256, rfft_256),
+    size_480: (480, cfft_480, rfft_480),
+    size_4096: (4096, cfft_4096, rfft_4096),
+}
+
+#[test]
+fn f64() {
+    let input = signal_generators::ramp_complex(64, 1.7, 64.);
+    let input = signal_generators::to_complex64(&input);
+
+    let expected = references::rust_fft_f64(&input);
+    let mut input: [_; 64] = input.try_into().unwrap();
+    let result = complex::cfft_f64(&mut input);
+
+    approx::assert_complex64_eq(result, &expected);
+}
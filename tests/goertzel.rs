@@ -0,0 +1,146 @@
+use microfft::goertzel::{dft_bin, Goertzel, GoertzelBank};
+use microfft::test_utils::*;
+use microfft::{real, Complex32, Complex64, RealSpectrum};
+use std::convert::TryInto;
+
+/// The DFT of `input` at the (possibly non-integer) `bin`.
+fn reference_bin(input: &[f64], bin: f64) -> Complex64 {
+    let n = input.len() as f64;
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let phase = -2. * std::f64::consts::PI * bin * i as f64 / n;
+            x * Complex64::new(phase.cos(), phase.sin())
+        })
+        .sum()
+}
+
+macro_rules! goertzel_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::to_f64(&signal_generators::noise_real($N, 12345));
+
+                let mut packed: [_; $N] = input.clone().try_into().unwrap();
+                let spectrum = RealSpectrum::new(real::rfft_f64(&mut packed));
+
+                for k in 0..=$N / 2 {
+                    let bin = dft_bin(&input, k as f64);
+                    let expected = spectrum.bin(k);
+                    assert!(approx::complex64_eq(bin, expected, 1e-9), "{k}: {bin} !~ {expected}");
+                }
+
+                let input = signal_generators::noise_real($N, 12345);
+
+                let mut packed: [_; $N] = input.clone().try_into().unwrap();
+                let spectrum = RealSpectrum::new(real::rfft(&mut packed));
+
+                let tolerance = 5e-5 * $N as f32;
+                for k in 0..=$N / 2 {
+                    let bin = dft_bin(&input, k as f32);
+                    let expected = spectrum.bin(k);
+                    assert!(approx::complex_eq(bin, expected, tolerance), "{k}: {bin} !~ {expected}");
+                }
+
+                let input = signal_generators::noise_real(3 * $N, 12345);
+                let mut filter = Goertzel::new(1.5, $N);
+
+                let results: Vec<_> = input.iter().filter_map(|x| filter.push(*x)).collect();
+
+                assert_eq!(results.len(), 3);
+                assert_eq!(filter.count(), 0);
+                for (block, result) in input.chunks($N).zip(results) {
+                    let expected = dft_bin(block, 1.5);
+                    assert!(approx::complex_eq(result, expected, 1e-3), "{result} !~ {expected}");
+                }
+            }
+        )*
+    };
+}
+
+goertzel_tests! {
+    size_4: 4,
+    size_16: 16,
+    size_64: 64,
+    size_1024: 1024,
+}
+
+#[test]
+fn non_integer_bins() {
+    let input = signal_generators::to_f64(&signal_generators::noise_real(100, 12345));
+
+    for bin in [0.25, 1.5, 7.3, 33.33, 49.9] {
+        let result = dft_bin(&input, bin);
+        let expected = reference_bin(&input, bin);
+        assert!(
+            approx::complex64_eq(result, expected, 1e-9),
+            "{bin}: {result} !~ {expected}"
+        );
+    }
+}
+
+#[test]
+fn partial_block() {
+    let input = signal_generators::to_f64(&signal_generators::noise_real(64, 12345));
+    let mut filter = Goertzel::new(5.5, 64);
+    for x in &input[..40] {
+        assert_eq!(filter.push(*x), None);
+    }
+
+    // missing samples are treated as zero
+    let mut padded = input[..40].to_vec();
+    padded.resize(64, 0.);
+    let expected = reference_bin(&padded, 5.5);
+    assert!(approx::complex64_eq(filter.result(), expected, 1e-9));
+    assert!(approx::f64_eq(filter.power(), expected.norm_sqr(), 1e-9));
+
+    filter.reset();
+    assert_eq!(filter.count(), 0);
+    assert_eq!(filter.result(), Complex64::new(0., 0.));
+}
+
+#[test]
+fn power() {
+    let input = signal_generators::sine_real(256, 1000., 8000.);
+    let mut filter = Goertzel::from_frequency(1000., 8000., 256);
+    for x in &input[..255] {
+        filter.push(*x);
+    }
+
+    let power = filter.power();
+    let expected = filter.result().norm_sqr();
+    assert!(
+        (power - expected).abs() / expected < 1e-4,
+        "{power} !~ {expected}"
+    );
+}
+
+#[test]
+fn bank() {
+    let input = signal_generators::noise_real(200, 12345);
+    let bins = [0., 3., 12.5, 99.];
+    let mut bank = GoertzelBank::new(bins, 100);
+
+    let results: Vec<_> = input.iter().filter_map(|x| bank.push(*x)).collect();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(bank.filters().len(), 4);
+    for (block, result) in input.chunks(100).zip(results) {
+        for (bin, value) in bins.iter().zip(result) {
+            let expected = dft_bin(block, *bin);
+            assert!(
+                approx::complex_eq(value, expected, 1e-3),
+                "{value} !~ {expected}"
+            );
+        }
+    }
+
+    bank.push(1.);
+    assert_eq!(bank.powers(), [1.; 4]);
+    assert!(bank
+        .results()
+        .iter()
+        .all(|c| approx::complex_eq(*c, Complex32::new(1., 0.), 1e-6)));
+}
@@ -0,0 +1,49 @@
+use microfft::hartley;
+use microfft::test_utils::*;
+use std::convert::TryInto;
+
+macro_rules! dht_tests {
+    ( $( $name:ident: $N:expr => ($dht:ident, $dht_f64:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_real($N, 12345);
+
+                let expected = references::dht_f64(&signal_generators::to_f64(&input));
+                let expected: Vec<_> = expected.iter().map(|&x| x as f32).collect();
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let result = hartley::$dht(&mut input);
+
+                approx::assert_f32_eq(result, &expected);
+
+                let input: [_; $N] = signal_generators::noise_real($N, 12345)
+                    .try_into()
+                    .unwrap();
+
+                let mut data = input;
+                let result = hartley::$dht(hartley::$dht(&mut data));
+                let result: Vec<_> = result.iter().map(|x| x / $N as f32).collect();
+                approx::assert_f32_eq(&result, &input);
+
+                let input = signal_generators::noise_real($N, 12345);
+                let input = signal_generators::to_f64(&input);
+
+                let expected = references::dht_f64(&input);
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let result = hartley::$dht_f64(&mut input);
+
+                approx::assert_f64_eq(result, &expected);
+            }
+        )*
+    };
+}
+
+dht_tests! {
+    size_2: 2 => (dht_2, dht_2_f64),
+    size_4: 4 => (dht_4, dht_4_f64),
+    size_8: 8 => (dht_8, dht_8_f64),
+    size_64: 64 => (dht_64, dht_64_f64),
+    size_1024: 1024 => (dht_1024, dht_1024_f64),
+    size_12: 12 => (dht_12, dht_12_f64),
+    size_480: 480 => (dht_480, dht_480_f64),
+}
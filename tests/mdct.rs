@@ -0,0 +1,113 @@
+use microfft::mdct;
+use microfft::test_utils::*;
+use microfft::window::{self, Window};
+use std::convert::TryInto;
+
+macro_rules! mdct_tests {
+    ( $( $name:ident: $N:expr => ($mdct:ident, $imdct:ident, $mdct_f64:ident, $imdct_f64:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_real($N, 12345);
+
+                let expected = references::mdct_f64(&signal_generators::to_f64(&input));
+                let expected: Vec<_> = expected.iter().map(|&x| x as f32).collect();
+                let input: [_; $N] = input.try_into().unwrap();
+                let mut output = [0.; $N / 2];
+                let result = mdct::$mdct(&input, &mut output);
+
+                approx::assert_f32_eq(result, &expected);
+
+                let input = signal_generators::noise_real($N / 2, 12345);
+
+                let expected = references::imdct_f64(&signal_generators::to_f64(&input));
+                let expected: Vec<_> = expected.iter().map(|&x| x as f32).collect();
+                let input: [_; $N / 2] = input.try_into().unwrap();
+                let mut output = [0.; $N];
+                let result = mdct::$imdct(&input, &mut output);
+
+                approx::assert_f32_eq(result, &expected);
+
+                let input = signal_generators::noise_real($N, 12345);
+                let input = signal_generators::to_f64(&input);
+
+                let expected = references::mdct_f64(&input);
+                let input: [_; $N] = input.try_into().unwrap();
+                let mut coefficients = [0.; $N / 2];
+                let result = mdct::$mdct_f64(&input, &mut coefficients);
+                approx::assert_f64_eq(result, &expected);
+
+                let expected = references::imdct_f64(result);
+                let mut output = [0.; $N];
+                let result = mdct::$imdct_f64(&coefficients, &mut output);
+                approx::assert_f64_eq(result, &expected);
+
+                let mut sine = [0.; $N];
+                Window::Sine.fill(&mut sine);
+                let mut kbd = [0.; $N];
+                Window::Kbd(4.).fill(&mut kbd);
+
+                for window in [sine, kbd] {
+                    let signal = signal_generators::noise_real($N * 4, 12345);
+                    let mut overlap = [0.; $N / 2];
+                    let mut output = Vec::new();
+
+                    for start in (0..=($N * 4 - $N)).step_by($N / 2) {
+                        let mut block: [_; $N] =
+                            signal[start..(start + $N)].try_into().unwrap();
+                        window::apply(&mut block, &window);
+
+                        let mut coefficients = [0.; $N / 2];
+                        mdct::$mdct(&block, &mut coefficients);
+                        let block = mdct::$imdct(&coefficients, &mut block);
+                        window::apply(block, &window);
+
+                        let mut samples = [0.; $N / 2];
+                        mdct::overlap_add(block, &mut overlap, &mut samples);
+                        output.extend_from_slice(&samples);
+                    }
+
+                    approx::assert_f32_eq(
+                        &output[($N / 2)..],
+                        &signal[($N / 2)..($N * 4 - $N / 2)],
+                    );
+                }
+            }
+        )*
+    };
+}
+
+mdct_tests! {
+    size_4: 4 => (mdct_4, imdct_4, mdct_4_f64, imdct_4_f64),
+    size_8: 8 => (mdct_8, imdct_8, mdct_8_f64, imdct_8_f64),
+    size_16: 16 => (mdct_16, imdct_16, mdct_16_f64, imdct_16_f64),
+    size_256: 256 => (mdct_256, imdct_256, mdct_256_f64, imdct_256_f64),
+    size_2048: 2048 => (mdct_2048, imdct_2048, mdct_2048_f64, imdct_2048_f64),
+}
+
+#[test]
+fn largest_size() {
+    let input = signal_generators::noise_real(32768, 12345);
+    let input: [_; 32768] = input.try_into().unwrap();
+
+    let mut coefficients = [0.; 16384];
+    mdct::mdct_32768(&input, &mut coefficients);
+
+    // Check a few coefficients against their definition.
+    use std::f64::consts::PI;
+    for k in [0, 1, 4097, 8191, 16383] {
+        let expected: f64 = input
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| {
+                let t = (i as f64 + 0.5 + 8192.) * (k as f64 + 0.5);
+                x as f64 * (2. * PI / 32768. * t).cos()
+            })
+            .sum();
+        assert!(
+            (coefficients[k] as f64 - expected).abs() < 0.05,
+            "{k}: {} != {expected}",
+            coefficients[k]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use microfft::multitaper::{self, Multitaper};
+use microfft::scaling::WindowScaling;
+use microfft::test_utils::signal_generators;
+use microfft::window::{self, Window};
+use std::f64::consts::PI;
+
+macro_rules! dpss_tests {
+    ( $( $name:ident: $N:expr, $K:expr, $NW:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut tapers = [[0f64; $N]; $K];
+                multitaper::dpss($NW, &mut tapers);
+
+                for i in 0..$K {
+                    for j in 0..$K {
+                        let dot: f64 = tapers[i].iter().zip(&tapers[j]).map(|(a, b)| a * b).sum();
+                        let expected = if i == j { 1. } else { 0. };
+                        assert!((dot - expected).abs() < 1e-10, "{i}, {j}: {dot}");
+                    }
+                }
+
+                // the tapers are eigenvectors of the sinc matrix, with
+                // their concentrations as eigenvalues
+                let mut tapers = [[0f64; $N]; $K];
+                let concentrations = multitaper::dpss($NW, &mut tapers);
+                let w = $NW / $N as f64;
+
+                for (taper, lambda) in tapers.iter().zip(concentrations) {
+                    for n in 0..$N {
+                        let av: f64 = (0..$N)
+                            .map(|m| {
+                                let d = n as f64 - m as f64;
+                                let a = if n == m { 2. * w } else { (2. * PI * w * d).sin() / (PI * d) };
+                                a * taper[m]
+                            })
+                            .sum();
+                        assert!((av - lambda * taper[n]).abs() < 1e-9, "{av} !~ {}", lambda * taper[n]);
+                    }
+                }
+
+                for pair in concentrations.windows(2) {
+                    assert!(pair[0] > pair[1]);
+                }
+                assert!(concentrations[0] < 1.);
+
+                let mut tapers = [[0f64; $N]; $K];
+                multitaper::dpss($NW, &mut tapers);
+
+                for (k, taper) in tapers.iter().enumerate() {
+                    let sign = if k % 2 == 0 { 1. } else { -1. };
+                    for n in 0..$N {
+                        assert!((taper[n] - sign * taper[$N - 1 - n]).abs() < 1e-10);
+                    }
+                    if k % 2 == 0 {
+                        assert!(taper.iter().sum::<f64>() > 0.);
+                    }
+                }
+
+                let mut tapers = [[0f64; $N]; $K];
+                let concentrations = multitaper::dpss($NW, &mut tapers);
+                let mut tapers_f32 = [[0f32; $N]; $K];
+                assert_eq!(multitaper::dpss($NW, &mut tapers_f32), concentrations);
+
+                for (a, b) in tapers.iter().zip(&tapers_f32) {
+                    for (a, b) in a.iter().zip(b) {
+                        assert_eq!(*a as f32, *b);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+dpss_tests! {
+    size_16: 16, 3, 2.,
+    size_64: 64, 4, 2.5,
+    size_256: 256, 7, 4.,
+}
+
+#[test]
+fn single_taper_matches_window_scaling() {
+    // a single, unnormalized taper results in the PSD of a windowed block
+    let input = signal_generators::to_f64(&signal_generators::noise_real(128, 12345));
+    let signal: [f64; 128] = input.clone().try_into().unwrap();
+
+    let mut taper = [[0.; 128]];
+    Window::Hann.fill(&mut taper[0]);
+    let estimator = Multitaper::new(&taper, [1.]);
+    let mut psd = [0.; 65];
+    estimator.psd(&signal, 100., &mut psd);
+
+    let mut x = signal;
+    window::apply(&mut x, &taper[0]);
+    let spectrum = microfft::real::rfft_128_f64(&mut x);
+    let scaling = WindowScaling::new(Window::Hann, 128);
+    for (a, b) in psd
+        .iter()
+        .zip(scaling.power_spectral_density(spectrum, 100.))
+    {
+        assert!((a - b).abs() < 1e-12 * b.max(1.), "{a} !~ {b}");
+    }
+}
+
+#[test]
+fn white_noise() {
+    // the PSD of uniform white noise in [-1, 1] is flat, and integrates to
+    // its mean square of 1/3
+    let sample_rate = 1000.;
+    let signal: [f32; 4096] = signal_generators::noise_real(4096, 54321)
+        .try_into()
+        .unwrap();
+
+    let mut tapers = Box::new([[0f32; 4096]; 7]);
+    let concentrations = multitaper::dpss(4., &mut tapers);
+    let estimator = Multitaper::new(&tapers, concentrations);
+
+    let mut psd = [0f32; 2049];
+    estimator.psd(&signal, sample_rate, &mut psd);
+    let mut spectra = Box::new([[0f32; 2049]; 7]);
+    let mut adaptive = [0f32; 2049];
+    estimator.adaptive_psd(&signal, sample_rate, &mut spectra, &mut adaptive);
+
+    let expected = 2. / 3. / sample_rate;
+    for psd in [psd, adaptive] {
+        let total: f32 = psd.iter().sum::<f32>() * sample_rate / 4096.;
+        assert!((total - 1. / 3.).abs() < 0.01, "{total}");
+
+        // with 7 tapers, the estimates of the individual bins fluctuate
+        // much less than a periodogram
+        let mean_error = psd[1..2048]
+            .iter()
+            .map(|p| (p - expected).abs())
+            .sum::<f32>()
+            / 2047.
+            / expected;
+        assert!(mean_error < 0.4, "{mean_error}");
+    }
+}
+
+#[test]
+fn adaptive_weighting_reduces_leakage() {
+    // next to a strong tone, the less concentrated tapers leak its power
+    // into the surrounding bins, which the adaptive weights suppress
+    let noise = signal_generators::to_f64(&signal_generators::noise_real(512, 12345));
+    let signal: [f64; 512] =
+        core::array::from_fn(|i| 1000. * (2. * PI * 100.25 * i as f64 / 512.).sin() + noise[i]);
+
+    let mut tapers = [[0f64; 512]; 8];
+    let concentrations = multitaper::dpss(4., &mut tapers);
+    let estimator = Multitaper::new(&tapers, concentrations);
+
+    let mut psd = [0.; 257];
+    estimator.psd(&signal, 1., &mut psd);
+    let mut spectra = [[0.; 257]; 8];
+    let mut adaptive = [0.; 257];
+    estimator.adaptive_psd(&signal, 1., &mut spectra, &mut adaptive);
+
+    // the PSD of the noise is `2/3` per unit of frequency
+    let noise_level = 2. / 3.;
+    let far: f64 = adaptive[150..250].iter().sum::<f64>() / 100.;
+    let far_fixed: f64 = psd[150..250].iter().sum::<f64>() / 100.;
+    assert!((far - noise_level).abs() < 0.2 * noise_level, "{far}");
+    assert!(far_fixed > 10. * noise_level, "{far_fixed}");
+
+    // both contain (most of) the power of the tone
+    let power = |psd: &[f64]| psd[90..=110].iter().sum::<f64>() / 512.;
+    assert!((power(&psd) - 500000.).abs() < 0.01 * 500000.);
+    assert!((power(&adaptive) - 500000.).abs() < 0.05 * 500000.);
+}
+
+#[test]
+fn adaptive_zero_signal() {
+    let mut tapers = [[0f32; 64]; 3];
+    let concentrations = multitaper::dpss(2., &mut tapers);
+    let estimator = Multitaper::new(&tapers, concentrations);
+
+    let mut spectra = [[0f32; 33]; 3];
+    let mut psd = [1f32; 33];
+    estimator.adaptive_psd(&[0.; 64], 1., &mut spectra, &mut psd);
+    assert_eq!(psd, [0.; 33]);
+}
+
+#[test]
+#[should_panic(expected = "time-bandwidth product must be in (0, N/2)")]
+fn bandwidth_too_large() {
+    let mut tapers = [[0f32; 16]; 2];
+    let _ = multitaper::dpss(8., &mut tapers);
+}
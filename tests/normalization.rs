@@ -0,0 +1,102 @@
+use microfft::test_utils::*;
+use microfft::{complex, inverse, inverse_real, real, Complex32, Normalization};
+use std::convert::TryInto;
+
+const MODES: [Normalization; 4] = [
+    Normalization::None,
+    Normalization::Inverse,
+    Normalization::Forward,
+    Normalization::Orthonormal,
+];
+
+/// The expected scaling of the forward and inverse transforms for `mode`.
+fn scales(mode: Normalization, n: usize) -> (f32, f32) {
+    let n = n as f32;
+    match mode {
+        Normalization::None => (1., 1.),
+        Normalization::Inverse => (1., 1. / n),
+        Normalization::Forward => (1. / n, 1.),
+        Normalization::Orthonormal => (1. / n.sqrt(), 1. / n.sqrt()),
+    }
+}
+
+macro_rules! normalization_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_complex($N, 12345);
+                let reference = references::rust_fft(&input);
+
+                for mode in MODES {
+                    let (forward, inverse) = scales(mode, $N);
+
+                    let mut data: [_; $N] = input.clone().try_into().unwrap();
+                    let spectrum = complex::cfft_normalized(&mut data, mode);
+                    let expected: Vec<_> = reference.iter().map(|c| c * forward).collect();
+                    approx::assert_complex_eq(spectrum, &expected);
+
+                    let recovered = inverse::ifft_normalized(spectrum, mode);
+                    let expected: Vec<_> = input
+                        .iter()
+                        .map(|c| c * forward * inverse * $N as f32)
+                        .collect();
+                    approx::assert_complex_eq(recovered, &expected);
+                }
+
+                let input = signal_generators::noise_real($N, 12345);
+                let reference = references::real_fft(&input);
+
+                for mode in MODES {
+                    let (forward, inverse) = scales(mode, $N);
+
+                    let mut data: [_; $N] = input.clone().try_into().unwrap();
+                    let spectrum = real::rfft_normalized(&mut data, mode);
+                    let nyquist = spectrum[0].im;
+                    assert!(approx::f32_eq(nyquist, reference[$N / 2].re * forward, 0.005));
+
+                    let mut unpacked = spectrum.clone();
+                    unpacked[0].im = 0.;
+                    let expected: Vec<_> =
+                        reference[..$N / 2].iter().map(|c| c * forward).collect();
+                    approx::assert_complex_eq(&unpacked, &expected);
+
+                    let recovered = inverse_real::irfft_normalized::<$N>(spectrum, mode);
+                    let expected: Vec<_> = input
+                        .iter()
+                        .map(|x| x * forward * inverse * $N as f32)
+                        .collect();
+                    approx::assert_f32_eq(recovered, &expected);
+                }
+
+                let input = signal_generators::noise_complex($N, 12345);
+
+                let mut expected: [_; $N] = input.clone().try_into().unwrap();
+                let expected = inverse::ifft(&mut expected);
+                let mut data: [_; $N] = input.try_into().unwrap();
+                let result = inverse::ifft_normalized(&mut data, Normalization::default());
+
+                assert_eq!(result, expected);
+            }
+        )*
+    };
+}
+
+normalization_tests! {
+    size_2: 2,
+    size_16: 16,
+    size_256: 256,
+    size_480: 480,
+}
+
+#[test]
+fn orthonormal_preserves_energy() {
+    let input = signal_generators::noise_complex(64, 12345);
+    let energy: f32 = input.iter().map(Complex32::norm_sqr).sum();
+
+    let mut data: [_; 64] = input.try_into().unwrap();
+    let spectrum = complex::cfft_normalized(&mut data, Normalization::Orthonormal);
+    let spectrum_energy: f32 = spectrum.iter().map(Complex32::norm_sqr).sum();
+
+    assert!(approx::f32_eq(energy, spectrum_energy, 0.001));
+}
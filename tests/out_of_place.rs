@@ -0,0 +1,83 @@
+use microfft::test_utils::*;
+use microfft::{complex, inverse, inverse_real, real, Complex32, Complex64};
+use std::convert::TryInto;
+
+macro_rules! out_of_place_tests {
+    ( $( $name:ident: $N:expr => ($rfft_N_into:ident, $irfft_N_into:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input: [_; $N] = signal_generators::noise_complex($N, 12345)
+                    .try_into()
+                    .unwrap();
+                let original = input;
+
+                let mut output = [Complex32::default(); $N];
+                let result = complex::cfft_into(&input, &mut output);
+
+                let mut expected = input;
+                assert_eq!(result, complex::cfft(&mut expected));
+                assert_eq!(input, original);
+
+                let mut output = [Complex32::default(); $N];
+                let result = inverse::ifft_into(&input, &mut output);
+
+                let mut expected = input;
+                assert_eq!(result, inverse::ifft(&mut expected));
+                assert_eq!(input, original);
+
+                let input = signal_generators::to_complex64(&input);
+                let input: [_; $N] = input.try_into().unwrap();
+                let original = input;
+
+                let mut output = [Complex64::default(); $N];
+                let result = complex::cfft_into_f64(&input, &mut output);
+
+                let mut expected = input;
+                assert_eq!(result, complex::cfft_f64(&mut expected));
+                assert_eq!(input, original);
+
+                let input: [_; $N] = signal_generators::noise_real($N, 12345)
+                    .try_into()
+                    .unwrap();
+                let original = input;
+
+                let mut output = [Complex32::default(); $N / 2];
+                let result = real::rfft_into(&input, &mut output);
+
+                let mut expected = input;
+                assert_eq!(result, real::rfft(&mut expected));
+                assert_eq!(input, original);
+
+                let mut output = [Complex32::default(); $N / 2];
+                assert_eq!(real::$rfft_N_into(&input, &mut output), result);
+
+                let input: [_; $N / 2] = signal_generators::noise_complex($N / 2, 12345)
+                    .try_into()
+                    .unwrap();
+                let original = input;
+
+                let mut output = [0.; $N];
+                let result = inverse_real::irfft_into::<$N>(&input, &mut output);
+
+                let mut expected = input;
+                assert_eq!(result, inverse_real::irfft::<$N>(&mut expected));
+                assert_eq!(input, original);
+
+                let mut output = [0.; $N];
+                assert_eq!(inverse_real::$irfft_N_into(&input, &mut output), result);
+            }
+        )*
+    };
+}
+
+out_of_place_tests! {
+    size_2: 2 => (rfft_2_into, irfft_2_into),
+    size_4: 4 => (rfft_4_into, irfft_4_into),
+    size_16: 16 => (rfft_16_into, irfft_16_into),
+    size_128: 128 => (rfft_128_into, irfft_128_into),
+    size_1024: 1024 => (rfft_1024_into, irfft_1024_into),
+    size_12: 12 => (rfft_12_into, irfft_12_into),
+    size_480: 480 => (rfft_480_into, irfft_480_into),
+    size_3840: 3840 => (rfft_3840_into, irfft_3840_into),
+}
@@ -0,0 +1,68 @@
+use microfft::test_utils::*;
+use microfft::{inverse_real, real, Complex32, Complex64};
+use std::convert::TryInto;
+
+macro_rules! pair_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let a: [_; $N] = signal_generators::noise_real($N, 12345).try_into().unwrap();
+                let b: [_; $N] = signal_generators::sine_real($N, 3., $N as f32)
+                    .try_into()
+                    .unwrap();
+
+                let mut output = [Complex32::default(); $N];
+                let (spectrum_a, spectrum_b) = real::rfft_pair(&a, &b, &mut output);
+
+                let (mut expected_a, mut expected_b) = (a, b);
+                approx::assert_complex_eq(spectrum_a, real::rfft(&mut expected_a));
+                approx::assert_complex_eq(spectrum_b, real::rfft(&mut expected_b));
+
+                let a: [_; $N] = signal_generators::noise_real($N, 12345).try_into().unwrap();
+                let b: [_; $N] = signal_generators::noise_real($N, 54321).try_into().unwrap();
+
+                let mut spectra = [Complex32::default(); $N];
+                let _ = real::rfft_pair(&a, &b, &mut spectra);
+
+                let (mut recovered_a, mut recovered_b) = ([0.; $N], [0.; $N]);
+                inverse_real::irfft_pair(&mut spectra, &mut recovered_a, &mut recovered_b);
+
+                approx::assert_f32_eq(&recovered_a, &a);
+                approx::assert_f32_eq(&recovered_b, &b);
+
+                let a: [_; $N] = signal_generators::to_f64(&signal_generators::noise_real($N, 12345))
+                    .try_into()
+                    .unwrap();
+                let b: [_; $N] = signal_generators::to_f64(&signal_generators::noise_real($N, 54321))
+                    .try_into()
+                    .unwrap();
+
+                let mut spectra = [Complex64::default(); $N];
+                let (spectrum_a, spectrum_b) = real::rfft_pair_f64(&a, &b, &mut spectra);
+
+                let (mut expected_a, mut expected_b) = (a, b);
+                approx::assert_complex64_eq(spectrum_a, real::rfft_f64(&mut expected_a));
+                approx::assert_complex64_eq(spectrum_b, real::rfft_f64(&mut expected_b));
+
+                let (mut recovered_a, mut recovered_b) = ([0.; $N], [0.; $N]);
+                inverse_real::irfft_pair_f64(&mut spectra, &mut recovered_a, &mut recovered_b);
+
+                approx::assert_f64_eq(&recovered_a, &a);
+                approx::assert_f64_eq(&recovered_b, &b);
+            }
+        )*
+    };
+}
+
+pair_tests! {
+    size_2: 2,
+    size_4: 4,
+    size_8: 8,
+    size_16: 16,
+    size_128: 128,
+    size_4096: 4096,
+    size_12: 12,
+    size_20: 20,
+    size_960: 960,
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "radix-4")]
+
+use std::convert::TryInto;
+
+use microfft::test_utils::*;
+
+macro_rules! radix_4_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_complex($N, 12345);
+                let input = signal_generators::to_complex64(&input);
+
+                let expected = references::radix_2_fft_f64(&input);
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let result = microfft::complex::$name(&mut input);
+
+                approx::assert_complex64_eq(result, &expected);
+            }
+        )*
+    };
+}
+
+// Sizes that are an even and an odd power of two end the radix-4 recursion
+// with a size of 1 and 2, respectively.
+radix_4_tests! {
+    cfft_2_f64: 2,
+    cfft_4_f64: 4,
+    cfft_8_f64: 8,
+    cfft_16_f64: 16,
+    cfft_32_f64: 32,
+    cfft_64_f64: 64,
+    cfft_512_f64: 512,
+    cfft_1024_f64: 1024,
+    cfft_32768_f64: 32768,
+}
+
+#[test]
+fn single_precision() {
+    let input = signal_generators::noise_complex(2048, 12345);
+
+    let expected = references::radix_2_fft_f64(&signal_generators::to_complex64(&input));
+    let expected: Vec<_> = expected
+        .iter()
+        .map(|c| microfft::Complex32::new(c.re as f32, c.im as f32))
+        .collect();
+    let mut input: [_; 2048] = input.try_into().unwrap();
+    let result = microfft::complex::cfft_2048(&mut input);
+
+    approx::assert_complex_eq(result, &expected);
+}
+
+#[test]
+fn mixed_radix() {
+    // The power-of-two sub-CFFTs of the mixed-radix sizes use the radix-4
+    // kernel as well.
+    let input = signal_generators::noise_complex(480, 12345);
+
+    let expected = references::rust_fft(&input);
+    let mut input: [_; 480] = input.try_into().unwrap();
+    let result = microfft::complex::cfft_480(&mut input);
+
+    approx::assert_complex_eq(result, &expected);
+}
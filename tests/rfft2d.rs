@@ -0,0 +1,97 @@
+use microfft::test_utils::*;
+use microfft::{inverse_real, real, Complex32, Complex64};
+
+fn to_rows<const W: usize, const H: usize>(input: &[f32]) -> [[f32; W]; H] {
+    let mut rows = [[0.; W]; H];
+    for (row, chunk) in rows.iter_mut().zip(input.chunks(W)) {
+        row.copy_from_slice(chunk);
+    }
+    rows
+}
+
+fn flatten<T: Copy, const W: usize>(rows: &[[T; W]]) -> Vec<T> {
+    rows.iter().flatten().copied().collect()
+}
+
+/// The left `W/2 + 1` columns of the 2D CFFT of the real `input`.
+fn half_plane(input: &[f32], width: usize) -> Vec<Complex32> {
+    let input: Vec<_> = input.iter().map(|&x| Complex32::new(x, 0.)).collect();
+    references::rust_fft_2d(&input, width)
+        .chunks(width)
+        .flat_map(|row| row[..width / 2 + 1].to_vec())
+        .collect()
+}
+
+macro_rules! rfft2d_tests {
+    ( $( $name:ident: $W:expr, $H:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::noise_real($W * $H, 12345);
+
+                let expected = half_plane(&input, $W);
+                let input = to_rows::<$W, $H>(&input);
+                let mut output = [[Complex32::default(); $W / 2 + 1]; $H];
+                let result = real::rfft2d(&input, &mut output);
+
+                approx::assert_complex_eq(&flatten(result), &expected);
+
+                let input = signal_generators::noise_real($W * $H, 12345);
+                let input = to_rows::<$W, $H>(&input);
+
+                let mut spectrum = [[Complex32::default(); $W / 2 + 1]; $H];
+                let mut output = [[0.; $W]; $H];
+                let recovered = inverse_real::irfft2d::<$W, $H>(
+                    real::rfft2d(&input, &mut spectrum),
+                    &mut output,
+                );
+
+                approx::assert_f32_eq(&flatten(recovered), &flatten(&input));
+
+                let input = signal_generators::noise_real($W * $H, 12345);
+                let expected = signal_generators::to_complex64(&half_plane(&input, $W));
+                let input = to_rows::<$W, $H>(&input).map(|row| row.map(f64::from));
+
+                let mut spectrum = [[Complex64::default(); $W / 2 + 1]; $H];
+                let result = real::rfft2d_f64(&input, &mut spectrum);
+                for (value, expected) in flatten(result).iter().zip(&expected) {
+                    assert!(approx::complex64_eq(*value, *expected, 1e-3), "{value} !~ {expected}");
+                }
+
+                let mut output = [[0.; $W]; $H];
+                let recovered = inverse_real::irfft2d_f64::<$W, $H>(result, &mut output);
+                approx::assert_f64_eq(&flatten(recovered), &flatten(&input));
+            }
+        )*
+    };
+}
+
+rfft2d_tests! {
+    size_2x2: 2, 2,
+    size_32x32: 32, 32,
+    size_64x16: 64, 16,
+    size_8x128: 8, 128,
+    size_12x20: 12, 20,
+}
+
+#[test]
+fn nyquist_column() {
+    // Alternating columns only have energy at the horizontal Nyquist
+    // frequency, which ends up in the last column rather than the DC bin.
+    let mut input = [[0.; 8]; 4];
+    for row in input.iter_mut() {
+        for (x, value) in row.iter_mut().enumerate() {
+            *value = if x % 2 == 0 { 1. } else { -1. };
+        }
+    }
+
+    let mut output = [[Complex32::default(); 5]; 4];
+    let result = real::rfft2d(&input, &mut output);
+
+    for (y, row) in result.iter().enumerate() {
+        for (x, value) in row.iter().enumerate() {
+            let expected = if (x, y) == (4, 0) { 32. } else { 0. };
+            assert_eq!(*value, Complex32::new(expected, 0.));
+        }
+    }
+}
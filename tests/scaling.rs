@@ -0,0 +1,126 @@
+use microfft::real::{rfft, rfft_f64};
+use microfft::scaling::WindowScaling;
+use microfft::test_utils::signal_generators;
+use microfft::window::{self, Window};
+use std::f64::consts::PI;
+
+const WINDOWS: [Window; 5] = [
+    Window::Rectangular,
+    Window::Hann,
+    Window::BlackmanHarris,
+    Window::FlatTop,
+    Window::Kaiser(8.6),
+];
+
+macro_rules! scaling_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                // a sine of amplitude 3 centered on a bin has an RMS
+                // value of `3 / sqrt(2)` and a mean square of 4.5
+                let k = $N / 4;
+                for window in WINDOWS {
+                    let mut coefficients = [0.; $N];
+                    window.fill(&mut coefficients);
+                    let mut x: [f64; $N] =
+                        core::array::from_fn(|i| 3. * (2. * PI * (k * i) as f64 / $N as f64).sin());
+                    window::apply(&mut x, &coefficients);
+                    let spectrum = rfft_f64(&mut x);
+
+                    let scaling = WindowScaling::new(window, $N);
+                    let amplitudes: Vec<f64> = scaling.amplitude_spectrum(spectrum).collect();
+                    let powers: Vec<f64> = scaling.power_spectrum(spectrum).collect();
+                    assert_eq!(amplitudes.len(), $N / 2 + 1);
+                    assert!((amplitudes[k] - 3. / 2f64.sqrt()).abs() < 1e-3, "{window:?}");
+                    assert!((powers[k] - 4.5).abs() < 2e-3, "{window:?}");
+                }
+
+                // constant and alternating values are not doubled
+                for window in WINDOWS {
+                    let mut coefficients = [0.; $N];
+                    window.fill(&mut coefficients);
+                    let mut x: [f32; $N] =
+                        core::array::from_fn(|i| 0.5 + if i % 2 == 0 { 0.25 } else { -0.25 });
+                    window::apply(&mut x, &coefficients);
+                    let spectrum = rfft(&mut x);
+
+                    let scaling = WindowScaling::new(window, $N);
+                    let amplitudes: Vec<f32> = scaling.amplitude_spectrum(spectrum).collect();
+                    assert!((amplitudes[0] - 0.5).abs() < 1e-4, "{window:?}");
+                    assert!((amplitudes[$N / 2] - 0.25).abs() < 1e-4, "{window:?}");
+                }
+
+                // with a rectangular window, the PSD integrates to the mean
+                // square of the signal
+                let sample_rate = 1000.;
+                let input = signal_generators::to_f64(&signal_generators::noise_real($N, 12345));
+                let mean_square = input.iter().map(|x| x * x).sum::<f64>() / $N as f64;
+
+                let mut x: [f64; $N] = input.try_into().unwrap();
+                let spectrum = rfft_f64(&mut x);
+                let scaling = WindowScaling::new(Window::Rectangular, $N);
+                let psd = scaling.power_spectral_density(spectrum, sample_rate);
+                let total = psd.sum::<f64>() * sample_rate / $N as f64;
+
+                assert!((total - mean_square).abs() < 1e-9 * mean_square);
+            }
+        )*
+    };
+}
+
+scaling_tests! {
+    size_32: 32,
+    size_64: 64,
+    size_1024: 1024,
+}
+
+#[test]
+fn window_properties() {
+    let scaling = WindowScaling::new(Window::Rectangular, 64);
+    assert_eq!(scaling.fft_len(), 64);
+    assert_eq!(scaling.coherent_gain(), 1.);
+    assert_eq!(scaling.enbw(), 1.);
+
+    let scaling = WindowScaling::new(Window::Hann, 64);
+    assert!((scaling.coherent_gain() - 0.5).abs() < 1e-12);
+    assert!((scaling.enbw() - 1.5).abs() < 1e-12);
+}
+
+#[test]
+fn from_coefficients() {
+    for window in WINDOWS.into_iter().chain([Window::Sine, Window::Kbd(4.)]) {
+        let mut coefficients = [0f32; 256];
+        window.fill(&mut coefficients);
+
+        let expected = WindowScaling::new(window, 256);
+        let scaling = WindowScaling::from_coefficients(&coefficients);
+        assert_eq!(scaling.fft_len(), 256);
+        assert!((scaling.coherent_gain() - expected.coherent_gain()).abs() < 1e-6);
+        assert!((scaling.enbw() - expected.enbw()).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn flat_top_between_bins() {
+    // the flat-top window measures tones between bins within 0.2 %
+    let mut x: [f32; 256] =
+        core::array::from_fn(|i| (2. * PI * 20.5 * i as f64 / 256.).sin() as f32);
+    let mut flat_top = [0.; 256];
+    Window::FlatTop.fill(&mut flat_top);
+    window::apply(&mut x, &flat_top);
+    let spectrum = rfft(&mut x);
+
+    let scaling = WindowScaling::new(Window::FlatTop, 256);
+    let peak = scaling.amplitude_spectrum(spectrum).fold(0f32, f32::max);
+    assert!((peak - 0.5f32.sqrt()).abs() < 2e-3 * 0.5f32.sqrt());
+}
+
+#[test]
+#[should_panic(expected = "spectrum length must be half the window length")]
+fn length_mismatch() {
+    let mut x = [0f32; 64];
+    let spectrum = rfft(&mut x);
+    let scaling = WindowScaling::new(Window::Hann, 128);
+    let _ = scaling.power_spectrum(spectrum);
+}
@@ -1,5 +1,5 @@
 use microfft::test_utils::*;
-use microfft::Complex32;
+use microfft::{Complex32, Complex64};
 use std::convert::TryInto;
 
 // Macro to generate complex FFT round-trip tests for all signal types
@@ -65,6 +65,15 @@ cfft_tests! {
     cfft_32768: (32768, cfft_32768, ifft_32768),
 }
 
+cfft_tests! {
+    cfft_12: (12, cfft_12, ifft_12),
+    cfft_20: (20, cfft_20, ifft_20),
+    cfft_60: (60, cfft_60, ifft_60),
+    cfft_480: (480, cfft_480, ifft_480),
+    cfft_960: (960, cfft_960, ifft_960),
+    cfft_3840: (3840, cfft_3840, ifft_3840),
+}
+
 // Macro to generate real FFT round-trip tests for all signal types
 macro_rules! rfft_tests {
     ( $( $name:ident: ($N:expr, $rfft_name:ident, $irfft_name:ident), )* ) => {
@@ -127,3 +136,100 @@ rfft_tests! {
     rfft_16384: (16384, rfft_16384, irfft_16384),
     rfft_32768: (32768, rfft_32768, irfft_32768),
 }
+
+rfft_tests! {
+    rfft_12: (12, rfft_12, irfft_12),
+    rfft_20: (20, rfft_20, irfft_20),
+    rfft_60: (60, rfft_60, irfft_60),
+    rfft_480: (480, rfft_480, irfft_480),
+    rfft_960: (960, rfft_960, irfft_960),
+    rfft_3840: (3840, rfft_3840, irfft_3840),
+}
+
+// Macro to generate double-precision complex FFT round-trip tests
+macro_rules! cfft_f64_tests {
+    ( $( $name:ident: ($N:expr, $cfft_name:ident, $ifft_name:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let test_cases = [
+                    ("noise", signal_generators::noise_complex($N, 12345)),
+                    ("sine", signal_generators::sine_complex($N, 1.7, $N as f32)),
+                    ("pulse", signal_generators::single_pulse_complex($N)),
+                ];
+
+                for (test_name, input) in test_cases.iter() {
+                    let input = signal_generators::to_complex64(input);
+                    let original: [Complex64; $N] = input.try_into().unwrap();
+
+                    let mut buf = original.clone();
+                    let _ = microfft::complex::$cfft_name(&mut buf);
+                    let recovered = microfft::inverse::$ifft_name(&mut buf);
+
+                    for (i, (orig, recovered)) in original.iter().zip(recovered.iter()).enumerate() {
+                        assert!(
+                            approx::complex64_eq(*orig, *recovered, 1e-9),
+                            "Complex f64 FFT round-trip failed for {} signal at index {}: original={:?}, recovered={:?}",
+                            test_name,
+                            i,
+                            orig,
+                            recovered,
+                        );
+                    }
+                }
+            }
+        )*
+    };
+}
+
+cfft_f64_tests! {
+    cfft_2_f64: (2, cfft_2_f64, ifft_2_f64),
+    cfft_16_f64: (16, cfft_16_f64, ifft_16_f64),
+    cfft_256_f64: (256, cfft_256_f64, ifft_256_f64),
+    cfft_4096_f64: (4096, cfft_4096_f64, ifft_4096_f64),
+    cfft_32768_f64: (32768, cfft_32768_f64, ifft_32768_f64),
+}
+
+// Macro to generate double-precision real FFT round-trip tests
+macro_rules! rfft_f64_tests {
+    ( $( $name:ident: ($N:expr, $rfft_name:ident, $irfft_name:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let test_cases = [
+                    ("noise", signal_generators::noise_real($N, 12345)),
+                    ("sine", signal_generators::sine_real($N, 2.3, $N as f32)),
+                    ("pulse", signal_generators::single_pulse_real($N)),
+                ];
+
+                for (test_name, input) in test_cases.iter() {
+                    let input = signal_generators::to_f64(input);
+                    let original: [f64; $N] = input.try_into().unwrap();
+
+                    let mut buf = original.clone();
+                    let spectrum = microfft::real::$rfft_name(&mut buf);
+                    let recovered = microfft::inverse_real::$irfft_name(spectrum);
+
+                    for (i, (orig, recovered)) in original.iter().zip(recovered.iter()).enumerate() {
+                        assert!(
+                            approx::f64_eq(*orig, *recovered, 1e-9),
+                            "Real f64 FFT round-trip failed for {} signal at index {}: original={}, recovered={}",
+                            test_name,
+                            i,
+                            orig,
+                            recovered,
+                        );
+                    }
+                }
+            }
+        )*
+    };
+}
+
+rfft_f64_tests! {
+    rfft_2_f64: (2, rfft_2_f64, irfft_2_f64),
+    rfft_16_f64: (16, rfft_16_f64, irfft_16_f64),
+    rfft_256_f64: (256, rfft_256_f64, irfft_256_f64),
+    rfft_4096_f64: (4096, rfft_4096_f64, irfft_4096_f64),
+    rfft_32768_f64: (32768, rfft_32768_f64, irfft_32768_f64),
+}
@@ -0,0 +1,95 @@
+use microfft::test_utils::*;
+use microfft::{slice, Complex32, Error};
+
+const SIZES: [usize; 7] = [2, 4, 64, 480, 1024, 3840, 32768];
+
+#[test]
+fn cfft() {
+    for n in SIZES {
+        let mut input = signal_generators::ramp_complex(n, 1.7, n as f32);
+
+        let expected = references::rust_fft(&input);
+        let result = slice::cfft(&mut input).unwrap();
+
+        approx::assert_complex_eq(result, &expected);
+    }
+}
+
+#[test]
+fn rfft() {
+    for n in SIZES {
+        let mut input = signal_generators::ramp_real(n, 1.7, n as f32);
+
+        let expected = references::real_fft(&input);
+        let result = slice::rfft(&mut input).unwrap();
+
+        assert_eq!(result.len(), n / 2);
+        assert!(approx::f32_eq(result[0].im, expected[n / 2].re, 0.005));
+        result[0].im = 0.0;
+        approx::assert_complex_eq(result, &expected[..(n / 2)]);
+    }
+}
+
+#[test]
+fn roundtrip() {
+    for n in SIZES {
+        let input = signal_generators::noise_complex(n, 12345);
+        let mut data = input.clone();
+        slice::cfft(&mut data).unwrap();
+        let recovered = slice::ifft(&mut data).unwrap();
+        approx::assert_complex_eq(recovered, &input);
+
+        let input = signal_generators::noise_real(n, 12345);
+        let mut data = input.clone();
+        let spectrum = slice::rfft(&mut data).unwrap();
+        let recovered = slice::irfft(spectrum).unwrap();
+        approx::assert_f32_eq(recovered, &input);
+    }
+}
+
+#[test]
+fn f64() {
+    let input = signal_generators::ramp_complex(480, 1.7, 480.);
+    let mut input = signal_generators::to_complex64(&input);
+
+    let expected = references::rust_fft_f64(&input);
+    let result = slice::cfft_f64(&mut input).unwrap();
+
+    approx::assert_complex64_eq(result, &expected);
+}
+
+#[test]
+fn not_power_of_two() {
+    for n in [0, 3, 6, 9, 45, 100, 360, 720, 1000, 1440, 4097] {
+        let mut input = vec![Complex32::default(); n];
+        assert_eq!(slice::cfft(&mut input), Err(Error::UnsupportedSize));
+        assert_eq!(slice::ifft(&mut input), Err(Error::UnsupportedSize));
+
+        let mut input = vec![0.; n * 2 + 1];
+        assert_eq!(slice::rfft(&mut input), Err(Error::UnsupportedSize));
+    }
+
+    for n in [0, 3, 50, 4097] {
+        let mut input = vec![Complex32::default(); n];
+        assert_eq!(slice::irfft(&mut input), Err(Error::UnsupportedSize));
+    }
+}
+
+#[test]
+fn too_large() {
+    for n in [65536, 7680] {
+        let mut input = vec![Complex32::default(); n];
+        assert_eq!(slice::cfft(&mut input), Err(Error::TooLarge));
+
+        let mut input = vec![0.; n];
+        assert_eq!(slice::rfft(&mut input), Err(Error::TooLarge));
+    }
+}
+
+#[test]
+fn input_unmodified_on_error() {
+    let input = signal_generators::noise_complex(100, 12345);
+    let mut data = input.clone();
+    assert!(slice::cfft(&mut data).is_err());
+    assert_eq!(data, input);
+}
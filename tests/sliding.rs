@@ -0,0 +1,115 @@
+use microfft::sliding::SlidingDft;
+use microfft::test_utils::*;
+use microfft::{complex, Complex32, Complex64};
+use std::convert::TryInto;
+
+macro_rules! sliding_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let signal = signal_generators::noise_complex(3 * $N, 12345);
+
+                let window: [_; $N] = signal[..$N].try_into().unwrap();
+                let mut sdft = SlidingDft::from_window(&window);
+
+                for end in ($N + 1)..=(3 * $N) {
+                    sdft.push(signal[end - 1]);
+
+                    let mut expected: [_; $N] = signal[end - $N..end].try_into().unwrap();
+                    let expected = complex::cfft(&mut expected);
+                    let mut spectrum = [Complex32::default(); $N];
+                    approx::assert_complex_eq(sdft.spectrum(&mut spectrum), expected);
+                }
+
+                let signal = signal_generators::noise_real(2 * $N, 12345);
+
+                let mut sdft = SlidingDft::<f32, $N>::new();
+                for x in &signal {
+                    sdft.push(*x);
+                }
+
+                let window = signal[$N..]
+                    .iter()
+                    .map(|&x| Complex32::new(x, 0.))
+                    .collect::<Vec<_>>();
+                let expected = references::rust_fft(&window);
+                let bins: Vec<_> = (0..$N).map(|k| sdft.bin(k)).collect();
+                approx::assert_complex_eq(&bins, &expected);
+
+                let samples: Vec<_> = sdft.samples().collect();
+                assert_eq!(samples, window);
+
+                let signal = signal_generators::to_complex64(&signal_generators::noise_complex(2 * $N, 12345));
+
+                let window: [_; $N] = signal[..$N].try_into().unwrap();
+                let mut spectrum = window;
+                let _ = complex::cfft_f64(&mut spectrum);
+                let mut sdft = SlidingDft::from_spectrum(&window, &spectrum);
+                for x in &signal[$N..] {
+                    sdft.push(*x);
+                }
+
+                let mut expected: [_; $N] = signal[$N..].try_into().unwrap();
+                let expected = complex::cfft_f64(&mut expected);
+                let mut spectrum = [Complex64::default(); $N];
+                approx::assert_complex64_eq(sdft.spectrum(&mut spectrum), expected);
+            }
+        )*
+    };
+}
+
+sliding_tests! {
+    size_2: 2,
+    size_4: 4,
+    size_16: 16,
+    size_128: 128,
+    size_12: 12,
+    size_60: 60,
+}
+
+#[test]
+fn no_drift() {
+    // After many updates, the bins still match a freshly computed CFFT.
+    let signal = signal_generators::noise_complex(100_000, 12345);
+
+    let mut sdft = SlidingDft::<f32, 64>::new();
+    for x in &signal {
+        sdft.push(*x);
+    }
+
+    let mut expected: [_; 64] = signal[signal.len() - 64..].try_into().unwrap();
+    let expected = complex::cfft(&mut expected);
+    let mut spectrum = [Complex32::default(); 64];
+    approx::assert_complex_eq(sdft.spectrum(&mut spectrum), expected);
+}
+
+#[test]
+fn refresh() {
+    let signal = signal_generators::noise_complex(1000, 12345);
+
+    let mut sdft = SlidingDft::<f32, 32>::default();
+    for x in &signal {
+        sdft.push(*x);
+    }
+    let mut before = [Complex32::default(); 32];
+    let before = *sdft.spectrum(&mut before);
+
+    sdft.refresh();
+    let mut after = [Complex32::default(); 32];
+    approx::assert_complex_eq(sdft.spectrum(&mut after), &before);
+
+    let samples: Vec<_> = sdft.samples().collect();
+    assert_eq!(samples, &signal[signal.len() - 32..]);
+
+    sdft.push(Complex32::new(1., 0.));
+    let mut expected: [_; 32] = signal[signal.len() - 31..]
+        .iter()
+        .copied()
+        .chain([Complex32::new(1., 0.)])
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+    let expected = complex::cfft(&mut expected);
+    approx::assert_complex_eq(sdft.spectrum(&mut after), expected);
+}
@@ -0,0 +1,112 @@
+use microfft::test_utils::*;
+use microfft::{inverse_real, real, Complex32, RealSpectrum};
+use std::convert::TryInto;
+
+macro_rules! spectrum_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = signal_generators::ramp_real($N, 1.7, $N as f32);
+
+                let expected = references::real_fft(&input);
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let spectrum = RealSpectrum::new(real::rfft(&mut input));
+
+                assert_eq!(spectrum.fft_len(), $N);
+                assert_eq!(spectrum.bin_count(), $N / 2 + 1);
+                assert!(approx::f32_eq(spectrum.dc(), expected[0].re, 0.005));
+                assert!(approx::f32_eq(spectrum.nyquist(), expected[$N / 2].re, 0.005));
+
+                let bins: Vec<_> = spectrum.bins().collect();
+                approx::assert_complex_eq(&bins, &expected);
+                for (k, c) in expected.iter().enumerate() {
+                    assert!(approx::complex_eq(spectrum.bin(k), *c, 0.005));
+                }
+
+                let input = signal_generators::noise_real($N, 12345);
+
+                let expected = references::real_fft(&input);
+                let mut input: [_; $N] = input.try_into().unwrap();
+                let spectrum = RealSpectrum::new(real::rfft(&mut input));
+
+                let magnitudes: Vec<_> = spectrum.magnitudes().collect();
+                let expected_magnitudes: Vec<_> = expected.iter().map(|c| c.norm()).collect();
+                approx::assert_f32_eq(&magnitudes, &expected_magnitudes);
+
+                for (p, c) in spectrum.powers().zip(&expected) {
+                    assert!(approx::f32_eq(p, c.norm_sqr(), 0.01 * c.norm_sqr().max(1.)));
+                }
+
+                for (phase, c) in spectrum.phases().zip(spectrum.bins()) {
+                    assert!(approx::f32_eq(phase, c.arg(), 1e-5), "{phase} != {}", c.arg());
+                }
+
+                let input: [_; $N] = signal_generators::noise_real($N, 12345)
+                    .try_into()
+                    .unwrap();
+
+                let mut data = input;
+                let packed = real::rfft(&mut data);
+                let original = *packed;
+
+                let spectrum = RealSpectrum::from(packed);
+                assert_eq!(spectrum.as_packed(), &original);
+                let packed = spectrum.into_packed();
+                assert_eq!(packed, &original);
+
+                let recovered = inverse_real::irfft::<$N>(packed);
+                approx::assert_f32_eq(recovered, &input);
+            }
+        )*
+    };
+}
+
+spectrum_tests! {
+    size_2: 2,
+    size_4: 4,
+    size_16: 16,
+    size_1024: 1024,
+    size_480: 480,
+}
+
+#[test]
+#[should_panic]
+fn bin_out_of_range() {
+    let mut packed = [Complex32::default(); 8];
+    let spectrum = RealSpectrum::new(&mut packed);
+    let _ = spectrum.bin(9);
+}
+
+#[test]
+fn bin_frequency() {
+    let mut packed = [Complex32::default(); 512];
+    let spectrum = RealSpectrum::new(&mut packed);
+
+    assert_eq!(spectrum.bin_frequency(0, 48000.), 0.);
+    assert_eq!(spectrum.bin_frequency(1, 48000.), 46.875);
+    assert_eq!(spectrum.bin_frequency(512, 48000.), 24000.);
+}
+
+#[test]
+fn phases_in_all_quadrants() {
+    let mut packed = [
+        Complex32::new(-1., 2.),
+        Complex32::new(1., 1.),
+        Complex32::new(-1., 1.),
+        Complex32::new(-1., -1.),
+        Complex32::new(1., -1.),
+        Complex32::new(0., 3.),
+        Complex32::new(-3., 0.),
+        Complex32::new(0., -3.),
+    ];
+    let spectrum = RealSpectrum::new(&mut packed);
+
+    for (phase, c) in spectrum.phases().zip(spectrum.bins()) {
+        assert!(
+            approx::f32_eq(phase, c.arg(), 1e-6),
+            "{phase} != {}",
+            c.arg()
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use microfft::scaling::WindowScaling;
+use microfft::test_utils::signal_generators;
+use microfft::welch::{self, Welch};
+use microfft::window::Window;
+
+macro_rules! welch_tests {
+    ( $( $name:ident: $N:expr, $rfft:ident, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                fn reference(signal: &[f64], window: Window, overlap: usize, sample_rate: f64) -> Vec<f64> {
+                    let scaling = WindowScaling::new(window, $N);
+                    let mut sum = vec![0.; $N / 2 + 1];
+                    let mut segments = 0;
+                    let mut start = 0;
+                    while start + $N <= signal.len() {
+                        let mut x: [f64; $N] = signal[start..start + $N].try_into().unwrap();
+                        let mut coefficients = [0.; $N];
+                        window.fill(&mut coefficients);
+                        microfft::window::apply(&mut x, &coefficients);
+                        let spectrum = microfft::real::$rfft(&mut x);
+                        for (s, p) in sum.iter_mut().zip(scaling.power_spectral_density(spectrum, sample_rate)) {
+                            *s += p;
+                        }
+                        segments += 1;
+                        start += $N - overlap;
+                    }
+                    sum.iter().map(|s| s / segments as f64).collect()
+                }
+
+                fn assert_close(result: &[f64], expected: &[f64]) {
+                    let max = expected.iter().cloned().fold(0., f64::max);
+                    for (a, b) in result.iter().zip(expected) {
+                        assert!((a - b).abs() <= 1e-12 * max, "{a} !~ {b}");
+                    }
+                }
+
+                // the trailing 37 samples do not fill a segment
+                let signal = signal_generators::to_f64(&signal_generators::noise_real(10 * $N + 37, 12345));
+
+                for (window, overlap) in [(Window::Hann, $N / 2), (Window::Rectangular, 0), (Window::BlackmanHarris, 3 * $N / 4)] {
+                    let mut psd = [0.; $N / 2 + 1];
+                    welch::welch::<_, $N>(&signal, window, overlap, 250., &mut psd);
+                    assert_close(&psd, &reference(&signal, window, overlap, 250.));
+                }
+
+                let signal = signal_generators::to_f64(&signal_generators::noise_real(5 * $N + 3, 12345));
+                let mut expected = [0.; $N / 2 + 1];
+                welch::welch::<_, $N>(&signal, Window::Hann, $N / 4, 1., &mut expected);
+
+                // sample by sample
+                let mut accumulator = [0.; $N / 2 + 1];
+                let mut estimator = Welch::<_, $N>::new(Window::Hann, $N / 4, &mut accumulator);
+                for x in &signal {
+                    estimator.push(*x);
+                }
+                let mut psd = [0.; $N / 2 + 1];
+                estimator.psd(1., &mut psd);
+                assert_close(&psd, &expected);
+                assert_close(estimator.finish(1.), &expected);
+
+                // in uneven slices
+                let mut accumulator = [0.; $N / 2 + 1];
+                let mut estimator = Welch::<_, $N>::new(Window::Hann, $N / 4, &mut accumulator);
+                for chunk in signal.chunks(7) {
+                    estimator.push_slice(chunk);
+                }
+                assert_eq!(estimator.segments(), 6);
+                assert_close(estimator.finish(1.), &expected);
+
+                // the PSD of uniform white noise in [-1, 1] is flat, and
+                // integrates to its mean square of 1/3
+                let sample_rate = 1000.;
+                let signal = signal_generators::noise_real(200 * $N, 54321);
+                let mut psd = [0f32; $N / 2 + 1];
+                welch::welch::<_, $N>(&signal, Window::Hann, $N / 2, sample_rate, &mut psd);
+
+                let total: f32 = psd.iter().sum::<f32>() * sample_rate / $N as f32;
+                assert!((total - 1. / 3.).abs() < 0.01, "{total}");
+                let expected = 2. / 3. / sample_rate;
+                for p in &psd[1..$N / 2] {
+                    assert!((p - expected).abs() < 0.5 * expected, "{p} !~ {expected}");
+                }
+            }
+        )*
+    };
+}
+
+welch_tests! {
+    size_16: 16, rfft_16_f64,
+    size_256: 256, rfft_256_f64,
+}
+
+#[test]
+fn incomplete_segment() {
+    let mut accumulator = [1f32; 33];
+    let mut estimator = Welch::<_, 64>::new(Window::Hann, 32, &mut accumulator);
+    estimator.push_slice(&[1.; 63]);
+    assert_eq!(estimator.segments(), 0);
+
+    let mut psd = [1.; 33];
+    assert_eq!(estimator.psd(1., &mut psd), &[0.; 33]);
+
+    estimator.push(1.);
+    assert_eq!(estimator.segments(), 1);
+    estimator.push_slice(&[1.; 31]);
+    assert_eq!(estimator.segments(), 1);
+    estimator.push(1.);
+    assert_eq!(estimator.segments(), 2);
+
+    estimator.reset();
+    assert_eq!(estimator.segments(), 0);
+    assert_eq!(estimator.finish(1.), &[0.; 33]);
+}
+
+#[test]
+fn constant_signal() {
+    // a constant value `c` has a power of `c^2` at DC, spread over the
+    // equivalent noise bandwidth of the window
+    let mut psd = [0f64; 33];
+    let signal = [2.; 1000];
+    welch::welch::<_, 64>(&signal, Window::Hann, 32, 64., &mut psd);
+
+    let scaling = WindowScaling::new(Window::Hann, 64);
+    // bins are 1 Hz wide
+    let total = psd.iter().sum::<f64>();
+    assert!((total - 4.).abs() < 1e-9);
+    assert!((psd[0] - 4. / scaling.enbw()).abs() < 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "overlap must be less than the segment length")]
+fn overlap_too_large() {
+    let mut accumulator = [0f32; 33];
+    let _ = Welch::<_, 64>::new(Window::Hann, 64, &mut accumulator);
+}
@@ -0,0 +1,212 @@
+use microfft::window::{self, Window};
+use microfft::Complex32;
+use std::f64::consts::PI;
+
+/// The zeroth-order modified Bessel function of the first kind.
+fn bessel_i0(x: f64) -> f64 {
+    let mut term = 1.;
+    let mut sum = 1.;
+    for k in 1..100 {
+        term *= (x / 2. / k as f64).powi(2);
+        sum += term;
+    }
+    sum
+}
+
+/// The coefficient `w[n]` of `window` with parameter `M = m`.
+fn reference(window: Window, n: usize, m: usize) -> f64 {
+    let x = n as f64 / m as f64;
+    let cosine_sum = |a: &[f64]| -> f64 {
+        a.iter()
+            .enumerate()
+            .map(|(k, a_k)| (-1f64).powi(k as i32) * a_k * (2. * PI * k as f64 * x).cos())
+            .sum()
+    };
+
+    match window {
+        Window::Rectangular => 1.,
+        Window::Hann => cosine_sum(&[0.5, 0.5]),
+        Window::Hamming => cosine_sum(&[0.54, 0.46]),
+        Window::Blackman => cosine_sum(&[0.42, 0.5, 0.08]),
+        Window::BlackmanHarris => cosine_sum(&[0.35875, 0.48829, 0.14128, 0.01168]),
+        Window::FlatTop => cosine_sum(&[
+            0.21557895,
+            0.41663158,
+            0.277263158,
+            0.083578947,
+            0.006947368,
+        ]),
+        Window::Kaiser(beta) => {
+            bessel_i0(beta * (1. - (2. * x - 1.).powi(2)).sqrt()) / bessel_i0(beta)
+        }
+        Window::Tukey(alpha) => {
+            let x = x.min(1. - x);
+            if x < alpha / 2. {
+                0.5 - 0.5 * (2. * PI * x / alpha).cos()
+            } else {
+                1.
+            }
+        }
+        Window::Gaussian(sigma) => (-0.5 * ((2. * x - 1.) / sigma).powi(2)).exp(),
+        Window::Sine | Window::Kbd(_) => unreachable!("not defined in terms of `M`"),
+    }
+}
+
+const WINDOWS: [Window; 12] = [
+    Window::Rectangular,
+    Window::Hann,
+    Window::Hamming,
+    Window::Blackman,
+    Window::BlackmanHarris,
+    Window::FlatTop,
+    Window::Kaiser(0.),
+    Window::Kaiser(8.6),
+    Window::Tukey(0.),
+    Window::Tukey(0.5),
+    Window::Gaussian(0.4),
+    Window::Gaussian(0.1),
+];
+
+macro_rules! window_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                for window in WINDOWS {
+                    let mut coefficients = [0.; $N];
+                    window.fill(&mut coefficients);
+                    let mut coefficients_f32 = [0f32; $N];
+                    window.fill(&mut coefficients_f32);
+
+                    for n in 0..$N {
+                        let expected = reference(window, n, $N);
+                        let w = coefficients[n];
+                        assert!((w - expected).abs() < 1e-12, "{window:?}[{n}]: {w} !~ {expected}");
+                        let w = f64::from(coefficients_f32[n]);
+                        assert!((w - expected).abs() < 1e-6, "{window:?}[{n}]: {w} !~ {expected}");
+                    }
+                }
+
+                for window in WINDOWS {
+                    let mut coefficients = [0.; $N];
+                    window.fill_symmetric(&mut coefficients);
+
+                    for n in 0..$N {
+                        let expected = if $N == 1 { 1. } else { reference(window, n, $N - 1) };
+                        let w = coefficients[n];
+                        assert!((w - expected).abs() < 1e-12, "{window:?}[{n}]: {w} !~ {expected}");
+                        assert!((w - coefficients[$N - 1 - n]).abs() < 1e-12);
+                    }
+                }
+
+                let input = microfft::test_utils::signal_generators::noise_complex($N, 12345);
+
+                for window in WINDOWS {
+                    let mut coefficients = [0f32; $N];
+                    window.fill(&mut coefficients);
+
+                    let mut x: [Complex32; $N] = input.clone().try_into().unwrap();
+                    window::apply_complex(&mut x, &coefficients);
+                    let mut y: [f32; $N] = core::array::from_fn(|n| input[n].re);
+                    window::apply(&mut y, &coefficients);
+
+                    for n in 0..$N {
+                        assert_eq!(x[n], input[n] * coefficients[n]);
+                        assert_eq!(y[n], input[n].re * coefficients[n]);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+window_tests! {
+    size_1: 1,
+    size_2: 2,
+    size_16: 16,
+    size_60: 60,
+    size_127: 127,
+    size_1024: 1024,
+}
+
+#[test]
+fn tukey_extremes() {
+    let mut tukey = [0f64; 64];
+    let mut expected = [0.; 64];
+
+    Window::Tukey(1.).fill(&mut tukey);
+    Window::Hann.fill(&mut expected);
+    for (a, b) in tukey.iter().zip(&expected) {
+        assert!((a - b).abs() < 1e-12);
+    }
+
+    Window::Tukey(0.).fill(&mut tukey);
+    Window::Kaiser(0.).fill(&mut expected);
+    assert_eq!(tukey, [1.; 64]);
+    assert_eq!(expected, [1.; 64]);
+}
+
+#[test]
+fn periodic_extends_symmetric() {
+    // the periodic window is the symmetric window of length `N + 1`,
+    // without its last value
+    for window in WINDOWS {
+        let mut periodic = [0f64; 32];
+        let mut symmetric = [0.; 33];
+        window.fill(&mut periodic);
+        window.fill_symmetric(&mut symmetric);
+
+        for (a, b) in periodic.iter().zip(&symmetric) {
+            assert!((a - b).abs() < 1e-12, "{window:?}: {a} !~ {b}");
+        }
+    }
+}
+
+#[test]
+fn sine() {
+    let mut periodic = [0f32; 64];
+    Window::Sine.fill(&mut periodic);
+    let mut symmetric = [0f32; 64];
+    Window::Sine.fill_symmetric(&mut symmetric);
+
+    assert_eq!(periodic, symmetric);
+    for (n, w) in periodic.iter().enumerate() {
+        let expected = (std::f32::consts::PI * (n as f32 + 0.5) / 64.).sin();
+        assert!((w - expected).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn kbd() {
+    // AAC long block window, alpha = 4
+    let mut periodic = [0f64; 2048];
+    Window::Kbd(4.).fill(&mut periodic);
+    let mut symmetric = [0f64; 2048];
+    Window::Kbd(4.).fill_symmetric(&mut symmetric);
+
+    assert_eq!(periodic, symmetric);
+    assert!(periodic[0] > 0. && periodic[0] < 1e-3);
+    assert!(periodic.windows(2).take(1023).all(|w| w[0] < w[1]));
+    assert!((periodic[1023] - 1.).abs() < 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "window length must be even")]
+fn kbd_odd_length() {
+    Window::Kbd(4.).fill(&mut [0f32; 15]);
+}
+
+#[test]
+fn princen_bradley() {
+    for window in [Window::Sine, Window::Kbd(4.)] {
+        let mut coefficients = [0f64; 64];
+        window.fill(&mut coefficients);
+
+        for n in 0..32 {
+            let w = coefficients[n];
+            let sum = w * w + coefficients[n + 32] * coefficients[n + 32];
+            assert!((sum - 1.).abs() < 1e-12, "{window:?}: {sum}");
+            assert!((w - coefficients[63 - n]).abs() < 1e-12);
+        }
+    }
+}